@@ -1,28 +1,28 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
-use serde_json::{json, Number};
+use serde_json::{json, Number, Value};
 use serde_json_lodash as l;
 
 pub fn criterion_capitalize(c: &mut Criterion) {
     c.bench_function("::capitalize examples", |b| {
-        b.iter(|| l::capitalize(black_box(json!("FRED"))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!("FRED"))))
     });
     c.bench_function("::capitalize null", |b| {
-        b.iter(|| l::capitalize(black_box(json!(null))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!(null))))
     });
     c.bench_function("::capitalize bool", |b| {
-        b.iter(|| l::capitalize(black_box(json!(true))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!(true))))
     });
     c.bench_function("::capitalize number", |b| {
-        b.iter(|| l::capitalize(black_box(json!(-0.1))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!(-0.1))))
     });
     c.bench_function("::capitalize string", |b| {
-        b.iter(|| l::capitalize(black_box(json!("abc"))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!("abc"))))
     });
     c.bench_function("::capitalize array", |b| {
-        b.iter(|| l::capitalize(black_box(json!([true, -0.1, "abc", [], {}]))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!([true, -0.1, "abc", [], {}]))))
     });
     c.bench_function("::capitalize object", |b| {
-        b.iter(|| l::capitalize(black_box(json!({"a": 123}))))
+        b.iter(|| l::capitalize::<Value>(black_box(json!({"a": 123}))))
     });
 }
 
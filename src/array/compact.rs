@@ -1,4 +1,5 @@
 use crate::lib::{json, Value};
+use crate::internal::value_is_truthy;
 
 /// See lodash [compact](https://lodash.com/docs/#compact)
 pub fn compact(v: Value) -> Value {
@@ -11,20 +12,7 @@ pub fn compact(v: Value) -> Value {
             Value::Array(vec)
         }
         Value::Array(vec) => {
-            let result = vec
-                .into_iter()
-                .filter(|v| match v {
-                    Value::Null => false,
-                    Value::Bool(b) => *b,
-                    Value::Number(n) => match n.as_i64() {
-                        Some(v) => v != 0,
-                        None => false,
-                    },
-                    Value::String(s) => !s.is_empty(),
-                    Value::Array(_) => true,
-                    Value::Object(_) => true,
-                })
-                .collect::<Vec<_>>();
+            let result = vec.into_iter().filter(value_is_truthy).collect::<Vec<_>>();
             Value::Array(result)
         }
         Value::Object(_) => json!([]),
@@ -56,6 +44,7 @@ pub fn compact(v: Value) -> Value {
 /// assert_eq!(compact!(json!("ab")), json!(["a","b"]));
 /// assert_eq!(compact!(json!({"a":1})), json!([]));
 /// assert_eq!(compact!(json!([["ab"],{"a":1}])), json!([ [ "ab" ], { "a": 1 } ]));
+/// assert_eq!(compact!(json!([0.0, 1.5, -0.5])), json!([1.5, -0.5]));
 /// ```
 #[macro_export]
 macro_rules! compact {
@@ -1,51 +1,22 @@
 use crate::lib::{Value};
+use crate::internal::{base_difference, flatten_arrays};
+use crate::{EqualityMode, ValueKey};
 
 /// See lodash [difference](https://lodash.com/docs/#difference)
+///
+/// Values are compared by [EqualityMode::SameValueZero], in O(n + m).
 pub fn difference(v1: Value, v2: Value) -> Value {
-    let mut result = vec![];
-    let v1 = match v1 {
-        Value::Null => return Value::Array(result),
-        Value::Bool(_) => return Value::Array(result),
-        Value::Number(_) => return Value::Array(result),
-        Value::String(_) => return Value::Array(result),
+    difference_in(EqualityMode::SameValueZero, v1, v2)
+}
+/// Same as [difference()], but values are compared by `mode`
+pub fn difference_in(mode: EqualityMode, v1: Value, v2: Value) -> Value {
+    let vec1 = match v1 {
         Value::Array(vec) => vec,
-        Value::Object(_) => return Value::Array(result),
+        _ => return Value::Array(vec![]),
     };
-    match v2 {
-        Value::Null => return Value::Array(v1),
-        Value::Bool(_) => return Value::Array(v1),
-        Value::Number(_) => return Value::Array(v1),
-        Value::String(_) => return Value::Array(v1),
-        Value::Array(vec) => {
-            'outer: for v in v1.iter() {
-                match v {
-                    Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-                        for vv in vec.iter() {
-                            match vv {
-                                Value::Null => {
-                                    if v.is_null() {
-                                        continue 'outer;
-                                    }
-                                }
-                                Value::Bool(_) | Value::Number(_) | Value::String(_) => {
-                                    if v == vv {
-                                        continue 'outer;
-                                    }
-                                }
-                                Value::Array(_) => continue,
-                                Value::Object(_) => continue,
-                            }
-                        }
-                    }
-                    Value::Array(_) => (),
-                    Value::Object(_) => (),
-                }
-                result.push(v.clone())
-            }
-        }
-        Value::Object(_) => return Value::Array(v1),
-    };
-    Value::Array(result)
+    Value::Array(base_difference(vec1, &flatten_arrays(vec![v2]), |v| {
+        ValueKey::new(v, mode)
+    }))
 }
 
 /// Based on [difference()]
@@ -74,6 +45,9 @@ pub fn difference(v1: Value, v2: Value) -> Value {
 /// assert_eq!(difference!(json!([1,null])), json!([1,null]));
 /// assert_eq!(difference!(json!({})), json!([]));
 /// assert_eq!(difference!(json!([null,true,0,"",1.1,[],{}]), json!([null,true,0,"",1.1,[],{}])), json!([ [], {} ]));
+/// assert_eq!(difference!(json!([1, 1.5, 2.0, 2]), json!([2])), json!([1, 1.5]));
+/// let big: Vec<_> = (0..100_000).collect();
+/// assert_eq!(difference!(json!(big), json!(big)), json!([]));
 /// ```
 #[macro_export]
 macro_rules! difference {
@@ -90,3 +64,44 @@ macro_rules! difference {
         $crate::difference($a, $b)
     };
 }
+/// Based on [difference_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   difference_in!(EqualityMode::Structural, json!([[2], [1]]), json!([[2], [3]])),
+///   json!([[1]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(difference_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(difference_in!(EqualityMode::Structural, json!(null)), json!([]));
+/// assert_eq!(difference_in!(EqualityMode::Structural, json!([{}, {}])), json!([{}, {}]));
+/// assert_eq!(difference_in!(EqualityMode::Structural, json!([{}, [], {"a": 1}]), json!([{"a": 1.0}, []])), json!([{}]));
+/// assert_eq!(difference_in!(EqualityMode::SameValueZero, json!([{}, 1]), json!([{}, 1])), json!([{}]));
+/// ```
+#[macro_export]
+macro_rules! difference_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $crate::difference_in($m, $a, json!([]))
+    };
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::difference_in($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::difference_in($m, $a, $b)
+    };
+}
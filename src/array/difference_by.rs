@@ -0,0 +1,84 @@
+use crate::lib::{Value};
+use crate::internal::{base_difference, flatten_arrays};
+use crate::{EqualityMode, Iteratee, ValueKey};
+
+#[doc(hidden)]
+pub fn _difference_by_all<I: Iteratee>(mut arrays: Vec<Value>, iteratee: I) -> Value {
+    let vec = match arrays.first_mut() {
+        Some(Value::Array(vec)) => std::mem::take(vec),
+        _ => return Value::Array(vec![]),
+    };
+    let excluded = flatten_arrays(arrays.split_off(1));
+    let mode = EqualityMode::SameValueZero;
+    Value::Array(base_difference(vec, &excluded, |v| {
+        ValueKey::new(&iteratee.call(v), mode)
+    }))
+}
+/// See lodash [differenceBy](https://lodash.com/docs/#differenceBy)
+///
+/// Same as [difference()](crate::difference()), but compares the computed values, in O(n + m).
+/// [difference_by!](crate::difference_by!) takes any number of arrays to exclude, followed by the iteratee,
+/// which defaults to comparing the items themselves when the last argument is an array too, like lodash.
+pub fn difference_by<I: Iteratee>(v1: Value, v2: Value, iteratee: I) -> Value {
+    _difference_by_all(vec![v1, v2], iteratee)
+}
+
+/// Based on [difference_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   difference_by!(json!([2.1, 1.2]), json!([2.3, 3.4]), |n: &Value| floor!(n.clone())),
+///   json!([1.2])
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   difference_by!(json!([{ "x": 2 }, { "x": 1 }]), json!([{ "x": 1 }]), "x"),
+///   json!([{ "x": 2 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(difference_by!(), json!([]));
+/// // without an iteratee, the items are compared themselves like lodash
+/// assert_eq!(difference_by!(json!([1]), json!([2])), json!([1]));
+/// assert_eq!(difference_by!(json!([1, 2, 3]), json!([1.0]), json!([3])), json!([2]));
+/// assert_eq!(difference_by!(json!([1, 1])), json!([1, 1]));
+/// assert_eq!(difference_by!(json!(null), json!([1]), "x"), json!([]));
+/// assert_eq!(difference_by!(json!([1, 2]), json!(null), |n: &Value| n.clone()), json!([1, 2]));
+/// let users = json!([{"id": 1, "name": "a"}, {"id": 2, "name": "b"}, {"id": 3, "name": "c"}]);
+/// let removed = json!([{"id": 2}]);
+/// let banned = json!([{"id": 3.0}]);
+/// assert_eq!(difference_by!(users, removed, banned, "id"), json!([{"id": 1, "name": "a"}]));
+/// assert_eq!(difference_by!(json!([{"a": {"b": 1}}, {"a": {}}]), json!([{}]), "a.b"), json!([{"a": {"b": 1}}]));
+/// assert_eq!(difference_by!(json!(["a", "b"]), json!(["c"]), |_: &Value| json!([])), json!(["a", "b"]));
+/// let big: Vec<_> = (0..100_000).map(|n| json!({"id": n})).collect();
+/// assert_eq!(difference_by!(json!(big), json!(big), "id"), json!([]));
+/// ```
+#[macro_export]
+macro_rules! difference_by {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {{
+        let mut arrays = vec![$($arrays),*];
+        let iteratee = $crate::internal::ByLast::split($f, &mut arrays);
+        $crate::_difference_by_all(arrays, iteratee)
+    }};
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::difference_by!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::difference($a, json!([]))
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::difference_by!(@arrays [$a] $($rest)+)
+    };
+}
@@ -0,0 +1,78 @@
+use crate::lib::{Value};
+use crate::internal::flatten_arrays;
+
+#[doc(hidden)]
+pub fn _difference_with_all<C: Fn(&Value, &Value) -> bool>(
+    mut arrays: Vec<Value>,
+    comparator: C,
+) -> Value {
+    let vec = match arrays.first_mut() {
+        Some(Value::Array(vec)) => std::mem::take(vec),
+        _ => return Value::Array(vec![]),
+    };
+    let excluded = flatten_arrays(arrays.split_off(1));
+    Value::Array(
+        vec.into_iter()
+            .filter(|v| !excluded.iter().any(|e| comparator(v, e)))
+            .collect(),
+    )
+}
+/// See lodash [differenceWith](https://lodash.com/docs/#differenceWith)
+///
+/// Same as [difference()](crate::difference()), but compares the items with `comparator`, in O(n × m).
+/// [difference_with!](crate::difference_with!) takes any number of arrays to exclude, followed by the comparator.
+pub fn difference_with<C: Fn(&Value, &Value) -> bool>(
+    v1: Value,
+    v2: Value,
+    comparator: C,
+) -> Value {
+    _difference_with_all(vec![v1, v2], comparator)
+}
+
+/// Based on [difference_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }]);
+/// assert_eq!(
+///   difference_with!(objects, json!([{ "x": 1, "y": 2 }]), |a: &Value, b: &Value| a == b),
+///   json!([{ "x": 2, "y": 1 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(difference_with!(), json!([]));
+/// assert_eq!(difference_with!(json!([1, 1])), json!([1, 1]));
+/// assert_eq!(difference_with!(json!(null), json!([1]), |a: &Value, b: &Value| a == b), json!([]));
+/// assert_eq!(difference_with!(json!([1, 2]), |_: &Value, _: &Value| true), json!([1, 2]));
+/// assert_eq!(difference_with!(json!([1, 2]), json!([0]), |_: &Value, _: &Value| true), json!([]));
+/// assert_eq!(
+///   difference_with!(json!([[1], [2], [3]]), json!([[1]]), json!([[3]]), |a: &Value, b: &Value| a == b),
+///   json!([[2]])
+/// );
+/// ```
+#[macro_export]
+macro_rules! difference_with {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {
+        $crate::_difference_with_all(vec![$($arrays),*], $f)
+    };
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::difference_with!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::difference($a, json!([]))
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::difference_with!(@arrays [$a] $($rest)+)
+    };
+}
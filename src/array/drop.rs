@@ -1,20 +1,16 @@
 use crate::lib::{json, Value};
+use crate::internal::{base_slice, clamp_count};
 
 /// See lodash [drop](https://lodash.com/docs/#drop)
-pub fn drop(v: Value, n: usize) -> Value {
+///
+/// A negative `n` drops nothing.
+pub fn drop(v: Value, n: isize) -> Value {
     match v {
-        Value::Null => json!([]),
-        Value::Bool(_) => json!([]),
-        Value::Number(_) => json!([]),
-        Value::String(_) => json!([]),
         Value::Array(vec) => {
-            if n > vec.len() {
-                return json!([]);
-            }
-            let (_, right) = vec.split_at(n);
-            Value::Array(right.to_vec())
+            let len = vec.len();
+            Value::Array(base_slice(vec, clamp_count(n, len), len))
         }
-        Value::Object(_) => json!([]),
+        _ => json!([]),
     }
 }
 
@@ -56,6 +52,7 @@ pub fn drop(v: Value, n: usize) -> Value {
 /// assert_eq!(drop!(json!("")), json!([]));
 /// assert_eq!(drop!(json!([])), json!([]));
 /// assert_eq!(drop!(json!({})), json!([]));
+/// assert_eq!(drop!(json!([1, 2]), -1), json!([1, 2]));
 /// ```
 #[macro_export]
 macro_rules! drop {
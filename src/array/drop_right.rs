@@ -1,21 +1,16 @@
 use crate::lib::{json, Value};
+use crate::internal::{base_slice, clamp_count};
 
 /// See lodash [dropRight](https://lodash.com/docs/#dropRight)
-pub fn drop_right(v: Value, n: usize) -> Value {
+///
+/// A negative `n` drops nothing.
+pub fn drop_right(v: Value, n: isize) -> Value {
     match v {
-        Value::Null => json!([]),
-        Value::Bool(_) => json!([]),
-        Value::Number(_) => json!([]),
-        Value::String(_) => json!([]),
         Value::Array(vec) => {
             let len = vec.len();
-            if n > len {
-                return json!([]);
-            }
-            let (left, _) = vec.split_at(len - n);
-            Value::Array(left.to_vec())
+            Value::Array(base_slice(vec, 0, len - clamp_count(n, len)))
         }
-        Value::Object(_) => json!([]),
+        _ => json!([]),
     }
 }
 
@@ -57,6 +52,7 @@ pub fn drop_right(v: Value, n: usize) -> Value {
 /// assert_eq!(drop_right!(json!("")), json!([]));
 /// assert_eq!(drop_right!(json!([])), json!([]));
 /// assert_eq!(drop_right!(json!({})), json!([]));
+/// assert_eq!(drop_right!(json!([1, 2]), -1), json!([1, 2]));
 /// ```
 #[macro_export]
 macro_rules! drop_right {
@@ -1,28 +1,17 @@
-use std::mem;
-
-use crate::lib::{json, Value};
+use crate::lib::{Value};
+use crate::internal::relative_index;
 
 /// See lodash [fill](https://lodash.com/docs/#fill)
-pub fn fill(mut array: Value, value: Value, start: usize, end: usize) -> Value {
-    let vec = match array {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return json!([])
-        }
-        Value::Array(ref mut vec) => {
-            let real_end = {
-                if end > vec.len() {
-                    vec.len()
-                } else {
-                    end
-                }
-            };
-            for item in vec.iter_mut().take(real_end).skip(start) {
-                let _ = mem::replace(item, value.clone());
-            }
-            vec
+///
+/// A negative `start` or `end` counts from the end of the array, and a value which isn't an array is returned as is.
+pub fn fill(mut array: Value, value: Value, start: isize, end: isize) -> Value {
+    if let Value::Array(vec) = &mut array {
+        let len = vec.len();
+        let (start, end) = (relative_index(start, len), relative_index(end, len));
+        for item in vec.iter_mut().take(end).skip(start) {
+            *item = value.clone();
         }
-    };
-    *array.as_array_mut().unwrap() = vec.to_vec();
+    }
     array
 }
 
@@ -55,14 +44,20 @@ pub fn fill(mut array: Value, value: Value, start: usize, end: usize) -> Value {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// assert_eq!(fill!(), json!([]));
-/// assert_eq!(fill!(json!(null)), json!([]));
-/// assert_eq!(fill!(json!(true)), json!([]));
-/// assert_eq!(fill!(json!(0)), json!([]));
-/// assert_eq!(fill!(json!("")), json!([]));
+/// assert_eq!(fill!(json!(null)), json!(null));
+/// assert_eq!(fill!(json!(true)), json!(true));
+/// assert_eq!(fill!(json!(0)), json!(0));
+/// assert_eq!(fill!(json!("")), json!(""));
 /// assert_eq!(fill!(json!([1])), json!([null]));
 /// assert_eq!(fill!(json!([1]), json!(""), 2), json!([1]));
 /// assert_eq!(fill!(json!([1]), json!(""), 0, 2), json!([""]));
-/// assert_eq!(fill!(json!({})), json!([]));
+/// assert_eq!(fill!(json!([1, 2, 3]), json!(0), -2), json!([1, 0, 0]));
+/// assert_eq!(fill!(json!([1, 2, 3]), json!(0), 0, -1), json!([0, 0, 3]));
+/// assert_eq!(fill!(json!([1, 2, 3]), json!(0), -5, 1), json!([0, 2, 3]));
+/// assert_eq!(fill!(json!([1, 2, 3]), json!(0), 2, 1), json!([1, 2, 3]));
+/// assert_eq!(fill!(json!({})), json!({}));
+/// let array = json!([1, 2]);
+/// assert_eq!(fill!(array, json!(0)), json!([0, 0]));
 /// ```
 #[macro_export]
 macro_rules! fill {
@@ -70,13 +65,13 @@ macro_rules! fill {
         json!([])
     };
     ($a:expr $(,)*) => {
-        $crate::fill($a, json!(null), 0, $a.as_array().unwrap_or(&vec![]).len())
+        $crate::fill($a, json!(null), 0, isize::MAX)
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::fill($a, $b, 0, $a.as_array().unwrap_or(&vec![]).len())
+        $crate::fill($a, $b, 0, isize::MAX)
     };
     ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::fill($a, $b, $c, $a.as_array().unwrap_or(&vec![]).len())
+        $crate::fill($a, $b, $c, isize::MAX)
     };
     ($a:expr, $b:expr, $c:expr, $d:expr $(,)*) => {
         $crate::fill($a, $b, $c, $d)
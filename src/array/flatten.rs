@@ -1,7 +1,7 @@
 use crate::lib::{json, Value};
+use crate::{FromValue, IntoValue};
 
-///
-pub fn x_flatten_x(vec: Vec<Value>) -> Vec<Value> {
+fn base_flatten(vec: Vec<Value>) -> Vec<Value> {
     if vec.is_empty() {
         return vec![];
     }
@@ -24,25 +24,35 @@ pub fn x_flatten_x(vec: Vec<Value>) -> Vec<Value> {
     }
     result
 }
+///
+#[deprecated(note = "use `flatten::<Vec<Value>>()`")]
+pub fn x_flatten_x(vec: Vec<Value>) -> Vec<Value> {
+    flatten(vec)
+}
 /// See lodash [flatten](https://lodash.com/docs/#flatten)
-pub fn flatten(v: Value) -> Value {
-    match v {
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<Value>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::flatten;
+/// assert_eq!(flatten::<Vec<Value>>(vec![json!(1), json!([2, [3]])]), vec![json!(1), json!(2), json!([3])]);
+/// ```
+pub fn flatten<R: FromValue>(array: impl IntoValue) -> R {
+    R::from_value(match array.into_value() {
         Value::Null => json!([]),
         Value::Bool(_) => json!([]),
-        Value::String(s) => {
-            if s.is_empty() {
-                return json!([]);
-            }
-            Value::Array(
-                s.chars()
-                    .map(|c| Value::String(c.to_string()))
-                    .collect::<Vec<Value>>(),
-            )
-        }
+        Value::String(s) => Value::Array(
+            s.chars()
+                .map(|c| Value::String(c.to_string()))
+                .collect::<Vec<Value>>(),
+        ),
         Value::Number(_) => json!([]),
-        Value::Array(vec) => Value::Array(x_flatten_x(vec)),
+        Value::Array(vec) => Value::Array(base_flatten(vec)),
         Value::Object(_) => json!([]),
-    }
+    })
 }
 /// Based on [flatten()]
 ///
@@ -79,9 +89,9 @@ macro_rules! flatten {
         json!([])
     };
     ($a:expr $(,)*) => {
-        $crate::flatten($a)
+        $crate::flatten::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::flatten($a)
+        $crate::flatten::<$crate::lib::Value>($a)
     };
 }
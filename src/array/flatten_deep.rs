@@ -1,7 +1,7 @@
 use crate::lib::{json, Value};
+use crate::{FromValue, IntoValue};
 
-///
-pub fn x_flatten_deep_x(vec: Vec<Value>) -> Vec<Value> {
+fn base_flatten_deep(vec: Vec<Value>) -> Vec<Value> {
     if vec.is_empty() {
         return vec![];
     }
@@ -16,7 +16,7 @@ pub fn x_flatten_deep_x(vec: Vec<Value>) -> Vec<Value> {
                 result.push(item);
             }
             Value::Array(vec) => {
-                for item in x_flatten_deep_x(vec).into_iter() {
+                for item in base_flatten_deep(vec).into_iter() {
                     result.push(item);
                 }
             }
@@ -24,25 +24,35 @@ pub fn x_flatten_deep_x(vec: Vec<Value>) -> Vec<Value> {
     }
     result
 }
+///
+#[deprecated(note = "use `flatten_deep::<Vec<Value>>()`")]
+pub fn x_flatten_deep_x(vec: Vec<Value>) -> Vec<Value> {
+    flatten_deep(vec)
+}
 /// See lodash [flattenDeep](https://lodash.com/docs/#flattenDeep)
-pub fn flatten_deep(v: Value) -> Value {
-    match v {
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<Value>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::flatten_deep;
+/// assert_eq!(flatten_deep::<Vec<Value>>(vec![json!(1), json!([2, [3]])]), vec![json!(1), json!(2), json!(3)]);
+/// ```
+pub fn flatten_deep<R: FromValue>(array: impl IntoValue) -> R {
+    R::from_value(match array.into_value() {
         Value::Null => json!([]),
         Value::Bool(_) => json!([]),
-        Value::String(s) => {
-            if s.is_empty() {
-                return json!([]);
-            }
-            Value::Array(
-                s.chars()
-                    .map(|c| Value::String(c.to_string()))
-                    .collect::<Vec<Value>>(),
-            )
-        }
+        Value::String(s) => Value::Array(
+            s.chars()
+                .map(|c| Value::String(c.to_string()))
+                .collect::<Vec<Value>>(),
+        ),
         Value::Number(_) => json!([]),
-        Value::Array(vec) => Value::Array(x_flatten_deep_x(vec)),
+        Value::Array(vec) => Value::Array(base_flatten_deep(vec)),
         Value::Object(_) => json!([]),
-    }
+    })
 }
 /// Based on [flatten_deep()]
 ///
@@ -79,9 +89,9 @@ macro_rules! flatten_deep {
         json!([])
     };
     ($a:expr $(,)*) => {
-        $crate::flatten_deep($a)
+        $crate::flatten_deep::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::flatten_deep($a)
+        $crate::flatten_deep::<$crate::lib::Value>($a)
     };
 }
@@ -1,7 +1,7 @@
 use crate::lib::{json, Value};
+use crate::{FromValue, IntoValue};
 
-///
-pub fn x_flatten_depth_x(vec: Vec<Value>, depth: usize) -> Vec<Value> {
+fn base_flatten_depth(vec: Vec<Value>, depth: usize) -> Vec<Value> {
     if depth == 0 {
         return vec;
     }
@@ -19,7 +19,7 @@ pub fn x_flatten_depth_x(vec: Vec<Value>, depth: usize) -> Vec<Value> {
                 result.push(item);
             }
             Value::Array(vec) => {
-                for item in x_flatten_depth_x(vec, depth - 1).into_iter() {
+                for item in base_flatten_depth(vec, depth - 1).into_iter() {
                     result.push(item);
                 }
             }
@@ -27,25 +27,35 @@ pub fn x_flatten_depth_x(vec: Vec<Value>, depth: usize) -> Vec<Value> {
     }
     result
 }
+///
+#[deprecated(note = "use `flatten_depth::<Vec<Value>>()`")]
+pub fn x_flatten_depth_x(vec: Vec<Value>, depth: usize) -> Vec<Value> {
+    flatten_depth(vec, depth)
+}
 /// See lodash [flattenDepth](https://lodash.com/docs/#flattenDepth)
-pub fn flatten_depth(v: Value, depth: usize) -> Value {
-    match v {
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<Value>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::flatten_depth;
+/// assert_eq!(flatten_depth::<Vec<Value>>(vec![json!(1), json!([2, [3, [4]]])], 2), vec![json!(1), json!(2), json!(3), json!([4])]);
+/// ```
+pub fn flatten_depth<R: FromValue>(array: impl IntoValue, depth: usize) -> R {
+    R::from_value(match array.into_value() {
         Value::Null => json!([]),
         Value::Bool(_) => json!([]),
-        Value::String(s) => {
-            if s.is_empty() {
-                return json!([]);
-            }
-            Value::Array(
-                s.chars()
-                    .map(|c| Value::String(c.to_string()))
-                    .collect::<Vec<Value>>(),
-            )
-        }
+        Value::String(s) => Value::Array(
+            s.chars()
+                .map(|c| Value::String(c.to_string()))
+                .collect::<Vec<Value>>(),
+        ),
         Value::Number(_) => json!([]),
-        Value::Array(vec) => Value::Array(x_flatten_depth_x(vec, depth)),
+        Value::Array(vec) => Value::Array(base_flatten_depth(vec, depth)),
         Value::Object(_) => json!([]),
-    }
+    })
 }
 
 /// Based on [flatten_depth()]
@@ -90,12 +100,12 @@ macro_rules! flatten_depth {
         json!([])
     };
     ($a:expr $(,)*) => {
-        $crate::flatten_depth($a, 1)
+        $crate::flatten_depth::<$crate::lib::Value>($a, 1)
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::flatten_depth($a, $b)
+        $crate::flatten_depth::<$crate::lib::Value>($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::flatten_depth($a, $b)
+        $crate::flatten_depth::<$crate::lib::Value>($a, $b)
     };
 }
@@ -1,4 +1,5 @@
 use crate::lib::{json, Value, Map};
+use crate::FromValue;
 
 fn item_to_string(v: &Value) -> String {
     match v {
@@ -7,7 +8,7 @@ fn item_to_string(v: &Value) -> String {
         | Value::String(_)
         | Value::Number(_)
         | Value::Object(_)
-        | Value::Array(_) => crate::to_string_x(v.clone()),
+        | Value::Array(_) => String::from_value(v.clone()),
     }
 }
 
@@ -28,7 +29,7 @@ fn value_to_kv(v: &Value) -> Option<(String, Option<Value>)> {
             None
         }
         Value::Array(vec) => {
-            if let Some(k) = vec.get(0) {
+            if let Some(k) = vec.first() {
                 let k = item_to_string(k);
                 if let Some(v) = vec.get(1) {
                     return Some((k, Some(v.clone())));
@@ -1,55 +1,48 @@
 use crate::lib::{Value};
+use crate::internal::base_intersection;
+use crate::{EqualityMode, FromValue, IntoValue, ValueKey};
 
 #[doc(hidden)]
 pub fn _empty_array() -> Vec<Value> {
     vec![]
 }
+fn intersection_in_x(mode: EqualityMode, v1: Value, v2: Value) -> Vec<Value> {
+    let (vec1, vec2) = match (v1, v2) {
+        (Value::Array(vec1), Value::Array(vec2)) => (vec1, vec2),
+        _ => return vec![],
+    };
+    base_intersection(vec1, &vec2, |v| ValueKey::new(v, mode))
+}
 ///
+#[deprecated(note = "use `intersection::<Vec<Value>>()`")]
 pub fn intersection_x(v1: Value, v2: Value) -> Vec<Value> {
-    let mut result = vec![];
-    let vec1 = match v1 {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return result
-        }
-        Value::Array(vec) => {
-            if vec.is_empty() {
-                return result;
-            }
-            vec
-        }
-    };
-    match v2 {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return result
-        }
-        Value::Array(vec2) => {
-            if vec2.is_empty() {
-                return result;
-            }
-            for v1 in vec1.iter() {
-                // Based on the RFC https://262.ecma-international.org/7.0/#sec-samevaluenonnumber
-                // -- if they are same Object value return true
-                // For here we compare two Value, not &Value. It means v1 and v2 are absolutely not same object in memory.
-                // So we could just simply ignore them
-                if v1.is_object() || v1.is_array() {
-                    continue;
-                }
-                for v2 in vec2.iter() {
-                    if v2.is_object() || v2.is_array() {
-                        continue;
-                    }
-                    if v1 == v2 {
-                        result.push(v1.clone())
-                    }
-                }
-            }
-        }
-    }
-    result
+    intersection(v1, v2)
 }
 /// See lodash [intersection](https://lodash.com/docs/#intersection)
-pub fn intersection(v1: Value, v2: Value) -> Value {
-    Value::Array(intersection_x(v1, v2))
+///
+/// Values are compared by [EqualityMode::SameValueZero], in O(n + m).
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<Value>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::intersection;
+/// assert_eq!(intersection::<Vec<Value>>(vec![2, 1], vec![2, 3]), vec![json!(2)]);
+/// assert_eq!(intersection::<Value>(json!([2, 1]), json!([2, 3])), json!([2]));
+/// ```
+pub fn intersection<R: FromValue>(array: impl IntoValue, values: impl IntoValue) -> R {
+    let vec = intersection_in_x(
+        EqualityMode::SameValueZero,
+        array.into_value(),
+        values.into_value(),
+    );
+    R::from_value(Value::Array(vec))
+}
+/// Same as [intersection()], but values are compared by `mode`
+pub fn intersection_in(mode: EqualityMode, v1: Value, v2: Value) -> Value {
+    Value::Array(intersection_in_x(mode, v1, v2))
 }
 
 /// Based on [intersection_x()]
@@ -74,6 +67,7 @@ pub fn intersection(v1: Value, v2: Value) -> Value {
 /// assert_eq!(intersection_x!(), expect);
 /// assert_eq!(intersection_x!(json!([3, 2, 1]), json!([2, 3, 4]), json!([3, 2, 0])), vec![json!(3), json!(2)])
 /// ```
+#[deprecated(note = "use `intersection::<Vec<Value>>()`")]
 #[macro_export]
 macro_rules! intersection_x {
     () => (
@@ -86,12 +80,16 @@ macro_rules! intersection_x {
             $crate::_empty_array()
         }
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::intersection_x($a, $b)
-    };
-    ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::intersection_x!($crate::intersection($a, $b), $($rest)*)
-    };
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::intersection_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::intersection_x!($crate::intersection::<$crate::lib::Value>($a, $b), $($rest)*);
+        result
+    }};
 }
 /// Based on [intersection()]
 ///
@@ -122,7 +120,11 @@ macro_rules! intersection_x {
 /// assert_eq!(intersection!(json!([null,false,0,"","ab",[],{}])), json!([null,false,0,"","ab",[],{}]));
 /// assert_eq!(intersection!(json!([null,false,0,"","ab",[],{}]), json!([])), json!([]));
 /// assert_eq!(intersection!(json!([null,false,0,"","ab",[],{}]), json!([null,false,0,"","ab",[],{}])), json!([null,false,0,"","ab"]));
+/// assert_eq!(intersection!(json!([1, 2, 1, 2.0]), json!([2, 1.0])), json!([1, 2]));
+/// let big: Vec<_> = (0..100_000).collect();
+/// assert_eq!(intersection!(json!(big), json!([99_999, 0])), json!([0, 99_999]));
 /// assert_eq!(intersection!(json!([null, false, 1]), json!([null,false,0]), json!([false, 2, null])), json!([null,false]));
+/// assert_eq!(intersection!(vec![1, 2], vec![2, 3], json!([2])), json!([2]));
 /// ```
 #[macro_export]
 macro_rules! intersection {
@@ -130,16 +132,63 @@ macro_rules! intersection {
         json!([])
     );
     ($a:expr $(,)*) => {{
-        if $a.is_array() {
-            $a
+        let a = $crate::IntoValue::into_value($a);
+        if a.is_array() {
+            a
         } else {
             json!([])
         }
     }};
     ($a:expr, $b:expr $(,)*) => {
-        $crate::intersection($a, $b)
+        $crate::intersection::<$crate::lib::Value>($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::intersection!($crate::intersection($a, $b), $($rest)*)
+        $crate::intersection!($crate::intersection::<$crate::lib::Value>($a, $b), $($rest)*)
+    };
+}
+/// Based on [intersection_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   intersection_in!(EqualityMode::Structural, json!([[2], [1]]), json!([[2], [3]])),
+///   json!([[2]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(intersection_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(intersection_in!(EqualityMode::Structural, json!(null)), json!([]));
+/// assert_eq!(intersection_in!(EqualityMode::Structural, json!([{}, {}])), json!([{}, {}]));
+/// assert_eq!(intersection_in!(EqualityMode::Structural, json!([{}, [], 1]), json!([[], {}]), json!([{}])), json!([{}]));
+/// assert_eq!(intersection_in!(EqualityMode::SameValueZero, json!([{}, 1]), json!([{}, 1])), json!([1]));
+/// ```
+#[macro_export]
+macro_rules! intersection_in {
+    ($m:expr $(,)*) => (
+        json!([])
+    );
+    ($m:expr, $a:expr $(,)*) => {{
+        if $a.is_array() {
+            $a
+        } else {
+            json!([])
+        }
+    }};
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::intersection_in($m, $a, $b)
     };
+    ($m:expr, $a:expr, $b:expr, $($rest:tt)*) => {{
+        let mode = $m;
+        $crate::intersection_in!(mode, $crate::intersection_in(mode, $a, $b), $($rest)*)
+    }};
 }
@@ -0,0 +1,91 @@
+use crate::lib::{Value};
+use crate::internal::{base_intersection, base_uniq};
+use crate::{EqualityMode, Iteratee, ValueKey};
+
+#[doc(hidden)]
+pub fn _intersection_by_all<I: Iteratee>(arrays: Vec<Value>, iteratee: I) -> Value {
+    let mut vecs = match arrays
+        .into_iter()
+        .map(|v| match v {
+            Value::Array(vec) => Some(vec),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(vecs) if !vecs.is_empty() => vecs.into_iter(),
+        _ => return Value::Array(vec![]),
+    };
+    let mode = EqualityMode::SameValueZero;
+    let key = |v: &Value| ValueKey::new(&iteratee.call(v), mode);
+    let first = base_uniq(vecs.next().unwrap(), key);
+    Value::Array(vecs.fold(first, |result, other| {
+        base_intersection(result, &other, key)
+    }))
+}
+/// See lodash [intersectionBy](https://lodash.com/docs/#intersectionBy)
+///
+/// Same as [intersection()](crate::intersection()), but compares the computed values, in O(n + m).
+/// [intersection_by!](crate::intersection_by!) takes any number of arrays, followed by the iteratee,
+/// which defaults to comparing the items themselves when the last argument is an array too, like lodash.
+pub fn intersection_by<I: Iteratee>(v1: Value, v2: Value, iteratee: I) -> Value {
+    _intersection_by_all(vec![v1, v2], iteratee)
+}
+
+/// Based on [intersection_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   intersection_by!(json!([2.1, 1.2]), json!([2.3, 3.4]), |n: &Value| floor!(n.clone())),
+///   json!([2.1])
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   intersection_by!(json!([{ "x": 1 }]), json!([{ "x": 2 }, { "x": 1 }]), "x"),
+///   json!([{ "x": 1 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(intersection_by!(), json!([]));
+/// // without an iteratee, the items are compared themselves like lodash
+/// assert_eq!(intersection_by!(json!([2, 1]), json!([2, 3])), json!([2]));
+/// assert_eq!(intersection_by!(json!([2, 1]), json!([2, 3]), json!([2.0])), json!([2]));
+/// assert_eq!(intersection_by!(json!([1, 2])), json!([1, 2]));
+/// assert_eq!(intersection_by!(json!([1.5, 1.2]), |n: &Value| floor!(n.clone())), json!([1.5]));
+/// assert_eq!(intersection_by!(json!([1]), json!(null), |n: &Value| n.clone()), json!([]));
+/// let users = json!([{"id": 1, "name": "a"}, {"id": 2, "name": "b"}, {"id": 2, "name": "c"}]);
+/// let active = json!([{"id": 2}, {"id": 1}]);
+/// let admins = json!([{"id": 2.0}]);
+/// assert_eq!(intersection_by!(users, active, admins, "id"), json!([{"id": 2, "name": "b"}]));
+/// assert_eq!(intersection_by!(json!(["a"]), json!(["a"]), |_: &Value| json!({})), json!([]));
+/// let big: Vec<_> = (0..100_000).map(|n| json!({"id": n})).collect();
+/// assert_eq!(intersection_by!(json!(big), json!([{"id": 7}]), "id"), json!([{"id": 7}]));
+/// ```
+#[macro_export]
+macro_rules! intersection_by {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {{
+        let mut arrays = vec![$($arrays),*];
+        let iteratee = $crate::internal::ByLast::split($f, &mut arrays);
+        $crate::_intersection_by_all(arrays, iteratee)
+    }};
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::intersection_by!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::intersection!($a)
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::intersection_by!(@arrays [$a] $($rest)+)
+    };
+}
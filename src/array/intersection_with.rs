@@ -0,0 +1,91 @@
+use crate::lib::{Value};
+
+#[doc(hidden)]
+pub fn _intersection_with_all<C: Fn(&Value, &Value) -> bool>(
+    arrays: Vec<Value>,
+    comparator: C,
+) -> Value {
+    let mut vecs = match arrays
+        .into_iter()
+        .map(|v| match v {
+            Value::Array(vec) => Some(vec),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(vecs) if !vecs.is_empty() => vecs.into_iter(),
+        _ => return Value::Array(vec![]),
+    };
+    let first = vecs.next().unwrap();
+    let others: Vec<Vec<Value>> = vecs.collect();
+    let mut result: Vec<Value> = vec![];
+    for v in first {
+        if others
+            .iter()
+            .all(|other| other.iter().any(|o| comparator(&v, o)))
+            && !result.iter().any(|seen| comparator(&v, seen))
+        {
+            result.push(v);
+        }
+    }
+    Value::Array(result)
+}
+/// See lodash [intersectionWith](https://lodash.com/docs/#intersectionWith)
+///
+/// Same as [intersection()](crate::intersection()), but compares the items with `comparator`, in O(n × m).
+/// [intersection_with!](crate::intersection_with!) takes any number of arrays, followed by the comparator.
+pub fn intersection_with<C: Fn(&Value, &Value) -> bool>(
+    v1: Value,
+    v2: Value,
+    comparator: C,
+) -> Value {
+    _intersection_with_all(vec![v1, v2], comparator)
+}
+
+/// Based on [intersection_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }]);
+/// let others = json!([{ "x": 1, "y": 1 }, { "x": 1, "y": 2 }]);
+/// assert_eq!(
+///   intersection_with!(objects, others, |a: &Value, b: &Value| a == b),
+///   json!([{ "x": 1, "y": 2 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(intersection_with!(), json!([]));
+/// assert_eq!(intersection_with!(json!([1, 1])), json!([1, 1]));
+/// assert_eq!(intersection_with!(json!([1, 2]), |_: &Value, _: &Value| true), json!([1]));
+/// assert_eq!(intersection_with!(json!([1]), json!("a"), |_: &Value, _: &Value| true), json!([]));
+/// assert_eq!(
+///   intersection_with!(json!([[1], [2], [3]]), json!([[3], [1]]), json!([[1], [3]]), |a: &Value, b: &Value| a == b),
+///   json!([[1], [3]])
+/// );
+/// ```
+#[macro_export]
+macro_rules! intersection_with {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {
+        $crate::_intersection_with_all(vec![$($arrays),*], $f)
+    };
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::intersection_with!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::intersection!($a)
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::intersection_with!(@arrays [$a] $($rest)+)
+    };
+}
@@ -1,7 +1,7 @@
 use crate::lib::{Value};
+use crate::{FromValue, IntoValue};
 
-///
-pub fn join_x(v: Value, sep: &str) -> String {
+fn base_join(v: Value, sep: &str) -> String {
     match v {
         Value::Null => "".into(),
         Value::Bool(_) => "".into(),
@@ -18,7 +18,7 @@ pub fn join_x(v: Value, sep: &str) -> String {
         Value::Array(vec) => {
             let mut result = vec![];
             for item in vec.into_iter() {
-                result.push(crate::to_string_x(item));
+                result.push(String::from_value(item));
             }
             result.join(sep)
         }
@@ -26,9 +26,25 @@ pub fn join_x(v: Value, sep: &str) -> String {
     }
 }
 
+///
+#[deprecated(note = "use `join::<String>()`")]
+pub fn join_x(v: Value, sep: &str) -> String {
+    join(v, sep)
+}
 /// See lodash [join](https://lodash.com/docs/#join)
-pub fn join(v: Value, sep: &str) -> Value {
-    Value::String(join_x(v, sep))
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `String` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::join;
+/// assert_eq!(join::<String>(vec!["a", "b", "c"], "~"), "a~b~c".to_owned());
+/// assert_eq!(join::<Value>(json!(["a", "b", "c"]), "~"), json!("a~b~c"));
+/// ```
+pub fn join<R: FromValue>(array: impl IntoValue, separator: &str) -> R {
+    R::from_value(Value::String(base_join(array.into_value(), separator)))
 }
 
 /// Based on [join_x()]
@@ -51,20 +67,27 @@ pub fn join(v: Value, sep: &str) -> Value {
 /// # use serde_json::json;
 /// assert_eq!(join!(), "".to_owned());
 /// ```
+#[deprecated(note = "use `join::<String>()`")]
 #[macro_export]
 macro_rules! join_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::join_x($a, ",")
-    };
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::join_x($a, $b)
-    };
-    ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::join_x($a, $b)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::join_x($a, ",");
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::join_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::join_x($a, $b);
+        result
+    }};
 }
 /// Based on [join()]
 ///
@@ -94,6 +117,7 @@ macro_rules! join_x {
 /// assert_eq!(join!(json!([])), json!(""));
 /// assert_eq!(join!(json!([{},[],[1,[2,[3]]]])), json!("serde_json::map::Map<alloc::string::String, serde_json::value::Value>,,1,2,3"));
 /// assert_eq!(join!(json!({})), json!(""));
+/// assert_eq!(join!(vec![1, 2], "-"), json!("1-2"));
 /// ```
 #[macro_export]
 macro_rules! join {
@@ -101,12 +125,12 @@ macro_rules! join {
         json!("")
     };
     ($a:expr $(,)*) => {
-        $crate::join($a, ",")
+        $crate::join::<$crate::lib::Value>($a, ",")
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::join($a, $b)
+        $crate::join::<$crate::lib::Value>($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::join($a, $b)
+        $crate::join::<$crate::lib::Value>($a, $b)
     };
 }
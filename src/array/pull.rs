@@ -1,28 +1,30 @@
 use crate::lib::{Value};
+use crate::{EqualityMode, ValueKey};
 
 /// See lodash [pull](https://lodash.com/docs/#pull)
+///
+/// Values are compared by [EqualityMode::SameValueZero].
 pub fn pull(mut array: Value, value: Value) -> Value {
-    let new_vec = match array {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return array
-        }
-        Value::Array(ref vec) => {
-            match value {
-                Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => (),
-                Value::Array(_) | Value::Object(_) => return array,
-            }
-            let mut new_vec = vec![];
-            for item in vec.iter() {
-                if item != &value {
-                    new_vec.push(item.clone())
-                }
-            }
-            new_vec
-        }
-    };
-    *array.as_array_mut().unwrap() = new_vec;
+    pull_mut(&mut array, value);
     array
 }
+/// Same as [pull()], but changes `array` in place like lodash, a value which isn't an array is left as is
+pub fn pull_mut(array: &mut Value, value: Value) {
+    pull_in_mut(EqualityMode::SameValueZero, array, value)
+}
+/// Same as [pull()], but values are compared by `mode`
+pub fn pull_in(mode: EqualityMode, mut array: Value, value: Value) -> Value {
+    pull_in_mut(mode, &mut array, value);
+    array
+}
+/// Same as [pull_mut()], but values are compared by `mode`
+pub fn pull_in_mut(mode: EqualityMode, array: &mut Value, value: Value) {
+    if let Value::Array(vec) = array {
+        if let Some(key) = ValueKey::new(&value, mode) {
+            vec.retain(|v| ValueKey::new(v, mode).as_ref() != Some(&key));
+        }
+    }
+}
 
 /// Based on [pull()]
 ///
@@ -52,6 +54,7 @@ pub fn pull(mut array: Value, value: Value) -> Value {
 /// assert_eq!(pull!(json!([])), json!([]));
 /// assert_eq!(pull!(json!([[]]), json!([])), json!([[]]));
 /// assert_eq!(pull!(json!([{}]), json!({})), json!([{}]));
+/// assert_eq!(pull!(json!([1, 1.0, 2]), json!(1)), json!([2]));
 /// assert_eq!(pull!(json!({})), json!({}));
 /// ```
 #[macro_export]
@@ -69,3 +72,140 @@ macro_rules! pull {
         $crate::pull!($crate::pull($a, $b), $($rest)*)
     };
 }
+/// Based on [pull_mut()]
+///
+/// Takes any number of values, which are all removed in one pass like [pull_all_mut()](crate::pull_all_mut()).
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let mut array = json!(['a', 'b', 'c', 'a', 'b', 'c']);
+/// pull_mut!(&mut array, json!('a'), json!('c'));
+/// assert_eq!(
+///   array,
+///   json!(['b', 'b'])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// let mut array = json!([1, 2, 1.0]);
+/// pull_mut!(&mut array);
+/// assert_eq!(array, json!([1, 2, 1.0]));
+/// pull_mut!(&mut array, json!(1));
+/// assert_eq!(array, json!([2]));
+/// let mut array = json!([[1], {}]);
+/// pull_mut!(&mut array, json!([1]), json!({}));
+/// assert_eq!(array, json!([[1], {}]));
+/// let mut object = json!({"a": 1});
+/// pull_mut!(&mut object, json!(1));
+/// assert_eq!(object, json!({"a": 1}));
+/// ```
+#[macro_export]
+macro_rules! pull_mut {
+    () => {};
+    ($a:expr $(,)*) => {{
+        let _ = $a;
+    }};
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::pull_mut($a, $b)
+    };
+    ($a:expr, $($rest:expr),+ $(,)*) => {
+        $crate::pull_all_mut($a, $crate::lib::Value::Array(vec![$($rest),+]))
+    };
+}
+/// Based on [pull_in()]
+///
+/// Takes any number of values, which are all removed in one pass like [pull_all_in()](crate::pull_all_in()).
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   pull_in!(EqualityMode::Structural, json!([['a'], ['b'], ['c'], ['a']]), json!(['a']), json!(['c'])),
+///   json!([['b']])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(pull_in!(EqualityMode::Structural), json!(null));
+/// assert_eq!(pull_in!(EqualityMode::Structural, json!(0)), json!(0));
+/// assert_eq!(pull_in!(EqualityMode::Structural, json!([{}, [1], 1]), json!([1.0])), json!([{}, 1]));
+/// assert_eq!(pull_in!(EqualityMode::SameValueZero, json!([{}, 1]), json!({}), json!(1)), json!([{}]));
+/// ```
+#[macro_export]
+macro_rules! pull_in {
+    ($m:expr $(,)*) => {
+        json!(null)
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $a
+    };
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::pull_in($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $($rest:expr),+ $(,)*) => {
+        $crate::pull_all_in($m, $a, $crate::lib::Value::Array(vec![$($rest),+]))
+    };
+}
+/// Based on [pull_in_mut()]
+///
+/// Takes any number of values, which are all removed in one pass like [pull_all_in_mut()](crate::pull_all_in_mut()).
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// let mut array = json!([['a'], ['b'], ['c'], ['a']]);
+/// pull_in_mut!(EqualityMode::Structural, &mut array, json!(['a']), json!(['c']));
+/// assert_eq!(
+///   array,
+///   json!([['b']])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// let mut array = json!([{}, [1], 1]);
+/// pull_in_mut!(EqualityMode::Structural);
+/// pull_in_mut!(EqualityMode::Structural, &mut array);
+/// assert_eq!(array, json!([{}, [1], 1]));
+/// pull_in_mut!(EqualityMode::SameValueZero, &mut array, json!({}), json!(1));
+/// assert_eq!(array, json!([{}, [1]]));
+/// pull_in_mut!(EqualityMode::Structural, &mut array, json!({}));
+/// assert_eq!(array, json!([[1]]));
+/// ```
+#[macro_export]
+macro_rules! pull_in_mut {
+    ($m:expr $(,)*) => {{
+        let _ = $m;
+    }};
+    ($m:expr, $a:expr $(,)*) => {{
+        let _ = ($m, $a);
+    }};
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::pull_in_mut($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $($rest:expr),+ $(,)*) => {
+        $crate::pull_all_in_mut($m, $a, $crate::lib::Value::Array(vec![$($rest),+]))
+    };
+}
@@ -1,35 +1,29 @@
 use crate::lib::{Value};
+use crate::internal::base_difference;
+use crate::{EqualityMode, ValueKey};
 
 /// See lodash [pullAll](https://lodash.com/docs/#pullAll)
+///
+/// Values are compared by [EqualityMode::SameValueZero], in O(n + m).
 pub fn pull_all(mut array: Value, values: Value) -> Value {
-    let new_vec = match array {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return array
-        }
-        Value::Array(ref vec) => {
-            let values_vec = match values {
-                Value::Null
-                | Value::Bool(_)
-                | Value::Number(_)
-                | Value::String(_)
-                | Value::Object(_) => return array,
-                Value::Array(vec) => vec,
-            };
-            let mut new_vec = vec![];
-            'a: for item in vec.iter() {
-                for value in values_vec.iter() {
-                    if item == value {
-                        continue 'a;
-                    }
-                }
-                new_vec.push(item.clone())
-            }
-            new_vec
-        }
-    };
-    *array.as_array_mut().unwrap() = new_vec;
+    pull_all_mut(&mut array, values);
+    array
+}
+/// Same as [pull_all()], but changes `array` in place like lodash, a value which isn't an array is left as is
+pub fn pull_all_mut(array: &mut Value, values: Value) {
+    pull_all_in_mut(EqualityMode::SameValueZero, array, values)
+}
+/// Same as [pull_all()], but values are compared by `mode`
+pub fn pull_all_in(mode: EqualityMode, mut array: Value, values: Value) -> Value {
+    pull_all_in_mut(mode, &mut array, values);
     array
 }
+/// Same as [pull_all_mut()], but values are compared by `mode`
+pub fn pull_all_in_mut(mode: EqualityMode, array: &mut Value, values: Value) {
+    if let (Value::Array(vec), Value::Array(values)) = (array, values) {
+        *vec = base_difference(std::mem::take(vec), &values, |v| ValueKey::new(v, mode));
+    }
+}
 
 /// Based on [pull_all()]
 ///
@@ -62,6 +56,8 @@ pub fn pull_all(mut array: Value, values: Value) -> Value {
 /// assert_eq!(pull_all!(json!([{}]), json!({})), json!([{}]));
 /// assert_eq!(pull_all!(json!([null]), json!([null])), json!([]));
 /// assert_eq!(pull_all!(json!([null,0]), json!([null]), json!([0])), json!([0]));
+/// assert_eq!(pull_all!(json!([[1], {"a": 1}]), json!([[1], {"a": 1}])), json!([[1], {"a": 1}]));
+/// assert_eq!(pull_all!(json!([1, 1.0, 2]), json!([1])), json!([2]));
 /// assert_eq!(pull_all!(json!({})), json!({}));
 /// ```
 #[macro_export]
@@ -79,3 +75,134 @@ macro_rules! pull_all {
         $crate::pull_all($a, $b)
     };
 }
+/// Based on [pull_all_mut()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let mut array = json!(['a', 'b', 'c', 'a', 'b', 'c']);
+/// pull_all_mut!(&mut array, json!(['a', 'c']));
+/// assert_eq!(
+///   array,
+///   json!(['b', 'b'])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// let mut array = json!([1, 2, 3]);
+/// pull_all_mut!(&mut array);
+/// pull_all_mut!(&mut array, json!(1));
+/// assert_eq!(array, json!([1, 2, 3]));
+/// pull_all_mut!(&mut array, json!([1.0, 3]));
+/// assert_eq!(array, json!([2]));
+/// let mut object = json!({"a": 1});
+/// pull_all_mut!(&mut object, json!([1]));
+/// assert_eq!(object, json!({"a": 1}));
+/// ```
+#[macro_export]
+macro_rules! pull_all_mut {
+    () => {};
+    ($a:expr $(,)*) => {{
+        let _ = $a;
+    }};
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::pull_all_mut($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::pull_all_mut($a, $b)
+    };
+}
+/// Based on [pull_all_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   pull_all_in!(EqualityMode::Structural, json!([['a'], ['b'], ['a']]), json!([['a']])),
+///   json!([['b']])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(pull_all_in!(EqualityMode::Structural), json!(null));
+/// assert_eq!(pull_all_in!(EqualityMode::Structural, json!(0)), json!(0));
+/// assert_eq!(pull_all_in!(EqualityMode::Structural, json!([{}]), json!({})), json!([{}]));
+/// assert_eq!(pull_all_in!(EqualityMode::Structural, json!([[1], {"a": 1}, 1]), json!([[1.0], {"a": 1}])), json!([1]));
+/// assert_eq!(pull_all_in!(EqualityMode::SameValueZero, json!([[1], 1]), json!([[1], 1])), json!([[1]]));
+/// ```
+#[macro_export]
+macro_rules! pull_all_in {
+    ($m:expr $(,)*) => {
+        json!(null)
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $a
+    };
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::pull_all_in($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::pull_all_in($m, $a, $b)
+    };
+}
+/// Based on [pull_all_in_mut()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// let mut array = json!([['a'], ['b'], ['a']]);
+/// pull_all_in_mut!(EqualityMode::Structural, &mut array, json!([['a']]));
+/// assert_eq!(
+///   array,
+///   json!([['b']])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// let mut array = json!([{}, [], 1]);
+/// pull_all_in_mut!(EqualityMode::Structural);
+/// pull_all_in_mut!(EqualityMode::Structural, &mut array);
+/// pull_all_in_mut!(EqualityMode::Structural, &mut array, json!({}));
+/// assert_eq!(array, json!([{}, [], 1]));
+/// pull_all_in_mut!(EqualityMode::SameValueZero, &mut array, json!([{}, 1]));
+/// assert_eq!(array, json!([{}, []]));
+/// pull_all_in_mut!(EqualityMode::Structural, &mut array, json!([{}]));
+/// assert_eq!(array, json!([[]]));
+/// ```
+#[macro_export]
+macro_rules! pull_all_in_mut {
+    ($m:expr $(,)*) => {{
+        let _ = $m;
+    }};
+    ($m:expr, $a:expr $(,)*) => {{
+        let _ = ($m, $a);
+    }};
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::pull_all_in_mut($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::pull_all_in_mut($m, $a, $b)
+    };
+}
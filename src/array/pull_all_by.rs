@@ -1,37 +1,27 @@
 use crate::lib::{Value};
+use crate::internal::base_difference;
+use crate::{EqualityMode, Iteratee, ValueKey};
 
 /// See lodash [pullAllBy](https://lodash.com/docs/#pullAllBy)
-pub fn pull_all_by(mut array: Value, values: Value, iteratee: fn(&Value) -> &Value) -> Value {
-    let new_vec = match array {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return array
-        }
-        Value::Array(ref vec) => {
-            let values_vec = match values {
-                Value::Null
-                | Value::Bool(_)
-                | Value::Number(_)
-                | Value::String(_)
-                | Value::Object(_) => return array,
-                Value::Array(vec) => vec,
-            };
-            let mut new_vec = vec![];
-            'a: for item in vec.iter() {
-                let id_item = iteratee(item);
-                for value in values_vec.iter() {
-                    let id_value = iteratee(value);
-                    if id_item == id_value {
-                        continue 'a;
-                    }
-                }
-                new_vec.push(item.clone())
-            }
-            new_vec
-        }
-    };
-    *array.as_array_mut().unwrap() = new_vec;
+///
+/// Same as [pull_all()](crate::pull_all()), but compares the computed values, in O(n + m).
+///
+/// `iteratee` is an [Iteratee](crate::Iteratee), so the property shorthand like `"x"` works too.
+/// It used to be a `fn(&Value) -> &Value`: a closure like `|o| &o["x"]` now has to give an owned value,
+/// e.g. `|o: &Value| o["x"].clone()`.
+pub fn pull_all_by<I: Iteratee>(mut array: Value, values: Value, iteratee: I) -> Value {
+    pull_all_by_mut(&mut array, values, iteratee);
     array
 }
+/// Same as [pull_all_by()], but changes `array` in place like lodash, a value which isn't an array is left as is
+pub fn pull_all_by_mut<I: Iteratee>(array: &mut Value, values: Value, iteratee: I) {
+    if let (Value::Array(vec), Value::Array(values)) = (array, values) {
+        let mode = EqualityMode::SameValueZero;
+        *vec = base_difference(std::mem::take(vec), &values, |v| {
+            ValueKey::new(&iteratee.call(v), mode)
+        });
+    }
+}
 
 /// Based on [pull_all_by()]
 ///
@@ -39,15 +29,14 @@ pub fn pull_all_by(mut array: Value, values: Value, iteratee: fn(&Value) -> &Val
 ///
 /// ```rust
 /// #[macro_use] extern crate serde_json_lodash;
-/// use serde_json::json;
+/// use serde_json::{json, Value};
 /// let array = json!([{ 'x': 1 }, { 'x': 2 }, { 'x': 3 }, { 'x': 1 }]);
-/// // conflict with fn, not implemented
-/// // assert_eq!(
-/// //   pull_all_by!(array, json!([{ 'x': 1 }, { 'x': 3 }]), "x"),
-/// //   json!(0)
-/// // );
 /// assert_eq!(
-///   pull_all_by!(array, json!([{ 'x': 1 }, { 'x': 3 }]), |o| &o["x"]),
+///   pull_all_by!(array.clone(), json!([{ 'x': 1 }, { 'x': 3 }]), "x"),
+///   json!([{ 'x': 2 }])
+/// );
+/// assert_eq!(
+///   pull_all_by!(array, json!([{ 'x': 1 }, { 'x': 3 }]), |o: &Value| o["x"].clone()),
 ///   json!([{ 'x': 2 }])
 /// );
 /// ```
@@ -56,7 +45,7 @@ pub fn pull_all_by(mut array: Value, values: Value, iteratee: fn(&Value) -> &Val
 ///
 /// ```rust
 /// # #[macro_use] extern crate serde_json_lodash;
-/// # use serde_json::json;
+/// # use serde_json::{json, Value};
 /// assert_eq!(pull_all_by!(), json!(null));
 /// assert_eq!(pull_all_by!(json!(null)), json!(null));
 /// assert_eq!(pull_all_by!(json!(false)), json!(false));
@@ -66,8 +55,9 @@ pub fn pull_all_by(mut array: Value, values: Value, iteratee: fn(&Value) -> &Val
 /// assert_eq!(pull_all_by!(json!([[]]), json!([])), json!([[]]));
 /// assert_eq!(pull_all_by!(json!([{}]), json!({})), json!([{}]));
 /// assert_eq!(pull_all_by!(json!([null]), json!([null])), json!([]));
-/// assert_eq!(pull_all_by!(json!([null,0]), json!([null]), |x| &x), json!([0]));
-/// assert_eq!(pull_all_by!(json!([null,0]), json!([null]), |x| &x["__non__"]), json!([]));
+/// assert_eq!(pull_all_by!(json!([null,0]), json!([null]), |x: &Value| x.clone()), json!([0]));
+/// assert_eq!(pull_all_by!(json!([null,0]), json!([null]), |x: &Value| x["__non__"].clone()), json!([]));
+/// assert_eq!(pull_all_by!(json!([{"x": 1}, {"x": 3}]), json!([{"x": 1}]), |o: &Value| o["x"].clone()), json!([{"x": 3}]));
 /// assert_eq!(pull_all_by!(json!({})), json!({}));
 /// ```
 #[macro_export]
@@ -88,3 +78,57 @@ macro_rules! pull_all_by {
         $crate::pull_all_by($a, $b, $c)
     };
 }
+/// Based on [pull_all_by_mut()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let mut array = json!([{ 'x': 1 }, { 'x': 2 }, { 'x': 3 }, { 'x': 1 }]);
+/// pull_all_by_mut!(&mut array, json!([{ 'x': 1 }, { 'x': 3 }]), "x");
+/// assert_eq!(
+///   array,
+///   json!([{ 'x': 2 }])
+/// );
+/// let mut array = json!([{ 'x': 1 }, { 'x': 2 }, { 'x': 3 }, { 'x': 1 }]);
+/// pull_all_by_mut!(&mut array, json!([{ 'x': 1 }, { 'x': 3 }]), |o: &Value| o["x"].clone());
+/// assert_eq!(
+///   array,
+///   json!([{ 'x': 2 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// let mut array = json!([{"x": 1}, {"x": 2}]);
+/// pull_all_by_mut!(&mut array);
+/// pull_all_by_mut!(&mut array, json!([{"x": 1}]));
+/// assert_eq!(array, json!([{"x": 1}, {"x": 2}]));
+/// pull_all_by_mut!(&mut array, json!([{"x": 2.0}]), |o: &Value| o["x"].clone());
+/// assert_eq!(array, json!([{"x": 1}]));
+/// let mut object = json!({"x": 1});
+/// pull_all_by_mut!(&mut object, json!([{"x": 1}]), |o: &Value| o["x"].clone());
+/// assert_eq!(object, json!({"x": 1}));
+/// pull_all_by_mut!(&mut object, json!([{"x": 1}]), "x");
+/// assert_eq!(object, json!({"x": 1}));
+/// ```
+#[macro_export]
+macro_rules! pull_all_by_mut {
+    () => {};
+    ($a:expr $(,)*) => {{
+        let _ = $a;
+    }};
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::pull_all_mut($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::pull_all_by_mut($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::pull_all_by_mut($a, $b, $c)
+    };
+}
@@ -1,39 +1,26 @@
 use crate::lib::{Value};
 
 /// See lodash [pullAllWith](https://lodash.com/docs/#pullAllWith)
-pub fn pull_all_with(
+///
+/// Same as [pull_all()](crate::pull_all()), but compares the items with `comparator`, in O(n × m).
+pub fn pull_all_with<C: Fn(&Value, &Value) -> bool>(
     mut array: Value,
     values: Value,
-    comparator: fn(&Value, &Value) -> bool,
+    comparator: C,
 ) -> Value {
-    let new_vec = match array {
-        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
-            return array
-        }
-        Value::Array(ref vec) => {
-            let values_vec = match values {
-                Value::Null
-                | Value::Bool(_)
-                | Value::Number(_)
-                | Value::String(_)
-                | Value::Object(_) => return array,
-                Value::Array(vec) => vec,
-            };
-            let mut new_vec = vec![];
-            'a: for item in vec.iter() {
-                for value in values_vec.iter() {
-                    if comparator(item, value) {
-                        continue 'a;
-                    }
-                }
-                new_vec.push(item.clone())
-            }
-            new_vec
-        }
-    };
-    *array.as_array_mut().unwrap() = new_vec;
+    pull_all_with_mut(&mut array, values, comparator);
     array
 }
+/// Same as [pull_all_with()], but changes `array` in place like lodash, a value which isn't an array is left as is
+pub fn pull_all_with_mut<C: Fn(&Value, &Value) -> bool>(
+    array: &mut Value,
+    values: Value,
+    comparator: C,
+) {
+    if let (Value::Array(vec), Value::Array(values)) = (array, values) {
+        vec.retain(|item| !values.iter().any(|value| comparator(item, value)));
+    }
+}
 
 /// Based on [pull_all_with()]
 ///
@@ -65,6 +52,11 @@ pub fn pull_all_with(
 /// assert_eq!(pull_all_with!(json!([null]), json!([null])), json!([]));
 /// assert_eq!(pull_all_with!(json!([null,0]), json!([null]), |_, _| false), json!([null,0]));
 /// assert_eq!(pull_all_with!(json!([null,0]), json!([null]), |a, _| a == 0), json!([null]));
+/// let key = "x";
+/// assert_eq!(
+///   pull_all_with!(json!([{"x": 1}, {"x": 2}]), json!([{"x": 2, "y": 0}]), |a, b| a[key] == b[key]),
+///   json!([{"x": 1}])
+/// );
 /// assert_eq!(pull_all_with!(json!({})), json!({}));
 /// ```
 #[macro_export]
@@ -85,3 +77,54 @@ macro_rules! pull_all_with {
         $crate::pull_all_with($a, $b, $c)
     };
 }
+/// Based on [pull_all_with_mut()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let mut array = json!([{ 'x': 1, 'y': 2 }, { 'x': 3, 'y': 4 }, { 'x': 5, 'y': 6 }]);
+/// pull_all_with_mut!(&mut array, json!([{ 'x': 3, 'y': 4 }]), |a, b| a == b);
+/// assert_eq!(
+///   array,
+///   json!([{ 'x': 1, 'y': 2 }, { 'x': 5, 'y': 6 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// let mut array = json!([null, 0, [1]]);
+/// pull_all_with_mut!(&mut array);
+/// pull_all_with_mut!(&mut array, json!([[1]]));
+/// assert_eq!(array, json!([null, 0, [1]]));
+/// pull_all_with_mut!(&mut array, json!([[1]]), |a, b| a == b);
+/// assert_eq!(array, json!([null, 0]));
+/// pull_all_with_mut!(&mut array, json!([null]), |a, _| a == 0);
+/// assert_eq!(array, json!([null]));
+/// let nothing = json!(null);
+/// pull_all_with_mut!(&mut array, json!([0]), |a, _| a == &nothing);
+/// assert_eq!(array, json!([]));
+/// let mut object = json!({"a": 1});
+/// pull_all_with_mut!(&mut object, json!([1]), |_, _| true);
+/// assert_eq!(object, json!({"a": 1}));
+/// ```
+#[macro_export]
+macro_rules! pull_all_with_mut {
+    () => {};
+    ($a:expr $(,)*) => {{
+        let _ = $a;
+    }};
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::pull_all_mut($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::pull_all_with_mut($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::pull_all_with_mut($a, $b, $c)
+    };
+}
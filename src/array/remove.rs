@@ -0,0 +1,71 @@
+use crate::lib::{json, Value};
+use crate::internal::value_is_truthy;
+use crate::Iteratee;
+
+/// See lodash [remove](https://lodash.com/docs/#remove)
+///
+/// Removes the items matching `predicate` from `array` in place, and returns them,
+/// an item matches when the computed value is truthy. A value which isn't an array is left as is.
+pub fn remove<I: Iteratee>(array: &mut Value, predicate: I) -> Value {
+    let vec = match array {
+        Value::Array(vec) => vec,
+        _ => return json!([]),
+    };
+    let (removed, kept) = std::mem::take(vec)
+        .into_iter()
+        .partition(|v| value_is_truthy(&predicate.call(v)));
+    *vec = kept;
+    Value::Array(removed)
+}
+
+/// Based on [remove()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let mut array = json!([1, 2, 3, 4]);
+/// let evens = remove!(&mut array, |n: &Value| json!(n.as_i64().unwrap() % 2 == 0));
+/// assert_eq!(
+///   array,
+///   json!([1, 3])
+/// );
+/// assert_eq!(
+///   evens,
+///   json!([2, 4])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(remove!(), json!([]));
+/// let mut array = json!([1, 0, null, "a"]);
+/// assert_eq!(remove!(&mut array), json!([]));
+/// assert_eq!(array, json!([1, 0, null, "a"]));
+/// let mut users = json!([{"user": "a", "active": true}, {"user": "b"}, {"user": "c", "active": 1}]);
+/// assert_eq!(remove!(&mut users, "active"), json!([{"user": "a", "active": true}, {"user": "c", "active": 1}]));
+/// assert_eq!(users, json!([{"user": "b"}]));
+/// let mut object = json!({"a": 1});
+/// assert_eq!(remove!(&mut object, |_: &Value| json!(true)), json!([]));
+/// assert_eq!(object, json!({"a": 1}));
+/// ```
+#[macro_export]
+macro_rules! remove {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {{
+        let _ = $a;
+        json!([])
+    }};
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::remove($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::remove($a, $b)
+    };
+}
@@ -0,0 +1,52 @@
+use crate::lib::{Value};
+
+/// See lodash [reverse](https://lodash.com/docs/#reverse)
+///
+/// Reverses `array` in place, a value which isn't an array is left as is.
+pub fn reverse(array: &mut Value) {
+    if let Value::Array(vec) = array {
+        vec.reverse();
+    }
+}
+
+/// Based on [reverse()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let mut array = json!([1, 2, 3]);
+/// reverse!(&mut array);
+/// assert_eq!(
+///   array,
+///   json!([3, 2, 1])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// reverse!();
+/// let mut array = json!([]);
+/// reverse!(&mut array);
+/// assert_eq!(array, json!([]));
+/// let mut array = json!([[1, 2], {"a": 1}]);
+/// reverse!(&mut array);
+/// assert_eq!(array, json!([{"a": 1}, [1, 2]]));
+/// let mut string = json!("abc");
+/// reverse!(&mut string);
+/// assert_eq!(string, json!("abc"));
+/// ```
+#[macro_export]
+macro_rules! reverse {
+    () => {};
+    ($a:expr $(,)*) => {
+        $crate::reverse($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::reverse($a)
+    };
+}
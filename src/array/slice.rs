@@ -0,0 +1,71 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_slice, relative_index};
+
+/// See lodash [slice](https://lodash.com/docs/#slice)
+///
+/// A negative `start` or `end` counts from the end of the array.
+pub fn slice(array: Value, start: isize, end: isize) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let len = vec.len();
+            Value::Array(base_slice(
+                vec,
+                relative_index(start, len),
+                relative_index(end, len),
+            ))
+        }
+        _ => json!([]),
+    }
+}
+
+/// Based on [slice()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   slice!(json!([1, 2, 3, 4]), 1, 3),
+///   json!([2, 3])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(slice!(), json!([]));
+/// assert_eq!(slice!(json!(null)), json!([]));
+/// assert_eq!(slice!(json!("abc"), 1), json!([]));
+/// assert_eq!(slice!(json!({"a": 1})), json!([]));
+/// assert_eq!(slice!(json!([1, 2, 3])), json!([1, 2, 3]));
+/// assert_eq!(slice!(json!([1, 2, 3]), 1), json!([2, 3]));
+/// assert_eq!(slice!(json!([1, 2, 3]), -1), json!([3]));
+/// assert_eq!(slice!(json!([1, 2, 3]), -5), json!([1, 2, 3]));
+/// assert_eq!(slice!(json!([1, 2, 3]), 5), json!([]));
+/// assert_eq!(slice!(json!([1, 2, 3]), 0, -1), json!([1, 2]));
+/// assert_eq!(slice!(json!([1, 2, 3]), -2, -1), json!([2]));
+/// assert_eq!(slice!(json!([1, 2, 3]), 2, 1), json!([]));
+/// assert_eq!(slice!(json!([1, 2, 3]), 1, 10), json!([2, 3]));
+/// assert_eq!(slice!(json!([1, 2, 3]), isize::MIN, isize::MAX), json!([1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! slice {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::slice($a, 0, isize::MAX)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::slice($a, $b, isize::MAX)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::slice($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::slice($a, $b, $c)
+    };
+}
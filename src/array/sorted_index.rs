@@ -0,0 +1,103 @@
+use crate::lib::{Value};
+use crate::internal::base_sorted_index;
+use crate::IntoValue;
+
+///
+#[deprecated(note = "use `sorted_index()`, it takes a `Vec<Value>` too")]
+pub fn x_sorted_index(array: &[Value], value: &Value) -> usize {
+    base_sorted_index(array, value, false)
+}
+/// See lodash [sortedIndex](https://lodash.com/docs/#sortedIndex)
+///
+/// A binary search in O(log n), so `array` must be sorted by this order: booleans, numbers, strings,
+/// arrays, objects, then `null`. Numbers compare numerically, strings by UTF-16 code units,
+/// arrays item by item and objects entry by entry, in key order.
+pub fn sorted_index(array: impl IntoValue, value: impl IntoValue) -> usize {
+    match array.into_value() {
+        Value::Array(vec) => base_sorted_index(&vec, &value.into_value(), false),
+        _ => 0_usize,
+    }
+}
+
+/// Based on [x_sorted_index()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let array = vec![json!(30), json!(50)];
+/// assert_eq!(x_sorted_index!(&array, &json!(40)), 1);
+/// assert_eq!(x_sorted_index!(&array), 2);
+/// ```
+#[deprecated(note = "use `sorted_index()`, it takes a `Vec<Value>` too")]
+#[macro_export]
+macro_rules! x_sorted_index {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index($a, &json!(null));
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index($a, $b);
+        result
+    }};
+}
+/// Based on [sorted_index()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   sorted_index!(json!([30, 50]), json!(40)),
+///   1
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(sorted_index!(), 0);
+/// assert_eq!(sorted_index!(json!(null), json!(1)), 0);
+/// assert_eq!(sorted_index!(json!([1, 2])), 2);
+/// assert_eq!(sorted_index!(json!([1, 2, null])), 2);
+/// assert_eq!(sorted_index!(json!([]), json!(1)), 0);
+/// assert_eq!(sorted_index!(json!([1, 1, 1.0, 2]), json!(1)), 0);
+/// assert_eq!(sorted_index!(json!([1, 2]), json!(1.5)), 1);
+/// assert_eq!(sorted_index!(json!(["a", "c"]), json!("b")), 1);
+/// assert_eq!(sorted_index!(json!([false, 0, "", [], {}, null]), json!("a")), 3);
+/// assert_eq!(sorted_index!(json!([false, 0, "", [], {}, null]), json!(true)), 1);
+/// assert_eq!(sorted_index!(json!([[1], [1, 0], [2]]), json!([1, 0])), 1);
+/// assert_eq!(sorted_index!(json!([{"a": 1}, {"a": 1, "b": 0}, {"b": 0}]), json!({"a": 2})), 2);
+/// let big: Vec<_> = (0..100_000).collect();
+/// assert_eq!(sorted_index!(json!(big), json!(76_543)), 76_543);
+/// assert_eq!(sorted_index!(vec![30, 50], 40), 1);
+/// ```
+#[macro_export]
+macro_rules! sorted_index {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_index($a, json!(null))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_index($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sorted_index($a, $b)
+    };
+}
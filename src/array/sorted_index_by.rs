@@ -0,0 +1,119 @@
+use crate::lib::{Value};
+use crate::internal::value_sort_cmp;
+use crate::{IntoValue, Iteratee};
+use std::cmp::Ordering;
+
+///
+#[deprecated(note = "use `sorted_index_by()`, it takes a `Vec<Value>` too")]
+pub fn x_sorted_index_by<I: Iteratee>(array: &[Value], value: &Value, iteratee: I) -> usize {
+    sorted_index_by(array.to_vec(), value.clone(), iteratee)
+}
+/// See lodash [sortedIndexBy](https://lodash.com/docs/#sortedIndexBy)
+///
+/// Same as [sorted_index()](crate::sorted_index()), but compares the computed values, `iteratee` is called O(log n) times.
+pub fn sorted_index_by<I: Iteratee>(
+    array: impl IntoValue,
+    value: impl IntoValue,
+    iteratee: I,
+) -> usize {
+    match array.into_value() {
+        Value::Array(vec) => {
+            let computed = iteratee.call(&value.into_value());
+            vec.partition_point(|v| value_sort_cmp(&iteratee.call(v), &computed) == Ordering::Less)
+        }
+        _ => 0,
+    }
+}
+
+/// Based on [x_sorted_index_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json::Value;
+/// let mut records: Vec<Value> = vec![];
+/// for record in vec![json!({"id": 3}), json!({"id": 1}), json!({"id": 2})] {
+///   let index = x_sorted_index_by!(&records, &record, "id");
+///   records.insert(index, record);
+/// }
+/// assert_eq!(records, vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})]);
+/// ```
+#[deprecated(note = "use `sorted_index_by()`, it takes a `Vec<Value>` too")]
+#[macro_export]
+macro_rules! x_sorted_index_by {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index($a, &json!(null));
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index_by($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_index_by($a, $b, $c);
+        result
+    }};
+}
+/// Based on [sorted_index_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 4 }, { "x": 5 }]);
+/// assert_eq!(
+///   sorted_index_by!(objects.clone(), json!({ "x": 4 }), |o: &Value| o["x"].clone()),
+///   0
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   sorted_index_by!(objects, json!({ "x": 4 }), "x"),
+///   0
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(sorted_index_by!(), 0);
+/// assert_eq!(sorted_index_by!(json!([1, 2]), json!(2)), 1);
+/// assert_eq!(sorted_index_by!(json!(null), json!({"x": 1}), "x"), 0);
+/// assert_eq!(sorted_index_by!(json!([{"x": 1}, {"x": 3}]), json!({"x": 2}), "x"), 1);
+/// assert_eq!(sorted_index_by!(json!([{"x": 1}, {}]), json!({"x": 2}), "x"), 1);
+/// assert_eq!(sorted_index_by!(json!(["b", "ccc"]), json!("aa"), |s: &Value| json!(s.as_str().unwrap().len())), 1);
+/// assert_eq!(sorted_index_by!(vec![json!({"x": 4})], json!({"x": 5}), "x"), 1);
+/// ```
+#[macro_export]
+macro_rules! sorted_index_by {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_index($a, json!(null))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_index($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::sorted_index_by($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::sorted_index_by($a, $b, $c)
+    };
+}
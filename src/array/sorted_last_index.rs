@@ -0,0 +1,92 @@
+use crate::lib::{Value};
+use crate::internal::base_sorted_index;
+use crate::IntoValue;
+
+///
+#[deprecated(note = "use `sorted_last_index()`, it takes a `Vec<Value>` too")]
+pub fn x_sorted_last_index(array: &[Value], value: &Value) -> usize {
+    base_sorted_index(array, value, true)
+}
+/// See lodash [sortedLastIndex](https://lodash.com/docs/#sortedLastIndex)
+///
+/// Same as [sorted_index()](crate::sorted_index()), but gives the index after the last equal item.
+pub fn sorted_last_index(array: impl IntoValue, value: impl IntoValue) -> usize {
+    match array.into_value() {
+        Value::Array(vec) => base_sorted_index(&vec, &value.into_value(), true),
+        _ => 0_usize,
+    }
+}
+
+/// Based on [x_sorted_last_index()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let array = vec![json!(4), json!(5), json!(5), json!(5), json!(6)];
+/// assert_eq!(x_sorted_last_index!(&array, &json!(5)), 4);
+/// ```
+#[deprecated(note = "use `sorted_last_index()`, it takes a `Vec<Value>` too")]
+#[macro_export]
+macro_rules! x_sorted_last_index {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index($a, &json!(null));
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index($a, $b);
+        result
+    }};
+}
+/// Based on [sorted_last_index()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   sorted_last_index!(json!([4, 5, 5, 5, 6]), json!(5)),
+///   4
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(sorted_last_index!(), 0);
+/// assert_eq!(sorted_last_index!(json!(null), json!(1)), 0);
+/// assert_eq!(sorted_last_index!(json!([1, null])), 2);
+/// assert_eq!(sorted_last_index!(json!([1, 1.0, 2]), json!(1)), 2);
+/// assert_eq!(sorted_last_index!(json!(["a", "b", "b"]), json!("b")), 3);
+/// assert_eq!(sorted_last_index!(json!([true, 1, "1"]), json!(1)), 2);
+/// assert_eq!(sorted_last_index!(vec![4, 5, 5, 6], 5), 3);
+/// ```
+#[macro_export]
+macro_rules! sorted_last_index {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_last_index($a, json!(null))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_last_index($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sorted_last_index($a, $b)
+    };
+}
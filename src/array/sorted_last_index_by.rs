@@ -0,0 +1,115 @@
+use crate::lib::{Value};
+use crate::internal::value_sort_cmp;
+use crate::{IntoValue, Iteratee};
+use std::cmp::Ordering;
+
+///
+#[deprecated(note = "use `sorted_last_index_by()`, it takes a `Vec<Value>` too")]
+pub fn x_sorted_last_index_by<I: Iteratee>(array: &[Value], value: &Value, iteratee: I) -> usize {
+    sorted_last_index_by(array.to_vec(), value.clone(), iteratee)
+}
+/// See lodash [sortedLastIndexBy](https://lodash.com/docs/#sortedLastIndexBy)
+///
+/// Same as [sorted_last_index()](crate::sorted_last_index()), but compares the computed values, `iteratee` is called O(log n) times.
+pub fn sorted_last_index_by<I: Iteratee>(
+    array: impl IntoValue,
+    value: impl IntoValue,
+    iteratee: I,
+) -> usize {
+    match array.into_value() {
+        Value::Array(vec) => {
+            let computed = iteratee.call(&value.into_value());
+            vec.partition_point(|v| {
+                value_sort_cmp(&iteratee.call(v), &computed) != Ordering::Greater
+            })
+        }
+        _ => 0,
+    }
+}
+
+/// Based on [x_sorted_last_index_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let array = vec![json!({"x": 4}), json!({"x": 5})];
+/// assert_eq!(x_sorted_last_index_by!(&array, &json!({"x": 4}), "x"), 1);
+/// ```
+#[deprecated(note = "use `sorted_last_index_by()`, it takes a `Vec<Value>` too")]
+#[macro_export]
+macro_rules! x_sorted_last_index_by {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index($a, &json!(null));
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index_by($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index_by($a, $b, $c);
+        result
+    }};
+}
+/// Based on [sorted_last_index_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 4 }, { "x": 5 }]);
+/// assert_eq!(
+///   sorted_last_index_by!(objects.clone(), json!({ "x": 4 }), |o: &Value| o["x"].clone()),
+///   1
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   sorted_last_index_by!(objects, json!({ "x": 4 }), "x"),
+///   1
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(sorted_last_index_by!(), 0);
+/// assert_eq!(sorted_last_index_by!(json!([1, 2]), json!(1)), 1);
+/// assert_eq!(sorted_last_index_by!(json!(null), json!({"x": 1}), "x"), 0);
+/// assert_eq!(sorted_last_index_by!(json!([{"x": 1}, {"x": 1.0}, {"x": 3}]), json!({"x": 1}), "x"), 2);
+/// assert_eq!(sorted_last_index_by!(json!([{"x": 1}, {}]), json!({}), "x"), 2);
+/// assert_eq!(sorted_last_index_by!(vec![json!({"x": 4})], json!({"x": 4}), "x"), 1);
+/// ```
+#[macro_export]
+macro_rules! sorted_last_index_by {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_last_index($a, json!(null))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_last_index($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::sorted_last_index_by($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::sorted_last_index_by($a, $b, $c)
+    };
+}
@@ -0,0 +1,154 @@
+use crate::lib::{Value};
+use crate::internal::{base_sorted_index, value_sort_cmp};
+use crate::{EqualityMode, IntoValue, ValueKey};
+use std::cmp::Ordering;
+
+fn x_sorted_last_index_of_in(mode: EqualityMode, array: &[Value], value: &Value) -> isize {
+    let index = base_sorted_index(array, value, true);
+    match index.checked_sub(1).and_then(|i| array.get(i)) {
+        Some(v)
+            if value_sort_cmp(v, value) == Ordering::Equal && ValueKey::new(v, mode).is_some() =>
+        {
+            index as isize - 1
+        }
+        _ => -1,
+    }
+}
+/// See lodash [sortedLastIndexOf](https://lodash.com/docs/#sortedLastIndexOf)
+///
+/// Same as [sorted_index_of()](crate::sorted_index_of()), but gives the last equal item.
+pub fn sorted_last_index_of(array: impl IntoValue, value: impl IntoValue) -> isize {
+    sorted_last_index_of_in(
+        EqualityMode::SameValueZero,
+        array.into_value(),
+        value.into_value(),
+    )
+}
+///
+#[deprecated(note = "use `sorted_last_index_of()`, it takes a `Vec<Value>` too")]
+pub fn x_sorted_last_index_of(array: &[Value], value: &Value) -> isize {
+    x_sorted_last_index_of_in(EqualityMode::SameValueZero, array, value)
+}
+/// Same as [sorted_last_index_of()], but values are compared by `mode`
+pub fn sorted_last_index_of_in(mode: EqualityMode, array: Value, value: Value) -> isize {
+    match array {
+        Value::Array(vec) => x_sorted_last_index_of_in(mode, &vec, &value),
+        _ => -1_isize,
+    }
+}
+
+/// Based on [x_sorted_last_index_of()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let array = vec![json!(4), json!(5), json!(5), json!(5), json!(6)];
+/// assert_eq!(x_sorted_last_index_of!(&array, &json!(5)), 3);
+/// ```
+#[deprecated(note = "use `sorted_last_index_of()`, it takes a `Vec<Value>` too")]
+#[macro_export]
+macro_rules! x_sorted_last_index_of {
+    () => {
+        -1_isize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index_of($a, &json!(null));
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index_of($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_sorted_last_index_of($a, $b);
+        result
+    }};
+}
+/// Based on [sorted_last_index_of()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   sorted_last_index_of!(json!([4, 5, 5, 5, 6]), json!(5)),
+///   3
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(sorted_last_index_of!(), -1);
+/// assert_eq!(sorted_last_index_of!(json!(null), json!(1)), -1);
+/// assert_eq!(sorted_last_index_of!(json!([null, null])), 1);
+/// assert_eq!(sorted_last_index_of!(json!([1, 2])), -1);
+/// assert_eq!(sorted_last_index_of!(json!([1, 1.0, 2]), json!(1)), 1);
+/// assert_eq!(sorted_last_index_of!(json!([1, 2]), json!(0)), -1);
+/// assert_eq!(sorted_last_index_of!(json!([{}]), json!({})), -1);
+/// assert_eq!(sorted_last_index_of!(vec![1, 1, 2], 1), 1);
+/// ```
+#[macro_export]
+macro_rules! sorted_last_index_of {
+    () => {
+        -1_isize
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_last_index_of($a, json!(null))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_last_index_of($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sorted_last_index_of($a, $b)
+    };
+}
+/// Based on [sorted_last_index_of_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   sorted_last_index_of_in!(EqualityMode::Structural, json!([[4], [5], [5.0], [6]]), json!([5])),
+///   2
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(sorted_last_index_of_in!(EqualityMode::Structural), -1);
+/// assert_eq!(sorted_last_index_of_in!(EqualityMode::Structural, json!(null), json!(1)), -1);
+/// assert_eq!(sorted_last_index_of_in!(EqualityMode::Structural, json!([1, null])), 1);
+/// assert_eq!(sorted_last_index_of_in!(EqualityMode::Structural, json!([[1], [2]]), json!([3])), -1);
+/// assert_eq!(sorted_last_index_of_in!(EqualityMode::SameValueZero, json!([[1], [2]]), json!([2])), -1);
+/// ```
+#[macro_export]
+macro_rules! sorted_last_index_of_in {
+    ($m:expr $(,)*) => {
+        -1_isize
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $crate::sorted_last_index_of_in($m, $a, json!(null))
+    };
+    ($m:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::sorted_last_index_of_in($m, $a, $b)
+    };
+    ($m:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sorted_last_index_of_in($m, $a, $b)
+    };
+}
@@ -0,0 +1,94 @@
+use crate::lib::{Value};
+use crate::internal::base_sorted_uniq;
+use crate::{EqualityMode, ValueKey};
+
+/// See lodash [sortedUniq](https://lodash.com/docs/#sortedUniq)
+///
+/// Same as [uniq()](crate::uniq()), but only removes an item equal to the one before it,
+/// which is all of the duplicates of a sorted array, in O(n) without hashing.
+pub fn sorted_uniq(array: Value) -> Value {
+    sorted_uniq_in(EqualityMode::SameValueZero, array)
+}
+/// Same as [sorted_uniq()], but values are compared by `mode`
+pub fn sorted_uniq_in(mode: EqualityMode, array: Value) -> Value {
+    match array {
+        Value::Array(vec) => Value::Array(base_sorted_uniq(vec, |v| ValueKey::new(v, mode))),
+        _ => Value::Array(vec![]),
+    }
+}
+
+/// Based on [sorted_uniq()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   sorted_uniq!(json!([1, 1, 2])),
+///   json!([1, 2])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(sorted_uniq!(), json!([]));
+/// assert_eq!(sorted_uniq!(json!(null)), json!([]));
+/// assert_eq!(sorted_uniq!(json!({"a": 1})), json!([]));
+/// assert_eq!(sorted_uniq!(json!([1, 1.0, 2, 2, 3])), json!([1, 2, 3]));
+/// assert_eq!(sorted_uniq!(json!(["a", "a", "b"])), json!(["a", "b"]));
+/// assert_eq!(sorted_uniq!(json!([1, 2, 1])), json!([1, 2, 1]));
+/// assert_eq!(sorted_uniq!(json!([[], []])), json!([[], []]));
+/// ```
+#[macro_export]
+macro_rules! sorted_uniq {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_uniq($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::sorted_uniq($a)
+    };
+}
+/// Based on [sorted_uniq_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   sorted_uniq_in!(EqualityMode::Structural, json!([[1], [1.0], [2]])),
+///   json!([[1], [2]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(sorted_uniq_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(sorted_uniq_in!(EqualityMode::Structural, json!(null)), json!([]));
+/// assert_eq!(sorted_uniq_in!(EqualityMode::Structural, json!([{}, {}, [], {}])), json!([{}, [], {}]));
+/// assert_eq!(sorted_uniq_in!(EqualityMode::SameValueZero, json!([{}, {}, 1, 1])), json!([{}, {}, 1]));
+/// ```
+#[macro_export]
+macro_rules! sorted_uniq_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $crate::sorted_uniq_in($m, $a)
+    };
+    ($m:expr, $a:expr, $($rest:tt)*) => {
+        $crate::sorted_uniq_in($m, $a)
+    };
+}
@@ -0,0 +1,58 @@
+use crate::lib::{Value};
+use crate::internal::base_sorted_uniq;
+use crate::{EqualityMode, Iteratee, ValueKey};
+
+/// See lodash [sortedUniqBy](https://lodash.com/docs/#sortedUniqBy)
+///
+/// Same as [sorted_uniq()](crate::sorted_uniq()), but compares the computed values.
+pub fn sorted_uniq_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let mode = EqualityMode::SameValueZero;
+            Value::Array(base_sorted_uniq(vec, |v| {
+                ValueKey::new(&iteratee.call(v), mode)
+            }))
+        }
+        _ => Value::Array(vec![]),
+    }
+}
+
+/// Based on [sorted_uniq_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   sorted_uniq_by!(json!([1.1, 1.2, 2.3, 2.4]), |n: &Value| floor!(n.clone())),
+///   json!([1.1, 2.3])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(sorted_uniq_by!(), json!([]));
+/// assert_eq!(sorted_uniq_by!(json!(null)), json!([]));
+/// assert_eq!(sorted_uniq_by!(json!([1, 1, 2])), json!([1, 2]));
+/// assert_eq!(sorted_uniq_by!(json!([{"x": 1}, {"x": 1}, {"x": 2}, {"x": 1}]), "x"), json!([{"x": 1}, {"x": 2}, {"x": 1}]));
+/// assert_eq!(sorted_uniq_by!(json!([1, 2]), |_: &Value| json!({})), json!([1, 2]));
+/// ```
+#[macro_export]
+macro_rules! sorted_uniq_by {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::sorted_uniq($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sorted_uniq_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sorted_uniq_by($a, $b)
+    };
+}
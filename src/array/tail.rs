@@ -0,0 +1,51 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_slice, clamp_count};
+
+/// See lodash [tail](https://lodash.com/docs/#tail)
+pub fn tail(array: Value) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let len = vec.len();
+            Value::Array(base_slice(vec, clamp_count(1, len), len))
+        }
+        _ => json!([]),
+    }
+}
+
+/// Based on [tail()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   tail!(json!([1, 2, 3])),
+///   json!([2, 3])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(tail!(), json!([]));
+/// assert_eq!(tail!(json!(null)), json!([]));
+/// assert_eq!(tail!(json!("abc")), json!([]));
+/// assert_eq!(tail!(json!({"a": 1})), json!([]));
+/// assert_eq!(tail!(json!([])), json!([]));
+/// assert_eq!(tail!(json!([1])), json!([]));
+/// ```
+#[macro_export]
+macro_rules! tail {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::tail($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::tail($a)
+    };
+}
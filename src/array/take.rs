@@ -0,0 +1,68 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_slice, clamp_count};
+
+/// See lodash [take](https://lodash.com/docs/#take)
+///
+/// A negative `n` takes nothing.
+pub fn take(array: Value, n: isize) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let len = vec.len();
+            Value::Array(base_slice(vec, 0, clamp_count(n, len)))
+        }
+        _ => json!([]),
+    }
+}
+
+/// Based on [take()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   take!(json!([1, 2, 3])),
+///   json!([1])
+/// );
+/// assert_eq!(
+///   take!(json!([1, 2, 3]), 2),
+///   json!([1, 2])
+/// );
+/// assert_eq!(
+///   take!(json!([1, 2, 3]), 5),
+///   json!([1, 2, 3])
+/// );
+/// assert_eq!(
+///   take!(json!([1, 2, 3]), 0),
+///   json!([])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(take!(), json!([]));
+/// assert_eq!(take!(json!(null)), json!([]));
+/// assert_eq!(take!(json!("abc"), 2), json!([]));
+/// assert_eq!(take!(json!({"a": 1})), json!([]));
+/// assert_eq!(take!(json!([])), json!([]));
+/// assert_eq!(take!(json!([1, 2, 3]), -1), json!([]));
+/// ```
+#[macro_export]
+macro_rules! take {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::take($a, 1)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::take($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::take($a, $b)
+    };
+}
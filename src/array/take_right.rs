@@ -0,0 +1,68 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_slice, clamp_count};
+
+/// See lodash [takeRight](https://lodash.com/docs/#takeRight)
+///
+/// A negative `n` takes nothing.
+pub fn take_right(array: Value, n: isize) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let len = vec.len();
+            Value::Array(base_slice(vec, len - clamp_count(n, len), len))
+        }
+        _ => json!([]),
+    }
+}
+
+/// Based on [take_right()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   take_right!(json!([1, 2, 3])),
+///   json!([3])
+/// );
+/// assert_eq!(
+///   take_right!(json!([1, 2, 3]), 2),
+///   json!([2, 3])
+/// );
+/// assert_eq!(
+///   take_right!(json!([1, 2, 3]), 5),
+///   json!([1, 2, 3])
+/// );
+/// assert_eq!(
+///   take_right!(json!([1, 2, 3]), 0),
+///   json!([])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(take_right!(), json!([]));
+/// assert_eq!(take_right!(json!(null)), json!([]));
+/// assert_eq!(take_right!(json!("abc"), 2), json!([]));
+/// assert_eq!(take_right!(json!({"a": 1})), json!([]));
+/// assert_eq!(take_right!(json!([])), json!([]));
+/// assert_eq!(take_right!(json!([1, 2, 3]), -1), json!([]));
+/// ```
+#[macro_export]
+macro_rules! take_right {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::take_right($a, 1)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::take_right($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::take_right($a, $b)
+    };
+}
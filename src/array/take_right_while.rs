@@ -0,0 +1,116 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_slice, base_while};
+use crate::Iteratee;
+
+/// See lodash [takeRightWhile](https://lodash.com/docs/#takeRightWhile)
+///
+/// Takes the items from the end while they match `predicate`, an item matches when the computed value is truthy.
+pub fn take_right_while<I: Iteratee>(array: Value, predicate: I) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let (start, end) = base_while(&vec, predicate, false, true);
+            Value::Array(base_slice(vec, start, end))
+        }
+        _ => json!([]),
+    }
+}
+/// Same as [take_right_while()], but changes `array` in place, a value which isn't an array is left as is
+pub fn take_right_while_mut<I: Iteratee>(array: &mut Value, predicate: I) {
+    if let Value::Array(vec) = array {
+        let (start, end) = base_while(vec, predicate, false, true);
+        vec.truncate(end);
+        vec.drain(..start);
+    }
+}
+
+/// Based on [take_right_while()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let users = json!([
+///   { "user": "barney",  "active": true },
+///   { "user": "fred",    "active": false },
+///   { "user": "pebbles", "active": false }
+/// ]);
+/// assert_eq!(
+///   take_right_while!(users.clone(), |o: &Value| json!(!o["active"].as_bool().unwrap())),
+///   json!([{ "user": "fred", "active": false }, { "user": "pebbles", "active": false }])
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   take_right_while!(users, "active"),
+///   json!([])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(take_right_while!(), json!([]));
+/// assert_eq!(take_right_while!(json!(null)), json!([]));
+/// assert_eq!(take_right_while!(json!("abc"), |_: &Value| json!(true)), json!([]));
+/// assert_eq!(take_right_while!(json!({"a": 1}), "a"), json!([]));
+/// assert_eq!(take_right_while!(json!([]), "a"), json!([]));
+/// assert_eq!(take_right_while!(json!([1, 0, 2, 3])), json!([2, 3]));
+/// assert_eq!(take_right_while!(json!([1, 2]), |_: &Value| json!(1)), json!([1, 2]));
+/// assert_eq!(take_right_while!(json!([{"a": {}}, {"a": {"b": 1}}]), "a.b"), json!([{"a": {"b": 1}}]));
+/// ```
+#[macro_export]
+macro_rules! take_right_while {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::take_right_while($a, |v: &$crate::lib::Value| v.clone())
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::take_right_while($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::take_right_while($a, $b)
+    };
+}
+/// Based on [take_right_while_mut()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let logs = json!([{"ts": 1, "msg": "a"}, {"ts": 5, "msg": "b"}, {"ts": 9, "msg": "c"}]);
+/// // Keeps the records after the last one before 5
+/// let mut recent = logs.clone();
+/// take_right_while_mut!(&mut recent, |log: &Value| json!(log["ts"].as_i64() >= Some(5)));
+/// assert_eq!(recent, json!([{"ts": 5, "msg": "b"}, {"ts": 9, "msg": "c"}]));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// let mut array = json!([1, 0, 1]);
+/// take_right_while_mut!(&mut array);
+/// assert_eq!(array, take_right_while!(json!([1, 0, 1])));
+/// let mut object = json!({"a": 1});
+/// take_right_while_mut!(&mut object, "a");
+/// assert_eq!(object, json!({"a": 1}));
+/// ```
+#[macro_export]
+macro_rules! take_right_while_mut {
+    () => {};
+    ($a:expr $(,)*) => {
+        $crate::take_right_while_mut($a, |v: &$crate::lib::Value| v.clone())
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::take_right_while_mut($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::take_right_while_mut($a, $b)
+    };
+}
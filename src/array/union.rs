@@ -0,0 +1,96 @@
+use crate::lib::{Value};
+use crate::internal::{base_uniq, flatten_arrays};
+use crate::{EqualityMode, ValueKey};
+
+#[doc(hidden)]
+pub fn _union_all(arrays: Vec<Value>) -> Value {
+    _union_in_all(EqualityMode::SameValueZero, arrays)
+}
+#[doc(hidden)]
+pub fn _union_in_all(mode: EqualityMode, arrays: Vec<Value>) -> Value {
+    Value::Array(base_uniq(flatten_arrays(arrays), |v| {
+        ValueKey::new(v, mode)
+    }))
+}
+/// See lodash [union](https://lodash.com/docs/#union)
+///
+/// Values are compared by [EqualityMode::SameValueZero], in O(n + m), and the first of equal values is kept.
+/// [union!](crate::union!) takes any number of arrays.
+pub fn union(v1: Value, v2: Value) -> Value {
+    _union_all(vec![v1, v2])
+}
+/// Same as [union()], but values are compared by `mode`
+pub fn union_in(mode: EqualityMode, v1: Value, v2: Value) -> Value {
+    _union_in_all(mode, vec![v1, v2])
+}
+
+/// Based on [union()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   union!(json!([2]), json!([1, 2])),
+///   json!([2, 1])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(union!(), json!([]));
+/// assert_eq!(union!(json!(null)), json!([]));
+/// assert_eq!(union!(json!([1, 1, 2])), json!([1, 2]));
+/// assert_eq!(union!(json!([1]), json!(2), json!({"a": 3})), json!([1]));
+/// assert_eq!(union!(json!([3, 1]), json!([1.0, 2]), json!([2, 4]), json!([0])), json!([3, 1, 2, 4, 0]));
+/// assert_eq!(union!(json!([null, [], {}]), json!([null, [], {}])), json!([null, [], {}, [], {}]));
+/// let big: Vec<_> = (0..100_000).collect();
+/// assert_eq!(union!(json!(big), json!(big)), json!(big));
+/// ```
+#[macro_export]
+macro_rules! union {
+    () => {
+        json!([])
+    };
+    ($($a:expr),+ $(,)*) => {
+        $crate::_union_all(vec![$($a),+])
+    };
+}
+/// Based on [union_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   union_in!(EqualityMode::Structural, json!([[2]]), json!([[1], [2]])),
+///   json!([[2], [1]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(union_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(union_in!(EqualityMode::Structural, json!([{}, {}])), json!([{}]));
+/// assert_eq!(union_in!(EqualityMode::Structural, json!([1]), json!(2), json!([{"a": 1}]), json!([{"a": 1.0}])), json!([1, {"a": 1}]));
+/// assert_eq!(union_in!(EqualityMode::SameValueZero, json!([{}]), json!([{}])), json!([{}, {}]));
+/// ```
+#[macro_export]
+macro_rules! union_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $($a:expr),+ $(,)*) => {
+        $crate::_union_in_all($m, vec![$($a),+])
+    };
+}
@@ -0,0 +1,63 @@
+use crate::lib::{Value};
+use crate::internal::flatten_arrays;
+use crate::uniq_with;
+
+#[doc(hidden)]
+pub fn _union_with_all<C: Fn(&Value, &Value) -> bool>(arrays: Vec<Value>, comparator: C) -> Value {
+    uniq_with(Value::Array(flatten_arrays(arrays)), comparator)
+}
+/// See lodash [unionWith](https://lodash.com/docs/#unionWith)
+///
+/// Same as [union()](crate::union()), but compares the items with `comparator`, in O(n²).
+/// [union_with!](crate::union_with!) takes any number of arrays, followed by the comparator.
+pub fn union_with<C: Fn(&Value, &Value) -> bool>(v1: Value, v2: Value, comparator: C) -> Value {
+    _union_with_all(vec![v1, v2], comparator)
+}
+
+/// Based on [union_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }]);
+/// let others = json!([{ "x": 1, "y": 1 }, { "x": 1, "y": 2 }]);
+/// assert_eq!(
+///   union_with!(objects, others, |a: &Value, b: &Value| a == b),
+///   json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }, { "x": 1, "y": 1 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(union_with!(), json!([]));
+/// assert_eq!(union_with!(json!([1, 1])), json!([1]));
+/// assert_eq!(union_with!(json!([1, 2]), |_: &Value, _: &Value| true), json!([1]));
+/// assert_eq!(union_with!(json!([1]), json!(2), |a: &Value, b: &Value| a == b), json!([1]));
+/// assert_eq!(
+///   union_with!(json!([[1]]), json!([[2]]), json!([[1], [3]]), |a: &Value, b: &Value| a == b),
+///   json!([[1], [2], [3]])
+/// );
+/// ```
+#[macro_export]
+macro_rules! union_with {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {
+        $crate::_union_with_all(vec![$($arrays),*], $f)
+    };
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::union_with!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::_union_all(vec![$a])
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::union_with!(@arrays [$a] $($rest)+)
+    };
+}
@@ -0,0 +1,95 @@
+use crate::lib::{Value};
+use crate::internal::base_uniq;
+use crate::{EqualityMode, ValueKey};
+
+/// See lodash [uniq](https://lodash.com/docs/#uniq)
+///
+/// Values are compared by [EqualityMode::SameValueZero], in O(n).
+pub fn uniq(array: Value) -> Value {
+    uniq_in(EqualityMode::SameValueZero, array)
+}
+/// Same as [uniq()], but values are compared by `mode`
+pub fn uniq_in(mode: EqualityMode, array: Value) -> Value {
+    match array {
+        Value::Array(vec) => Value::Array(base_uniq(vec, |v| ValueKey::new(v, mode))),
+        _ => Value::Array(vec![]),
+    }
+}
+
+/// Based on [uniq()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   uniq!(json!([2, 1, 2])),
+///   json!([2, 1])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(uniq!(), json!([]));
+/// assert_eq!(uniq!(json!(null)), json!([]));
+/// assert_eq!(uniq!(json!("aa")), json!([]));
+/// assert_eq!(uniq!(json!({"a": 1})), json!([]));
+/// assert_eq!(uniq!(json!([])), json!([]));
+/// assert_eq!(uniq!(json!([null, null, false, 0, "", "0", 0.0, -0.0])), json!([null, false, 0, "", "0"]));
+/// assert_eq!(uniq!(json!([[], [], {}, {}])), json!([[], [], {}, {}]));
+/// let big: Vec<_> = (0..100_000).map(|n| n % 3).collect();
+/// assert_eq!(uniq!(json!(big)), json!([0, 1, 2]));
+/// ```
+#[macro_export]
+macro_rules! uniq {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::uniq($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::uniq($a)
+    };
+}
+/// Based on [uniq_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   uniq_in!(EqualityMode::Structural, json!([[2], [1], [2.0]])),
+///   json!([[2], [1]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(uniq_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(uniq_in!(EqualityMode::Structural, json!(null)), json!([]));
+/// assert_eq!(uniq_in!(EqualityMode::Structural, json!([{}, {}, 1, 1.0])), json!([{}, 1]));
+/// assert_eq!(uniq_in!(EqualityMode::SameValueZero, json!([{}, {}, 1, 1.0])), json!([{}, {}, 1]));
+/// ```
+#[macro_export]
+macro_rules! uniq_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $crate::uniq_in($m, $a)
+    };
+    ($m:expr, $a:expr, $($rest:tt)*) => {
+        $crate::uniq_in($m, $a)
+    };
+}
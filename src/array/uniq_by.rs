@@ -0,0 +1,64 @@
+use crate::lib::{Value};
+use crate::internal::base_uniq;
+use crate::{EqualityMode, Iteratee, ValueKey};
+
+/// See lodash [uniqBy](https://lodash.com/docs/#uniqBy)
+///
+/// Same as [uniq()](crate::uniq()), but compares the computed values, the first item of each computed value is kept.
+pub fn uniq_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let mode = EqualityMode::SameValueZero;
+            Value::Array(base_uniq(vec, |v| ValueKey::new(&iteratee.call(v), mode)))
+        }
+        _ => Value::Array(vec![]),
+    }
+}
+
+/// Based on [uniq_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   uniq_by!(json!([2.1, 1.2, 2.3]), |n: &Value| floor!(n.clone())),
+///   json!([2.1, 1.2])
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   uniq_by!(json!([{ "x": 1 }, { "x": 2 }, { "x": 1 }]), "x"),
+///   json!([{ "x": 1 }, { "x": 2 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(uniq_by!(), json!([]));
+/// assert_eq!(uniq_by!(json!(null)), json!([]));
+/// assert_eq!(uniq_by!(json!([1, 1.0, 2])), json!([1, 2]));
+/// assert_eq!(uniq_by!(json!({"x": 1}), "x"), json!([]));
+/// assert_eq!(uniq_by!(json!([{}, {"y": 1}, {"x": null}]), "x"), json!([{}]));
+/// assert_eq!(uniq_by!(json!([{"a": {"b": 1}}, {"a": {"b": 1.0}}]), "a.b"), json!([{"a": {"b": 1}}]));
+/// assert_eq!(uniq_by!(json!(["a", "bb", "c"]), |s: &Value| json!(s.as_str().unwrap().len())), json!(["a", "bb"]));
+/// assert_eq!(uniq_by!(json!([1, 2]), |_: &Value| json!([])), json!([1, 2]));
+/// ```
+#[macro_export]
+macro_rules! uniq_by {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::uniq($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::uniq_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::uniq_by($a, $b)
+    };
+}
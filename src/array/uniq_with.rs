@@ -0,0 +1,64 @@
+use crate::lib::{Value};
+
+/// See lodash [uniqWith](https://lodash.com/docs/#uniqWith)
+///
+/// The comparator is called with an item and each item kept before it, so this is O(n²).
+pub fn uniq_with<C: Fn(&Value, &Value) -> bool>(array: Value, comparator: C) -> Value {
+    match array {
+        Value::Array(vec) => {
+            let mut result: Vec<Value> = vec![];
+            for v in vec {
+                if !result.iter().any(|seen| comparator(&v, seen)) {
+                    result.push(v)
+                }
+            }
+            Value::Array(result)
+        }
+        _ => Value::Array(vec![]),
+    }
+}
+
+/// Based on [uniq_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }, { "x": 1, "y": 2 }]);
+/// assert_eq!(
+///   uniq_with!(objects, |a: &Value, b: &Value| a == b),
+///   json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(uniq_with!(), json!([]));
+/// assert_eq!(uniq_with!(json!(null)), json!([]));
+/// assert_eq!(uniq_with!(json!([1, 1, [], []])), json!([1, [], []]));
+/// assert_eq!(uniq_with!(json!([1, 2]), |_: &Value, _: &Value| false), json!([1, 2]));
+/// assert_eq!(uniq_with!(json!([1, 2, 3]), |_: &Value, _: &Value| true), json!([1]));
+/// assert_eq!(
+///   uniq_with!(json!(["a", "A", "b"]), |a: &Value, b: &Value| to_lower!(a.clone()) == to_lower!(b.clone())),
+///   json!(["a", "b"])
+/// );
+/// ```
+#[macro_export]
+macro_rules! uniq_with {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::uniq($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::uniq_with($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::uniq_with($a, $b)
+    };
+}
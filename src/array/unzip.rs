@@ -0,0 +1,135 @@
+use crate::lib::{Value};
+use crate::internal::value_undefined;
+use crate::{FromValue, IntoValue};
+
+fn base_unzip(array: Value) -> Vec<Vec<Value>> {
+    let groups: Vec<Vec<Value>> = match array {
+        Value::Array(vec) => vec
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::Array(group) => Some(group),
+                _ => None,
+            })
+            .collect(),
+        _ => return vec![],
+    };
+    let len = groups.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result: Vec<Vec<Value>> = (0..len).map(|_| Vec::with_capacity(groups.len())).collect();
+    for group in groups {
+        let mut group = group.into_iter();
+        for item in result.iter_mut() {
+            item.push(group.next().unwrap_or_else(value_undefined));
+        }
+    }
+    result
+}
+///
+#[deprecated(note = "use `unzip::<Vec<Vec<Value>>>()`")]
+pub fn unzip_x(array: Value) -> Vec<Vec<Value>> {
+    unzip(array)
+}
+/// See lodash [unzip](https://lodash.com/docs/#unzip)
+///
+/// Groups shorter than the longest one are padded with `null`, and items which aren't arrays are skipped.
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<Vec<Value>>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::unzip;
+/// assert_eq!(unzip::<Vec<Vec<Value>>>(json!([["a", 1], ["b", 2]])), vec![vec![json!("a"), json!("b")], vec![json!(1), json!(2)]]);
+/// assert_eq!(unzip::<Value>(vec![vec![1, 2], vec![3]]), json!([[1, 3], [2, null]]));
+/// ```
+pub fn unzip<R: FromValue>(array: impl IntoValue) -> R {
+    R::from_value(Value::Array(
+        base_unzip(array.into_value())
+            .into_iter()
+            .map(Value::Array)
+            .collect(),
+    ))
+}
+
+/// Based on [unzip_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   unzip_x!(json!([["a", 1, true], ["b", 2, false]])),
+///   vec![vec![json!("a"), json!("b")], vec![json!(1), json!(2)], vec![json!(true), json!(false)]]
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// let expect: Vec<Vec<Value>> = vec![];
+/// assert_eq!(unzip_x!(), expect);
+/// assert_eq!(unzip_x!(json!([[1], [2]])), vec![vec![json!(1), json!(2)]]);
+/// ```
+#[deprecated(note = "use `unzip::<Vec<Vec<Value>>>()`")]
+#[macro_export]
+macro_rules! unzip_x {
+    () => {
+        Vec::<Vec<$crate::lib::Value>>::new()
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::unzip_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::unzip_x($a);
+        result
+    }};
+}
+/// Based on [unzip()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let zipped = zip!(json!(["a", "b"]), json!([1, 2]), json!([true, false]));
+/// assert_eq!(
+///   zipped,
+///   json!([["a", 1, true], ["b", 2, false]])
+/// );
+/// assert_eq!(
+///   unzip!(zipped),
+///   json!([["a", "b"], [1, 2], [true, false]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(unzip!(), json!([]));
+/// assert_eq!(unzip!(json!(null)), json!([]));
+/// assert_eq!(unzip!(json!({"a": [1]})), json!([]));
+/// assert_eq!(unzip!(json!([])), json!([]));
+/// assert_eq!(unzip!(json!([[], []])), json!([]));
+/// assert_eq!(unzip!(json!([[1, 2, 3], 0, [4]])), json!([[1, 4], [2, null], [3, null]]));
+/// assert_eq!(unzip!(json!([[1, [2]], [3, [4]]])), json!([[1, 3], [[2], [4]]]));
+/// ```
+#[macro_export]
+macro_rules! unzip {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::unzip::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::unzip::<$crate::lib::Value>($a)
+    };
+}
@@ -0,0 +1,60 @@
+use crate::lib::{Value};
+use crate::unzip;
+
+/// See lodash [unzipWith](https://lodash.com/docs/#unzipWith)
+///
+/// Same as [unzip()](crate::unzip()), but each regrouped array is combined by `iteratee`.
+pub fn unzip_with<F: Fn(&[Value]) -> Value>(array: Value, iteratee: F) -> Value {
+    Value::Array(
+        unzip::<Vec<Vec<Value>>>(array)
+            .iter()
+            .map(|group| iteratee(group))
+            .collect(),
+    )
+}
+
+/// Based on [unzip_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let zipped = zip!(json!([1, 2]), json!([10, 20]), json!([100, 200]));
+/// assert_eq!(
+///   zipped,
+///   json!([[1, 10, 100], [2, 20, 200]])
+/// );
+/// assert_eq!(
+///   unzip_with!(zipped, |group: &[Value]| add!(group[0].clone(), group[1].clone())),
+///   json!([3, 30, 300])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(unzip_with!(), json!([]));
+/// assert_eq!(unzip_with!(json!([[1, 2], [3]])), json!([[1, 3], [2, null]]));
+/// assert_eq!(unzip_with!(json!(null), |_: &[Value]| json!(1)), json!([]));
+/// assert_eq!(unzip_with!(json!([[1, 2], [3]]), |group: &[Value]| json!(group.len())), json!([2, 2]));
+/// let sum = js_fn!(|a, b| add!(a, b));
+/// assert_eq!(unzip_with!(json!([[1, 2], [3]]), |group: &[Value]| sum.call(group)), json!([4, 2]));
+/// ```
+#[macro_export]
+macro_rules! unzip_with {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::unzip::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::unzip_with($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::unzip_with($a, $b)
+    };
+}
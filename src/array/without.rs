@@ -0,0 +1,101 @@
+use crate::lib::{json, Value};
+use crate::internal::base_difference;
+use crate::{EqualityMode, ValueKey};
+
+/// See lodash [without](https://lodash.com/docs/#without)
+///
+/// Same as [pull_all()](crate::pull_all()), but returns a new array, and `[]` for a value which isn't an array.
+/// [without!](crate::without!) takes the values to exclude one by one, like lodash.
+pub fn without(array: Value, values: Value) -> Value {
+    without_in(EqualityMode::SameValueZero, array, values)
+}
+/// Same as [without()], but values are compared by `mode`
+pub fn without_in(mode: EqualityMode, array: Value, values: Value) -> Value {
+    let vec = match array {
+        Value::Array(vec) => vec,
+        _ => return json!([]),
+    };
+    let values = match values {
+        Value::Array(values) => values,
+        _ => vec![],
+    };
+    Value::Array(base_difference(vec, &values, |v| ValueKey::new(v, mode)))
+}
+
+/// Based on [without()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   without!(json!([2, 1, 2, 3]), json!(1), json!(2)),
+///   json!([3])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(without!(), json!([]));
+/// assert_eq!(without!(json!(null)), json!([]));
+/// assert_eq!(without!(json!("abc"), json!("a")), json!([]));
+/// assert_eq!(without!(json!({"a": 1}), json!(1)), json!([]));
+/// assert_eq!(without!(json!([1, 2])), json!([1, 2]));
+/// assert_eq!(without!(json!([1, 2.0, 3]), json!(2)), json!([1, 3]));
+/// assert_eq!(without!(json!([[1], {}]), json!([1]), json!({})), json!([[1], {}]));
+/// assert_eq!(without!(json!([null, 0, ""]), json!(null)), json!([0, ""]));
+/// ```
+#[macro_export]
+macro_rules! without {
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::without($a, json!([]))
+    };
+    ($a:expr, $($rest:expr),+ $(,)*) => {
+        $crate::without($a, $crate::lib::Value::Array(vec![$($rest),+]))
+    };
+}
+/// Based on [without_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   without_in!(EqualityMode::Structural, json!([[2], [1], [2], [3]]), json!([1]), json!([2])),
+///   json!([[3]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(without_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(without_in!(EqualityMode::Structural, json!(null)), json!([]));
+/// assert_eq!(without_in!(EqualityMode::Structural, json!([{}, 1])), json!([{}, 1]));
+/// assert_eq!(without_in!(EqualityMode::Structural, json!([{"a": 1}, {}]), json!({"a": 1.0})), json!([{}]));
+/// assert_eq!(without_in!(EqualityMode::SameValueZero, json!([{}, 1]), json!({}), json!(1)), json!([{}]));
+/// ```
+#[macro_export]
+macro_rules! without_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $a:expr $(,)*) => {
+        $crate::without_in($m, $a, json!([]))
+    };
+    ($m:expr, $a:expr, $($rest:expr),+ $(,)*) => {
+        $crate::without_in($m, $a, $crate::lib::Value::Array(vec![$($rest),+]))
+    };
+}
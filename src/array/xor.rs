@@ -0,0 +1,96 @@
+use crate::lib::{Value};
+use crate::internal::base_xor;
+use crate::{EqualityMode, ValueKey};
+
+#[doc(hidden)]
+pub fn _xor_all(arrays: Vec<Value>) -> Value {
+    _xor_in_all(EqualityMode::SameValueZero, arrays)
+}
+#[doc(hidden)]
+pub fn _xor_in_all(mode: EqualityMode, arrays: Vec<Value>) -> Value {
+    Value::Array(base_xor(arrays, |v| ValueKey::new(v, mode)))
+}
+/// See lodash [xor](https://lodash.com/docs/#xor)
+///
+/// Keeps the values which are in only one of the arrays, compared by [EqualityMode::SameValueZero], in O(n + m).
+/// [xor!](crate::xor!) takes any number of arrays.
+pub fn xor(v1: Value, v2: Value) -> Value {
+    _xor_all(vec![v1, v2])
+}
+/// Same as [xor()], but values are compared by `mode`
+pub fn xor_in(mode: EqualityMode, v1: Value, v2: Value) -> Value {
+    _xor_in_all(mode, vec![v1, v2])
+}
+
+/// Based on [xor()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   xor!(json!([2, 1]), json!([2, 3])),
+///   json!([1, 3])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(xor!(), json!([]));
+/// assert_eq!(xor!(json!(null)), json!([]));
+/// assert_eq!(xor!(json!([1, 1, 2])), json!([1, 2]));
+/// assert_eq!(xor!(json!([1, 2]), json!("a")), json!([1, 2]));
+/// assert_eq!(xor!(json!([1, 1, 2]), json!([2.0, 3, 3])), json!([1, 3]));
+/// assert_eq!(xor!(json!([1]), json!([1]), json!([1])), json!([]));
+/// assert_eq!(xor!(json!([1, 2]), json!([2, 3]), json!([3, 4])), json!([1, 4]));
+/// assert_eq!(xor!(json!([[], {}]), json!([[], {}])), json!([[], {}, [], {}]));
+/// let big: Vec<_> = (0..100_000).collect();
+/// assert_eq!(xor!(json!(big), json!(big)), json!([]));
+/// ```
+#[macro_export]
+macro_rules! xor {
+    () => {
+        json!([])
+    };
+    ($($a:expr),+ $(,)*) => {
+        $crate::_xor_all(vec![$($a),+])
+    };
+}
+/// Based on [xor_in()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(
+///   xor_in!(EqualityMode::Structural, json!([[2], [1]]), json!([[2], [3]])),
+///   json!([[1], [3]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # use serde_json_lodash::EqualityMode;
+/// assert_eq!(xor_in!(EqualityMode::Structural), json!([]));
+/// assert_eq!(xor_in!(EqualityMode::Structural, json!([{}, {}])), json!([{}]));
+/// assert_eq!(xor_in!(EqualityMode::Structural, json!([{}]), json!(1), json!([{}, []])), json!([[]]));
+/// assert_eq!(xor_in!(EqualityMode::SameValueZero, json!([{}]), json!([{}])), json!([{}, {}]));
+/// ```
+#[macro_export]
+macro_rules! xor_in {
+    ($m:expr $(,)*) => {
+        json!([])
+    };
+    ($m:expr, $($a:expr),+ $(,)*) => {
+        $crate::_xor_in_all($m, vec![$($a),+])
+    };
+}
@@ -0,0 +1,73 @@
+use crate::lib::{Value};
+use crate::internal::base_xor;
+use crate::{EqualityMode, Iteratee, ValueKey};
+
+#[doc(hidden)]
+pub fn _xor_by_all<I: Iteratee>(arrays: Vec<Value>, iteratee: I) -> Value {
+    let mode = EqualityMode::SameValueZero;
+    Value::Array(base_xor(arrays, |v| ValueKey::new(&iteratee.call(v), mode)))
+}
+/// See lodash [xorBy](https://lodash.com/docs/#xorBy)
+///
+/// Same as [xor()](crate::xor()), but compares the computed values.
+/// [xor_by!](crate::xor_by!) takes any number of arrays, followed by the iteratee,
+/// which defaults to comparing the items themselves when the last argument is an array too, like lodash.
+pub fn xor_by<I: Iteratee>(v1: Value, v2: Value, iteratee: I) -> Value {
+    _xor_by_all(vec![v1, v2], iteratee)
+}
+
+/// Based on [xor_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   xor_by!(json!([2.1, 1.2]), json!([2.3, 3.4]), |n: &Value| floor!(n.clone())),
+///   json!([1.2, 3.4])
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   xor_by!(json!([{ "x": 1 }]), json!([{ "x": 2 }, { "x": 1 }]), "x"),
+///   json!([{ "x": 2 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(xor_by!(), json!([]));
+/// // without an iteratee, the items are compared themselves like lodash
+/// assert_eq!(xor_by!(json!([2, 1]), json!([2, 3])), json!([1, 3]));
+/// assert_eq!(xor_by!(json!([2, 1]), json!([2, 3]), json!([3, 4])), json!([1, 4]));
+/// assert_eq!(xor_by!(json!([1, 1])), json!([1]));
+/// assert_eq!(xor_by!(json!([1.5, 1.2]), |n: &Value| floor!(n.clone())), json!([1.5]));
+/// assert_eq!(
+///   xor_by!(json!([{"x": 1}]), json!([{"x": 2}]), json!([{"x": 3}, {"x": 1.0}]), "x"),
+///   json!([{"x": 2}, {"x": 3}])
+/// );
+/// assert_eq!(xor_by!(json!(["a"]), json!(["b"]), |_: &Value| json!([])), json!(["a", "b"]));
+/// ```
+#[macro_export]
+macro_rules! xor_by {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {{
+        let mut arrays = vec![$($arrays),*];
+        let iteratee = $crate::internal::ByLast::split($f, &mut arrays);
+        $crate::_xor_by_all(arrays, iteratee)
+    }};
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::xor_by!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::_xor_all(vec![$a])
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::xor_by!(@arrays [$a] $($rest)+)
+    };
+}
@@ -0,0 +1,62 @@
+use crate::lib::{Value};
+use crate::internal::base_xor_with;
+
+#[doc(hidden)]
+pub fn _xor_with_all<C: Fn(&Value, &Value) -> bool>(arrays: Vec<Value>, comparator: C) -> Value {
+    Value::Array(base_xor_with(arrays, comparator))
+}
+/// See lodash [xorWith](https://lodash.com/docs/#xorWith)
+///
+/// Same as [xor()](crate::xor()), but compares the items with `comparator`, in O(n²).
+/// [xor_with!](crate::xor_with!) takes any number of arrays, followed by the comparator.
+pub fn xor_with<C: Fn(&Value, &Value) -> bool>(v1: Value, v2: Value, comparator: C) -> Value {
+    _xor_with_all(vec![v1, v2], comparator)
+}
+
+/// Based on [xor_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "x": 1, "y": 2 }, { "x": 2, "y": 1 }]);
+/// let others = json!([{ "x": 1, "y": 1 }, { "x": 1, "y": 2 }]);
+/// assert_eq!(
+///   xor_with!(objects, others, |a: &Value, b: &Value| a == b),
+///   json!([{ "x": 2, "y": 1 }, { "x": 1, "y": 1 }])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(xor_with!(), json!([]));
+/// assert_eq!(xor_with!(json!([1, 1])), json!([1]));
+/// assert_eq!(xor_with!(json!([1, 2]), |_: &Value, _: &Value| true), json!([1]));
+/// assert_eq!(xor_with!(json!([1]), json!(2), |a: &Value, b: &Value| a == b), json!([1]));
+/// assert_eq!(
+///   xor_with!(json!([[1]]), json!([[2]]), json!([[1], [3]]), |a: &Value, b: &Value| a == b),
+///   json!([[2], [3]])
+/// );
+/// ```
+#[macro_export]
+macro_rules! xor_with {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {
+        $crate::_xor_with_all(vec![$($arrays),*], $f)
+    };
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::xor_with!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::_xor_all(vec![$a])
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::xor_with!(@arrays [$a] $($rest)+)
+    };
+}
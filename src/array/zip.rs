@@ -0,0 +1,49 @@
+use crate::lib::{Value};
+use crate::unzip;
+
+#[doc(hidden)]
+pub fn _zip_all(arrays: Vec<Value>) -> Value {
+    unzip(Value::Array(arrays))
+}
+/// See lodash [zip](https://lodash.com/docs/#zip)
+///
+/// Arrays shorter than the longest one are padded with `null`, and arguments which aren't arrays are skipped.
+/// [zip!](crate::zip!) takes any number of arrays.
+pub fn zip(v1: Value, v2: Value) -> Value {
+    _zip_all(vec![v1, v2])
+}
+
+/// Based on [zip()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   zip!(json!(["a", "b"]), json!([1, 2]), json!([true, false])),
+///   json!([["a", 1, true], ["b", 2, false]])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(zip!(), json!([]));
+/// assert_eq!(zip!(json!(null)), json!([]));
+/// assert_eq!(zip!(json!([1, 2])), json!([[1], [2]]));
+/// assert_eq!(zip!(json!([1, 2]), json!("ab")), json!([[1], [2]]));
+/// assert_eq!(zip!(json!([1, 2]), json!([3])), json!([[1, 3], [2, null]]));
+/// assert_eq!(zip!(json!([]), json!([3])), json!([[null, 3]]));
+/// ```
+#[macro_export]
+macro_rules! zip {
+    () => {
+        json!([])
+    };
+    ($($a:expr),+ $(,)*) => {
+        $crate::_zip_all(vec![$($a),+])
+    };
+}
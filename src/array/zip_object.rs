@@ -0,0 +1,70 @@
+use crate::lib::{Map, Value};
+use crate::internal::value_undefined;
+use crate::FromValue;
+
+/// See lodash [zipObject](https://lodash.com/docs/#zipObject)
+///
+/// Properties which aren't strings are converted like [to_string()](crate::to_string()),
+/// and properties without a value are set to `null`.
+pub fn zip_object(props: Value, values: Value) -> Value {
+    let props = match props {
+        Value::Array(vec) => vec,
+        _ => return Value::Object(Map::new()),
+    };
+    let mut values = match values {
+        Value::Array(vec) => vec.into_iter(),
+        _ => vec![].into_iter(),
+    };
+    let mut result = Map::new();
+    for prop in props {
+        result.insert(
+            String::from_value(prop),
+            values.next().unwrap_or_else(value_undefined),
+        );
+    }
+    Value::Object(result)
+}
+
+/// Based on [zip_object()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   zip_object!(json!(["a", "b"]), json!([1, 2])),
+///   json!({ "a": 1, "b": 2 })
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(zip_object!(), json!({}));
+/// assert_eq!(zip_object!(json!(null)), json!({}));
+/// assert_eq!(zip_object!(json!(["a"])), json!({"a": null}));
+/// assert_eq!(zip_object!(json!(["a", "b"]), json!([1])), json!({"a": 1, "b": null}));
+/// assert_eq!(zip_object!(json!(["a"]), json!([1, 2])), json!({"a": 1}));
+/// assert_eq!(zip_object!(json!(["a", "a"]), json!([1, 2])), json!({"a": 2}));
+/// assert_eq!(zip_object!(json!([1, null, true]), json!([1, 2, 3])), json!({"1": 1, "": 2, "true": 3}));
+/// assert_eq!(zip_object!(json!(["a.b"]), json!([1])), json!({"a.b": 1}));
+/// assert_eq!(zip_object!(json!({"a": 1}), json!([1])), json!({}));
+/// ```
+#[macro_export]
+macro_rules! zip_object {
+    () => {
+        json!({})
+    };
+    ($a:expr $(,)*) => {
+        $crate::zip_object($a, json!([]))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::zip_object($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::zip_object($a, $b)
+    };
+}
@@ -0,0 +1,72 @@
+use crate::lib::{Map, Value};
+use crate::internal::value_undefined;
+use crate::set;
+
+/// See lodash [zipObjectDeep](https://lodash.com/docs/#zipObjectDeep)
+///
+/// Same as [zip_object()](crate::zip_object()), but the properties are paths, set like [set()](crate::set()).
+pub fn zip_object_deep(props: Value, values: Value) -> Value {
+    let props = match props {
+        Value::Array(vec) => vec,
+        _ => return Value::Object(Map::new()),
+    };
+    let mut values = match values {
+        Value::Array(vec) => vec.into_iter(),
+        _ => vec![].into_iter(),
+    };
+    props
+        .into_iter()
+        .fold(Value::Object(Map::new()), |result, path| {
+            set(result, path, values.next().unwrap_or_else(value_undefined))
+        })
+}
+
+/// Based on [zip_object_deep()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   zip_object_deep!(json!(["a.b[0].c", "a.b[1].d"]), json!([1, 2])),
+///   json!({ "a": { "b": [{ "c": 1 }, { "d": 2 }] } })
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(zip_object_deep!(), json!({}));
+/// assert_eq!(zip_object_deep!(json!(null)), json!({}));
+/// assert_eq!(zip_object_deep!(json!(["a.b"])), json!({"a": {"b": null}}));
+/// assert_eq!(zip_object_deep!(json!(["a", "b.c"]), json!([1])), json!({"a": 1, "b": {"c": null}}));
+/// assert_eq!(zip_object_deep!(json!(["a.b[0].c"]), json!([1])), json!({"a": {"b": [{"c": 1}]}}));
+/// assert_eq!(zip_object_deep!(json!([["a", "b"], "a.c"]), json!([1, 2])), json!({"a": {"b": 1, "c": 2}}));
+/// let columns = json!(["id", "user.name", "user.tags[0]", "user.tags[1]"]);
+/// let rows = json!([[1, "a", "x", "y"], [2, "b", "z", null]]);
+/// assert_eq!(
+///   rows.as_array().unwrap().iter().map(|row| zip_object_deep!(columns.clone(), row.clone())).collect::<Value>(),
+///   json!([
+///     {"id": 1, "user": {"name": "a", "tags": ["x", "y"]}},
+///     {"id": 2, "user": {"name": "b", "tags": ["z", null]}}
+///   ])
+/// );
+/// ```
+#[macro_export]
+macro_rules! zip_object_deep {
+    () => {
+        json!({})
+    };
+    ($a:expr $(,)*) => {
+        $crate::zip_object_deep($a, json!([]))
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::zip_object_deep($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::zip_object_deep($a, $b)
+    };
+}
@@ -0,0 +1,59 @@
+use crate::lib::{Value};
+use crate::unzip_with;
+
+#[doc(hidden)]
+pub fn _zip_with_all<F: Fn(&[Value]) -> Value>(arrays: Vec<Value>, iteratee: F) -> Value {
+    unzip_with(Value::Array(arrays), iteratee)
+}
+/// See lodash [zipWith](https://lodash.com/docs/#zipWith)
+///
+/// Same as [zip()](crate::zip()), but each grouped array is combined by `iteratee`.
+/// [zip_with!](crate::zip_with!) takes any number of arrays, followed by the iteratee.
+pub fn zip_with<F: Fn(&[Value]) -> Value>(v1: Value, v2: Value, iteratee: F) -> Value {
+    _zip_with_all(vec![v1, v2], iteratee)
+}
+
+/// Based on [zip_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// assert_eq!(
+///   zip_with!(json!([1, 2]), json!([10, 20]), json!([100, 200]), |group: &[Value]| {
+///     add!(add!(group[0].clone(), group[1].clone()), group[2].clone())
+///   }),
+///   json!([111, 222])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(zip_with!(), json!([]));
+/// assert_eq!(zip_with!(json!([1, 2])), json!([[1], [2]]));
+/// assert_eq!(zip_with!(json!([1, 2]), |group: &[Value]| json!(group)), json!([[1], [2]]));
+/// assert_eq!(zip_with!(json!([1, 2]), json!([3]), |group: &[Value]| json!(group)), json!([[1, 3], [2, null]]));
+/// assert_eq!(zip_with!(json!(["a", "b"]), json!([1, 2]), |group: &[Value]| json!({ "k": group[0], "v": group[1] })), json!([{"k": "a", "v": 1}, {"k": "b", "v": 2}]));
+/// ```
+#[macro_export]
+macro_rules! zip_with {
+    (@arrays [$($arrays:expr),*] $f:expr $(,)*) => {
+        $crate::_zip_with_all(vec![$($arrays),*], $f)
+    };
+    (@arrays [$($arrays:expr),*] $a:expr, $($rest:tt)+) => {
+        $crate::zip_with!(@arrays [$($arrays,)* $a] $($rest)+)
+    };
+    () => {
+        json!([])
+    };
+    ($a:expr $(,)*) => {
+        $crate::_zip_all(vec![$a])
+    };
+    ($a:expr, $($rest:tt)+) => {
+        $crate::zip_with!(@arrays [$a] $($rest)+)
+    };
+}
@@ -0,0 +1,70 @@
+use crate::lib::Value;
+
+/// See lodash [after](https://lodash.com/docs/#after)
+///
+/// `func` is called from the `n`th call on, calls before that return `null`.
+pub fn after<F: FnMut(Vec<Value>) -> Value>(
+    n: usize,
+    mut func: F,
+) -> impl FnMut(Vec<Value>) -> Value {
+    let mut n = n;
+    move |args| {
+        n = n.saturating_sub(1);
+        if n == 0 {
+            func(args)
+        } else {
+            Value::Null
+        }
+    }
+}
+
+/// Based on [after()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let saves = vec!["profile", "settings"];
+/// let mut logs = vec![];
+/// let mut done = after!(saves.len(), |_: Vec<Value>| {
+///   logs.push("done saving!");
+///   json!(null)
+/// });
+/// for _ in saves.iter() {
+///   done(vec![]); // asyncSave({ 'type': type, 'complete': done })
+/// }
+/// drop(done);
+/// // => Logs 'done saving!' after the two async saves have completed.
+/// assert_eq!(logs, vec!["done saving!"]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(after!()(vec![]), json!(null));
+/// let mut now = after!(0, |args: Vec<Value>| args[0].clone());
+/// assert_eq!(now(vec![json!(1)]), json!(1));
+/// let mut third = after!(3, |args: Vec<Value>| args[0].clone());
+/// assert_eq!(third(vec![json!("a")]), json!(null));
+/// assert_eq!(third(vec![json!("b")]), json!(null));
+/// assert_eq!(third(vec![json!("c")]), json!("c"));
+/// assert_eq!(third(vec![json!("d")]), json!("d"));
+/// ```
+#[macro_export]
+macro_rules! after {
+    () => {
+        $crate::after(0, |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::after($a, |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::after($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::after($a, $b)
+    };
+}
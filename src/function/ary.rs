@@ -0,0 +1,60 @@
+use crate::lib::Value;
+
+/// See lodash [ary](https://lodash.com/docs/#ary)
+///
+/// Arguments past the `n`th are dropped before calling `func`.
+pub fn ary<F: Fn(Vec<Value>) -> Value>(func: F, n: usize) -> impl Fn(Vec<Value>) -> Value {
+    move |mut args| {
+        args.truncate(n);
+        func(args)
+    }
+}
+
+/// Based on [ary()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// // `parseInt(string, radix)`, where `map` gives the index as `radix`
+/// let parse_int = |args: Vec<Value>| match args.get(1).and_then(Value::as_u64).unwrap_or(0) {
+///   0 => json!(args[0].as_str().unwrap().parse::<i64>().ok()),
+///   radix @ 2..=36 => json!(i64::from_str_radix(args[0].as_str().unwrap(), radix as u32).ok()),
+///   _ => json!(null),
+/// };
+/// let strings = vec![json!("6"), json!("8"), json!("10")];
+/// let map = |f: &dyn Fn(Vec<Value>) -> Value| {
+///   strings.iter().enumerate().map(|(i, s)| f(vec![s.clone(), json!(i)])).collect::<Vec<Value>>()
+/// };
+/// assert_eq!(map(&parse_int), vec![json!(6), json!(null), json!(2)]);
+/// assert_eq!(map(&ary!(parse_int, 1)), vec![json!(6), json!(8), json!(10)]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// let args = vec![json!(1), json!(2), json!(3)];
+/// assert_eq!(ary!()(args.clone()), json!(null));
+/// assert_eq!(ary!(Value::Array)(args.clone()), json!([1, 2, 3]));
+/// assert_eq!(ary!(Value::Array, 0)(args.clone()), json!([]));
+/// assert_eq!(ary!(Value::Array, 2)(args.clone()), json!([1, 2]));
+/// assert_eq!(ary!(Value::Array, 5)(args), json!([1, 2, 3]));
+/// ```
+#[macro_export]
+macro_rules! ary {
+    () => {
+        $crate::ary(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null, 0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::ary($a, usize::MAX)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::ary($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::ary($a, $b)
+    };
+}
@@ -0,0 +1,73 @@
+use crate::lib::Value;
+
+/// See lodash [before](https://lodash.com/docs/#before)
+///
+/// `func` is called for the first `n - 1` calls, later calls return the value of the last one, or `null`.
+pub fn before<F: FnMut(Vec<Value>) -> Value>(n: usize, func: F) -> impl FnMut(Vec<Value>) -> Value {
+    let mut func = Some(func);
+    let mut n = n;
+    let mut result = Value::Null;
+    move |args| {
+        n = n.saturating_sub(1);
+        if n > 0 {
+            if let Some(func) = func.as_mut() {
+                result = func(args);
+            }
+        } else {
+            // let go of whatever `func` holds, like lodash does
+            func = None;
+        }
+        result.clone()
+    }
+}
+
+/// Based on [before()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let mut contacts = vec![];
+/// let mut add_contact_to_list = before!(5, |args: Vec<Value>| {
+///   contacts.push(args[0].clone());
+///   json!(contacts.len())
+/// });
+/// for i in 0..10 {
+///   add_contact_to_list(vec![json!(i)]); // a click
+/// }
+/// drop(add_contact_to_list);
+/// // => allows adding up to 4 contacts to the list
+/// assert_eq!(contacts, vec![json!(0), json!(1), json!(2), json!(3)]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(before!()(vec![]), json!(null));
+/// assert_eq!(before!(2)(vec![json!(1)]), json!(null));
+/// let mut never = before!(1, |_: Vec<Value>| json!(1));
+/// assert_eq!(never(vec![]), json!(null));
+/// let mut twice = before!(3, |args: Vec<Value>| args[0].clone());
+/// assert_eq!(twice(vec![json!("a")]), json!("a"));
+/// assert_eq!(twice(vec![json!("b")]), json!("b"));
+/// assert_eq!(twice(vec![json!("c")]), json!("b"));
+/// assert_eq!(twice(vec![json!("d")]), json!("b"));
+/// ```
+#[macro_export]
+macro_rules! before {
+    () => {
+        $crate::before(0, |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::before($a, |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::before($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::before($a, $b)
+    };
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::internal::mutex_lock;
+
+/// A task for [Clock::schedule()]
+pub type Task = Box<dyn FnOnce() + Send>;
+
+/// The time source and timer of [debounce_with()](crate::debounce_with()) and the other timed functions
+///
+/// [SystemClock] is the real one, [ManualClock] only moves when told to, for deterministic tests.
+pub trait Clock: Send + Sync {
+    /// The time since the origin of the clock
+    fn now(&self) -> Duration;
+    /// Runs `task` once `delay` has passed
+    fn schedule(&self, delay: Duration, task: Task);
+}
+
+/// A [Clock] on `Instant`, where each scheduled task sleeps in its own thread
+#[derive(Clone, Debug)]
+pub struct SystemClock {
+    origin: Instant,
+}
+impl SystemClock {
+    ///
+    pub fn new() -> SystemClock {
+        SystemClock {
+            origin: Instant::now(),
+        }
+    }
+}
+impl Default for SystemClock {
+    fn default() -> SystemClock {
+        SystemClock::new()
+    }
+}
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+    fn schedule(&self, delay: Duration, task: Task) {
+        thread::spawn(move || {
+            thread::sleep(delay);
+            task();
+        });
+    }
+}
+
+#[derive(Default)]
+struct ManualState {
+    now: Duration,
+    scheduled: u64,
+    // (due, order of scheduling) => task
+    tasks: BTreeMap<(Duration, u64), Task>,
+}
+
+/// A virtual [Clock], time passes with [ManualClock::advance()], which runs the tasks that come due
+///
+/// Clones share the same time and tasks.
+///
+/// ```rust
+/// use serde_json_lodash::{Clock, ManualClock};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let log = Arc::new(Mutex::new(vec![]));
+/// let (a, b) = (log.clone(), log.clone());
+/// clock.schedule(Duration::from_millis(20), Box::new(move || a.lock().unwrap().push("b")));
+/// clock.schedule(Duration::from_millis(10), Box::new(move || b.lock().unwrap().push("a")));
+/// clock.advance(Duration::from_millis(15));
+/// assert_eq!(*log.lock().unwrap(), vec!["a"]);
+/// assert_eq!(clock.pending(), 1);
+/// clock.advance(Duration::from_millis(5));
+/// assert_eq!(*log.lock().unwrap(), vec!["a", "b"]);
+/// assert_eq!(clock.now(), Duration::from_millis(20));
+/// ```
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    state: Arc<Mutex<ManualState>>,
+}
+impl ManualClock {
+    ///
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+    /// Moves the time forward, running the tasks due by then in order, at their due time
+    pub fn advance(&self, by: Duration) {
+        let until = mutex_lock(&self.state).now + by;
+        loop {
+            let mut state = mutex_lock(&self.state);
+            let due = match state.tasks.keys().next() {
+                Some(&key) if key.0 <= until => key,
+                _ => break,
+            };
+            let task = state.tasks.remove(&due).unwrap();
+            state.now = due.0;
+            // the task may schedule other tasks
+            drop(state);
+            task();
+        }
+        mutex_lock(&self.state).now = until;
+    }
+    /// The number of tasks not run yet
+    pub fn pending(&self) -> usize {
+        mutex_lock(&self.state).tasks.len()
+    }
+}
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        mutex_lock(&self.state).now
+    }
+    fn schedule(&self, delay: Duration, task: Task) {
+        let mut state = mutex_lock(&self.state);
+        state.scheduled += 1;
+        let key = (state.now + delay, state.scheduled);
+        state.tasks.insert(key, task);
+    }
+}
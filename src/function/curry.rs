@@ -0,0 +1,141 @@
+use crate::lib::Value;
+use crate::JsFn;
+
+/// A curried [JsFn], collecting arguments until it has its arity of them
+///
+/// The `_` placeholder of lodash is not implemented.
+#[derive(Clone, Debug)]
+pub struct Curry {
+    func: JsFn,
+    args: Vec<Value>,
+    from_right: bool,
+}
+impl Curry {
+    /// Adds `args` to those collected, after them, or before them for [curry_right()]
+    pub fn call(&self, args: &[Value]) -> Curry {
+        let args = if self.from_right {
+            args.iter().chain(self.args.iter()).cloned().collect()
+        } else {
+            self.args.iter().chain(args.iter()).cloned().collect()
+        };
+        Curry {
+            func: self.func.clone(),
+            args,
+            from_right: self.from_right,
+        }
+    }
+    /// Calls the function with the collected arguments, once there are enough of them
+    pub fn value(&self) -> Option<Value> {
+        if self.args.len() >= self.func.arity() {
+            Some(self.func.call(&self.args))
+        } else {
+            None
+        }
+    }
+}
+
+/// See lodash [curry](https://lodash.com/docs/#curry)
+///
+/// Use [JsFn::with_arity()] for lodash's `arity` parameter.
+pub fn curry(func: JsFn) -> Curry {
+    Curry {
+        func,
+        args: vec![],
+        from_right: false,
+    }
+}
+/// See lodash [curryRight](https://lodash.com/docs/#curryRight)
+pub fn curry_right(func: JsFn) -> Curry {
+    Curry {
+        func,
+        args: vec![],
+        from_right: true,
+    }
+}
+
+/// Based on [curry()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let abc = js_fn!(|a, b, c| json!([a, b, c]));
+/// let curried = curry!(abc.clone());
+/// assert_eq!(curried.call(&[json!(1)]).call(&[json!(2)]).call(&[json!(3)]).value(), Some(json!([1, 2, 3])));
+/// assert_eq!(curried.call(&[json!(1), json!(2)]).call(&[json!(3)]).value(), Some(json!([1, 2, 3])));
+/// assert_eq!(curried.call(&[json!(1), json!(2), json!(3)]).value(), Some(json!([1, 2, 3])));
+/// // Curried with placeholders is not implemented
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(curry!().value(), Some(json!(null)));
+/// let all = js_fn!(|a, b| json!([a, b]));
+/// assert_eq!(curry!(all.clone()).value(), None);
+/// assert_eq!(curry!(all.clone()).call(&[json!(1)]).value(), None);
+/// assert_eq!(curry!(all.clone()).call(&[]).call(&[json!(1)]).call(&[json!(2)]).value(), Some(json!([1, 2])));
+/// assert_eq!(curry!(all.clone(), 1).call(&[json!(1)]).value(), Some(json!([1, null])));
+/// let add = js_fn!(|a, b| add!(a, b));
+/// let add_one = curry!(add).call(&[json!(1)]);
+/// assert_eq!(add_one.call(&[json!(2)]).value(), Some(json!(3)));
+/// assert_eq!(add_one.call(&[json!(3)]).value(), Some(json!(4)));
+/// ```
+#[macro_export]
+macro_rules! curry {
+    () => {
+        $crate::curry($crate::js_fn!())
+    };
+    ($a:expr $(,)*) => {
+        $crate::curry($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::curry($a.with_arity($b))
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::curry($a.with_arity($b))
+    };
+}
+/// Based on [curry_right()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let abc = js_fn!(|a, b, c| json!([a, b, c]));
+/// let curried = curry_right!(abc.clone());
+/// assert_eq!(curried.call(&[json!(3)]).call(&[json!(2)]).call(&[json!(1)]).value(), Some(json!([1, 2, 3])));
+/// assert_eq!(curried.call(&[json!(2), json!(3)]).call(&[json!(1)]).value(), Some(json!([1, 2, 3])));
+/// assert_eq!(curried.call(&[json!(1), json!(2), json!(3)]).value(), Some(json!([1, 2, 3])));
+/// // Curried with placeholders is not implemented
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(curry_right!().value(), Some(json!(null)));
+/// let all = js_fn!(|a, b| json!([a, b]));
+/// assert_eq!(curry_right!(all.clone()).call(&[json!(1)]).value(), None);
+/// assert_eq!(curry_right!(all.clone(), 1).call(&[json!(1)]).value(), Some(json!([1, null])));
+/// ```
+#[macro_export]
+macro_rules! curry_right {
+    () => {
+        $crate::curry_right($crate::js_fn!())
+    };
+    ($a:expr $(,)*) => {
+        $crate::curry_right($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::curry_right($a.with_arity($b))
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::curry_right($a.with_arity($b))
+    };
+}
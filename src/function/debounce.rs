@@ -0,0 +1,486 @@
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use crate::lib::Value;
+use crate::internal::mutex_lock;
+use crate::{Clock, SystemClock};
+
+/// The options of [debounce()]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DebounceOptions {
+    /// Invoke on the leading edge of the timeout
+    pub leading: bool,
+    /// Invoke on the trailing edge of the timeout
+    pub trailing: bool,
+    /// The maximum time `func` is allowed to be delayed before it's invoked
+    pub max_wait: Option<Duration>,
+}
+impl Default for DebounceOptions {
+    fn default() -> DebounceOptions {
+        DebounceOptions {
+            leading: false,
+            trailing: true,
+            max_wait: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    last_args: Option<Vec<Value>>,
+    last_call_time: Option<Duration>,
+    last_invoke_time: Duration,
+    // the id of the pending timer, timers which are not this one do nothing when they expire
+    timer: Option<u64>,
+    timers: u64,
+    result: Value,
+}
+
+// `func` is taken out while it runs, so that it can call the debounced function again without a deadlock
+struct Func<F> {
+    func: Option<F>,
+    running_on: Option<ThreadId>,
+}
+// Puts `func` back once it returns, or panics
+struct Running<'a, F> {
+    shared: &'a Shared<F>,
+    func: Option<F>,
+}
+impl<F> Drop for Running<'_, F> {
+    fn drop(&mut self) {
+        let mut slot = mutex_lock(&self.shared.func);
+        slot.func = self.func.take();
+        slot.running_on = None;
+        drop(slot);
+        self.shared.func_returned.notify_one();
+    }
+}
+
+struct Shared<F> {
+    func: Mutex<Func<F>>,
+    func_returned: Condvar,
+    state: Mutex<State>,
+    clock: Arc<dyn Clock>,
+    wait: Duration,
+    options: DebounceOptions,
+}
+impl<F: FnMut(Vec<Value>) -> Value + Send + 'static> Shared<F> {
+    fn should_invoke(&self, state: &State, time: Duration) -> bool {
+        let last_call_time = match state.last_call_time {
+            Some(t) => t,
+            None => return true,
+        };
+        // `None` when the clock went backwards
+        match time.checked_sub(last_call_time) {
+            Some(since_last_call) if since_last_call < self.wait => {}
+            _ => return true,
+        }
+        match self.options.max_wait {
+            Some(max_wait) => time.saturating_sub(state.last_invoke_time) >= max_wait,
+            None => false,
+        }
+    }
+    fn remaining_wait(&self, state: &State, time: Duration) -> Duration {
+        let since_last_call = time.saturating_sub(state.last_call_time.unwrap_or_default());
+        let waiting = self.wait.saturating_sub(since_last_call);
+        match self.options.max_wait {
+            Some(max_wait) => {
+                waiting.min(max_wait.saturating_sub(time.saturating_sub(state.last_invoke_time)))
+            }
+            None => waiting,
+        }
+    }
+    fn start_timer(self: &Arc<Self>, state: &mut State, delay: Duration) {
+        state.timers += 1;
+        let id = state.timers;
+        state.timer = Some(id);
+        let shared = self.clone();
+        self.clock
+            .schedule(delay, Box::new(move || shared.timer_expired(id)));
+    }
+    // The arguments to invoke `func` with, `func` itself is called once `state` is unlocked
+    fn take_args(&self, state: &mut State, time: Duration) -> Vec<Value> {
+        state.last_invoke_time = time;
+        state.last_args.take().unwrap_or_default()
+    }
+    fn invoke(&self, args: Vec<Value>) -> Value {
+        let current = thread::current().id();
+        let mut slot = mutex_lock(&self.func);
+        let func = loop {
+            if let Some(func) = slot.func.take() {
+                break func;
+            }
+            if slot.running_on == Some(current) {
+                // called by `func` itself, which can't run twice at once
+                drop(slot);
+                return mutex_lock(&self.state).result.clone();
+            }
+            slot = self
+                .func_returned
+                .wait(slot)
+                .unwrap_or_else(PoisonError::into_inner);
+        };
+        slot.running_on = Some(current);
+        drop(slot);
+        let mut running = Running {
+            shared: self,
+            func: Some(func),
+        };
+        let result = (running.func.as_mut().unwrap())(args);
+        drop(running);
+        mutex_lock(&self.state).result = result.clone();
+        result
+    }
+    fn trailing_edge(&self, state: &mut State, time: Duration) -> Option<Vec<Value>> {
+        state.timer = None;
+        if self.options.trailing && state.last_args.is_some() {
+            Some(self.take_args(state, time))
+        } else {
+            state.last_args = None;
+            None
+        }
+    }
+    fn timer_expired(self: &Arc<Self>, id: u64) {
+        let mut state = mutex_lock(&self.state);
+        if state.timer != Some(id) {
+            return;
+        }
+        let time = self.clock.now();
+        if self.should_invoke(&state, time) {
+            if let Some(args) = self.trailing_edge(&mut state, time) {
+                drop(state);
+                self.invoke(args);
+            }
+        } else {
+            let remaining = self.remaining_wait(&state, time);
+            self.start_timer(&mut state, remaining);
+        }
+    }
+}
+
+/// The debounced function returned by [debounce()] and [throttle()](crate::throttle())
+///
+/// Clones share the same timers and state. `func` may call its own debounced function, like in lodash,
+/// but as it is already running, such a call which should invoke it gives the last result instead.
+pub struct Debounced<F> {
+    shared: Arc<Shared<F>>,
+}
+impl<F> Clone for Debounced<F> {
+    fn clone(&self) -> Debounced<F> {
+        Debounced {
+            shared: self.shared.clone(),
+        }
+    }
+}
+impl<F: FnMut(Vec<Value>) -> Value + Send + 'static> Debounced<F> {
+    /// Returns the result of the last `func` invocation, `null` before the first one
+    pub fn call(&self, args: Vec<Value>) -> Value {
+        let shared = &self.shared;
+        let mut state = mutex_lock(&shared.state);
+        let time = shared.clock.now();
+        let is_invoking = shared.should_invoke(&state, time);
+        state.last_args = Some(args);
+        state.last_call_time = Some(time);
+        if is_invoking {
+            if state.timer.is_none() {
+                // the leading edge
+                state.last_invoke_time = time;
+                shared.start_timer(&mut state, shared.wait);
+                if !shared.options.leading {
+                    return state.result.clone();
+                }
+                let args = shared.take_args(&mut state, time);
+                drop(state);
+                return shared.invoke(args);
+            }
+            if shared.options.max_wait.is_some() {
+                // invoked while the timer is already running, because of `max_wait`
+                shared.start_timer(&mut state, shared.wait);
+                let args = shared.take_args(&mut state, time);
+                drop(state);
+                return shared.invoke(args);
+            }
+        }
+        if state.timer.is_none() {
+            shared.start_timer(&mut state, shared.wait);
+        }
+        state.result.clone()
+    }
+    /// Cancels the delayed invocation
+    pub fn cancel(&self) {
+        let mut state = mutex_lock(&self.shared.state);
+        state.timer = None;
+        state.last_args = None;
+        state.last_call_time = None;
+        state.last_invoke_time = Duration::default();
+    }
+    /// Invokes the delayed invocation now, and returns the result of the last `func` invocation
+    pub fn flush(&self) -> Value {
+        let shared = &self.shared;
+        let mut state = mutex_lock(&shared.state);
+        if state.timer.is_none() {
+            return state.result.clone();
+        }
+        let time = shared.clock.now();
+        match shared.trailing_edge(&mut state, time) {
+            Some(args) => {
+                drop(state);
+                shared.invoke(args)
+            }
+            None => state.result.clone(),
+        }
+    }
+    /// Whether an invocation is waiting for its timer
+    pub fn pending(&self) -> bool {
+        mutex_lock(&self.shared.state).timer.is_some()
+    }
+}
+
+/// Same as [debounce()], but timed by `clock`
+pub fn debounce_with<F: FnMut(Vec<Value>) -> Value + Send + 'static>(
+    clock: Arc<dyn Clock>,
+    func: F,
+    wait: Duration,
+    options: DebounceOptions,
+) -> Debounced<F> {
+    let options = DebounceOptions {
+        max_wait: options.max_wait.map(|max_wait| max_wait.max(wait)),
+        ..options
+    };
+    Debounced {
+        shared: Arc::new(Shared {
+            func: Mutex::new(Func {
+                func: Some(func),
+                running_on: None,
+            }),
+            func_returned: Condvar::new(),
+            state: Mutex::new(State::default()),
+            clock,
+            wait,
+            options,
+        }),
+    }
+}
+/// See lodash [debounce](https://lodash.com/docs/#debounce)
+///
+/// Timed by a [SystemClock], the delayed invocations run in their own threads.
+pub fn debounce<F: FnMut(Vec<Value>) -> Value + Send + 'static>(
+    func: F,
+    wait: Duration,
+    options: DebounceOptions,
+) -> Debounced<F> {
+    debounce_with(Arc::new(SystemClock::new()), func, wait, options)
+}
+
+/// Based on [debounce_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{DebounceOptions, ManualClock};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// let ms = Duration::from_millis;
+/// let clock = ManualClock::new();
+/// let calculated = Arc::new(Mutex::new(vec![]));
+/// let log = calculated.clone();
+/// let calculate_layout = move |args: Vec<Value>| {
+///   log.lock().unwrap().push(args[0].clone());
+///   args[0].clone()
+/// };
+///
+/// // Avoid costly calculations while the window size is in flux.
+/// let on_resize = debounce_with!(Arc::new(clock.clone()), calculate_layout, ms(150));
+/// on_resize.call(vec![json!(1)]);
+/// clock.advance(ms(100));
+/// on_resize.call(vec![json!(2)]);
+/// clock.advance(ms(100));
+/// assert!(calculated.lock().unwrap().is_empty());
+/// clock.advance(ms(50));
+/// assert_eq!(*calculated.lock().unwrap(), vec![json!(2)]);
+///
+/// // Invoke `sendMail` when clicked, debouncing subsequent calls.
+/// let sent = Arc::new(Mutex::new(0));
+/// let count = sent.clone();
+/// let send_mail = move |_: Vec<Value>| { *count.lock().unwrap() += 1; json!("sent") };
+/// let on_click = debounce_with!(Arc::new(clock.clone()), send_mail, ms(300), DebounceOptions {
+///   leading: true,
+///   trailing: false,
+///   ..DebounceOptions::default()
+/// });
+/// assert_eq!(on_click.call(vec![]), json!("sent"));
+/// on_click.call(vec![]);
+/// clock.advance(ms(300));
+/// assert_eq!(*sent.lock().unwrap(), 1);
+///
+/// // Ensure `batchLog` is invoked once after 1 second of debounced calls.
+/// let batched = Arc::new(Mutex::new(vec![]));
+/// let log = batched.clone();
+/// let batch_log = move |args: Vec<Value>| { log.lock().unwrap().push(args[0].clone()); json!(null) };
+/// let debounced = debounce_with!(Arc::new(clock.clone()), batch_log, ms(250), DebounceOptions {
+///   max_wait: Some(ms(1000)),
+///   ..DebounceOptions::default()
+/// });
+/// for i in 0..12 {
+///   debounced.call(vec![json!(i)]); // a message every 100ms
+///   clock.advance(ms(100));
+/// }
+/// assert_eq!(*batched.lock().unwrap(), vec![json!(9)]);
+///
+/// // Cancel the trailing debounced invocation.
+/// debounced.cancel();
+/// clock.advance(ms(1000));
+/// assert_eq!(*batched.lock().unwrap(), vec![json!(9)]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::{DebounceOptions, ManualClock};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// let ms = Duration::from_millis;
+/// let clock = ManualClock::new();
+/// let first = |args: Vec<Value>| args.get(0).cloned().unwrap_or_default();
+/// let debounced = debounce_with!(Arc::new(clock.clone()), first, ms(10));
+/// assert_eq!(debounced.call(vec![json!(1)]), json!(null));
+/// assert_eq!(debounced.call(vec![json!(2)]), json!(null));
+/// assert!(debounced.pending());
+/// assert_eq!(debounced.flush(), json!(2));
+/// assert!(!debounced.pending());
+/// assert_eq!(debounced.flush(), json!(2));
+/// assert_eq!(debounced.call(vec![json!(3)]), json!(2));
+/// clock.advance(ms(10));
+/// assert_eq!(debounced.call(vec![json!(4)]), json!(3));
+/// debounced.cancel();
+/// assert_eq!(clock.pending(), 1); // the timer expires, but doesn't invoke
+/// clock.advance(ms(10));
+/// assert_eq!(debounced.flush(), json!(3));
+///
+/// let both = debounce_with!(Arc::new(clock.clone()), first, ms(10), DebounceOptions {
+///   leading: true,
+///   ..DebounceOptions::default()
+/// });
+/// assert_eq!(both.call(vec![json!(1)]), json!(1));
+/// clock.advance(ms(10));
+/// assert_eq!(both.flush(), json!(1)); // only called once, no trailing invocation
+/// assert_eq!(both.call(vec![json!(2)]), json!(2));
+/// assert_eq!(both.call(vec![json!(3)]), json!(2));
+/// clock.advance(ms(10));
+/// assert_eq!(both.flush(), json!(3));
+///
+/// let neither = debounce_with!(Arc::new(clock.clone()), first, ms(10), DebounceOptions {
+///   trailing: false,
+///   ..DebounceOptions::default()
+/// });
+/// neither.call(vec![json!(1)]);
+/// clock.advance(ms(10));
+/// assert_eq!(neither.flush(), json!(null));
+///
+/// assert_eq!(debounce_with!(Arc::new(clock.clone())).call(vec![]), json!(null));
+///
+/// // `func` calling its own debounced function doesn't deadlock
+/// type Func = Box<dyn FnMut(Vec<Value>) -> Value + Send>;
+/// let cell = Arc::new(std::sync::Mutex::new(None::<serde_json_lodash::Debounced<Func>>));
+/// let inner = cell.clone();
+/// let again: Func = Box::new(move |args: Vec<Value>| {
+///   let debounced = inner.lock().unwrap().clone().unwrap();
+///   let nested = debounced.call(vec![json!(0)]);
+///   json!([args[0], nested])
+/// });
+/// let leading = debounce_with!(Arc::new(clock.clone()), again, ms(10), DebounceOptions {
+///   leading: true,
+///   ..DebounceOptions::default()
+/// });
+/// *cell.lock().unwrap() = Some(leading.clone());
+/// assert_eq!(leading.call(vec![json!(1)]), json!([1, null]));
+/// clock.advance(ms(10)); // the trailing invocation, with the arguments of the nested call
+/// assert_eq!(leading.flush(), json!([0, [1, null]]));
+/// ```
+#[macro_export]
+macro_rules! debounce_with {
+    ($c:expr $(,)*) => {
+        $crate::debounce_with(
+            $c,
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            $crate::DebounceOptions::default(),
+        )
+    };
+    ($c:expr, $a:expr $(,)*) => {
+        $crate::debounce_with(
+            $c,
+            $a,
+            std::time::Duration::default(),
+            $crate::DebounceOptions::default(),
+        )
+    };
+    ($c:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::debounce_with($c, $a, $b, $crate::DebounceOptions::default())
+    };
+    ($c:expr, $a:expr, $b:expr, $d:expr $(,)*) => {
+        $crate::debounce_with($c, $a, $b, $d)
+    };
+    ($c:expr, $a:expr, $b:expr, $d:expr, $($rest:tt)*) => {
+        $crate::debounce_with($c, $a, $b, $d)
+    };
+}
+/// Based on [debounce()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+/// let (sender, receiver) = mpsc::channel();
+/// let sender = std::sync::Mutex::new(sender);
+/// let save = debounce!(move |args: Vec<Value>| {
+///   sender.lock().unwrap().send(args[0].clone()).unwrap();
+///   json!(null)
+/// }, Duration::from_millis(20));
+/// save.call(vec![json!("draft 1")]);
+/// save.call(vec![json!("draft 2")]);
+/// assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(json!("draft 2")));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(debounce!().call(vec![]), json!(null));
+/// assert_eq!(debounce!().flush(), json!(null));
+/// ```
+#[macro_export]
+macro_rules! debounce {
+    () => {
+        $crate::debounce(
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            $crate::DebounceOptions::default(),
+        )
+    };
+    ($a:expr $(,)*) => {
+        $crate::debounce(
+            $a,
+            std::time::Duration::default(),
+            $crate::DebounceOptions::default(),
+        )
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::debounce($a, $b, $crate::DebounceOptions::default())
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::debounce($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::debounce($a, $b, $c)
+    };
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::lib::Value;
+use crate::{delay_with, Clock, SystemClock, Timer};
+
+// Like lodash, deferring is delaying by 1ms
+const DEFER_WAIT: Duration = Duration::from_millis(1);
+
+/// Same as [defer()], but timed by `clock`
+pub fn defer_with<F: FnOnce(Vec<Value>) -> Value + Send + 'static>(
+    clock: Arc<dyn Clock>,
+    func: F,
+    args: Vec<Value>,
+) -> Timer {
+    delay_with(clock, func, DEFER_WAIT, args)
+}
+/// See lodash [defer](https://lodash.com/docs/#defer)
+///
+/// `func` runs in its own thread, after 1 millisecond like lodash.
+pub fn defer<F: FnOnce(Vec<Value>) -> Value + Send + 'static>(func: F, args: Vec<Value>) -> Timer {
+    defer_with(Arc::new(SystemClock::new()), func, args)
+}
+
+/// Based on [defer_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::ManualClock;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let logs = Arc::new(Mutex::new(vec![]));
+/// let log = logs.clone();
+/// defer_with!(Arc::new(clock.clone()), move |args: Vec<Value>| {
+///   log.lock().unwrap().push(args[0].clone());
+///   json!(null)
+/// }, json!("deferred"));
+/// assert!(logs.lock().unwrap().is_empty());
+/// clock.advance(Duration::from_millis(1));
+/// // => Logs 'deferred' after one millisecond.
+/// assert_eq!(*logs.lock().unwrap(), vec![json!("deferred")]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::ManualClock;
+/// # use std::sync::{Arc, Mutex};
+/// # use std::time::Duration;
+/// let clock = ManualClock::new();
+/// defer_with!(Arc::new(clock.clone()));
+/// let logs = Arc::new(Mutex::new(vec![]));
+/// let log = logs.clone();
+/// let timer = defer_with!(Arc::new(clock.clone()), move |_: Vec<Value>| { log.lock().unwrap().push(1); json!(null) });
+/// timer.cancel();
+/// clock.advance(Duration::from_millis(1));
+/// assert!(logs.lock().unwrap().is_empty());
+/// assert_eq!(clock.pending(), 0);
+/// ```
+#[macro_export]
+macro_rules! defer_with {
+    ($c:expr $(,)*) => {
+        $crate::defer_with($c, |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null, vec![])
+    };
+    ($c:expr, $a:expr $(,)*) => {
+        $crate::defer_with($c, $a, vec![])
+    };
+    ($c:expr, $a:expr, $($b:expr),+ $(,)*) => {
+        $crate::defer_with($c, $a, vec![$($b),+])
+    };
+}
+/// Based on [defer()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+/// let (sender, receiver) = mpsc::channel();
+/// defer!(move |args: Vec<Value>| {
+///   sender.send(args[0].clone()).unwrap();
+///   json!(null)
+/// }, json!("deferred"));
+/// // => Logs 'deferred' after one millisecond.
+/// assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(json!("deferred")));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// defer!();
+/// ```
+#[macro_export]
+macro_rules! defer {
+    () => {
+        $crate::defer(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null, vec![])
+    };
+    ($a:expr $(,)*) => {
+        $crate::defer($a, vec![])
+    };
+    ($a:expr, $($b:expr),+ $(,)*) => {
+        $crate::defer($a, vec![$($b),+])
+    };
+}
@@ -0,0 +1,165 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::lib::Value;
+use crate::{Clock, SystemClock};
+
+/// A handle on the invocation scheduled by [delay()] or [defer()](crate::defer()), like the timer id of lodash
+#[derive(Clone, Debug, Default)]
+pub struct Timer {
+    cancelled: Arc<AtomicBool>,
+}
+impl Timer {
+    /// Like js `clearTimeout`, the invocation won't happen if it hasn't yet
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Same as [delay()], but timed by `clock`
+pub fn delay_with<F: FnOnce(Vec<Value>) -> Value + Send + 'static>(
+    clock: Arc<dyn Clock>,
+    func: F,
+    wait: Duration,
+    args: Vec<Value>,
+) -> Timer {
+    let timer = Timer::default();
+    let cancelled = timer.cancelled.clone();
+    clock.schedule(
+        wait,
+        Box::new(move || {
+            if !cancelled.load(Ordering::SeqCst) {
+                func(args);
+            }
+        }),
+    );
+    timer
+}
+/// See lodash [delay](https://lodash.com/docs/#delay)
+///
+/// `func` runs in its own thread.
+pub fn delay<F: FnOnce(Vec<Value>) -> Value + Send + 'static>(
+    func: F,
+    wait: Duration,
+    args: Vec<Value>,
+) -> Timer {
+    delay_with(Arc::new(SystemClock::new()), func, wait, args)
+}
+
+/// Based on [delay_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::ManualClock;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let logs = Arc::new(Mutex::new(vec![]));
+/// let log = logs.clone();
+/// delay_with!(Arc::new(clock.clone()), move |args: Vec<Value>| {
+///   log.lock().unwrap().push(args[0].clone());
+///   json!(null)
+/// }, Duration::from_millis(1000), json!("later"));
+/// clock.advance(Duration::from_millis(999));
+/// assert!(logs.lock().unwrap().is_empty());
+/// clock.advance(Duration::from_millis(1));
+/// // => Logs 'later' after one second.
+/// assert_eq!(*logs.lock().unwrap(), vec![json!("later")]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::ManualClock;
+/// # use std::sync::{Arc, Mutex};
+/// # use std::time::Duration;
+/// let clock = ManualClock::new();
+/// let logs = Arc::new(Mutex::new(vec![]));
+/// let push = |logs: &Arc<Mutex<Vec<Value>>>| {
+///   let logs = logs.clone();
+///   move |args: Vec<Value>| { logs.lock().unwrap().push(Value::Array(args)); json!(null) }
+/// };
+/// delay_with!(Arc::new(clock.clone()));
+/// delay_with!(Arc::new(clock.clone()), push(&logs));
+/// delay_with!(Arc::new(clock.clone()), push(&logs), Duration::from_millis(10), json!(1), json!(2));
+/// let timer = delay_with!(Arc::new(clock.clone()), push(&logs), Duration::from_millis(5), json!(3));
+/// timer.cancel();
+/// clock.advance(Duration::from_millis(10));
+/// assert_eq!(*logs.lock().unwrap(), vec![json!([]), json!([1, 2])]);
+/// ```
+#[macro_export]
+macro_rules! delay_with {
+    ($c:expr $(,)*) => {
+        $crate::delay_with(
+            $c,
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            vec![],
+        )
+    };
+    ($c:expr, $a:expr $(,)*) => {
+        $crate::delay_with($c, $a, std::time::Duration::default(), vec![])
+    };
+    ($c:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::delay_with($c, $a, $b, vec![])
+    };
+    ($c:expr, $a:expr, $b:expr, $($d:expr),+ $(,)*) => {
+        $crate::delay_with($c, $a, $b, vec![$($d),+])
+    };
+}
+/// Based on [delay()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use std::sync::mpsc;
+/// use std::time::Duration;
+/// let (sender, receiver) = mpsc::channel();
+/// delay!(move |args: Vec<Value>| {
+///   sender.send(args[0].clone()).unwrap();
+///   json!(null)
+/// }, Duration::from_millis(10), json!("later"));
+/// // => Logs 'later' after ten milliseconds.
+/// assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(json!("later")));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use std::sync::mpsc;
+/// # use std::time::Duration;
+/// delay!();
+/// let (sender, receiver) = mpsc::channel();
+/// let timer = delay!(move |_: Vec<Value>| { sender.send(()).unwrap(); json!(null) }, Duration::from_secs(5));
+/// timer.cancel();
+/// assert!(receiver.try_recv().is_err());
+/// ```
+#[macro_export]
+macro_rules! delay {
+    () => {
+        $crate::delay(
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            vec![],
+        )
+    };
+    ($a:expr $(,)*) => {
+        $crate::delay($a, std::time::Duration::default(), vec![])
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::delay($a, $b, vec![])
+    };
+    ($a:expr, $b:expr, $($c:expr),+ $(,)*) => {
+        $crate::delay($a, $b, vec![$($c),+])
+    };
+}
@@ -0,0 +1,45 @@
+use crate::lib::Value;
+
+/// See lodash [flip](https://lodash.com/docs/#flip)
+pub fn flip<F: Fn(Vec<Value>) -> Value>(func: F) -> impl Fn(Vec<Value>) -> Value {
+    move |mut args| {
+        args.reverse();
+        func(args)
+    }
+}
+
+/// Based on [flip()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let flipped = flip!(Value::Array);
+/// assert_eq!(
+///   flipped(vec![json!("a"), json!("b"), json!("c"), json!("d")]),
+///   json!(["d", "c", "b", "a"])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(flip!()(vec![json!(1)]), json!(null));
+/// assert_eq!(flip!(Value::Array)(vec![]), json!([]));
+/// assert_eq!(flip!(flip!(Value::Array))(vec![json!(1), json!(2)]), json!([1, 2]));
+/// ```
+#[macro_export]
+macro_rules! flip {
+    () => {
+        $crate::flip(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::flip($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::flip($a)
+    };
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+use std::rc::Rc;
+
+use crate::lib::Value;
+
+type Func = Rc<dyn Fn(&[Value]) -> Value>;
+
+/// A js-like function: a closure over its arguments, with a declared arity like js `func.length`
+///
+/// Like js, it may be called with fewer or more arguments than its arity.
+/// Build one with [JsFn::new()], or lift any function with [js_fn!](crate::js_fn!), which gives `null` for missing arguments.
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{JsFn, chunk};
+/// let f = JsFn::new(2, |args: &[Value]| chunk(args[0].clone(), args[1].as_u64().unwrap_or(1) as usize));
+/// assert_eq!(f.arity(), 2);
+/// assert_eq!(f.call(&[json!([1, 2, 3]), json!(2)]), json!([[1, 2], [3]]));
+/// ```
+#[derive(Clone)]
+pub struct JsFn {
+    arity: usize,
+    func: Func,
+}
+impl JsFn {
+    ///
+    pub fn new<F: Fn(&[Value]) -> Value + 'static>(arity: usize, func: F) -> JsFn {
+        JsFn {
+            arity,
+            func: Rc::new(func),
+        }
+    }
+    ///
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+    /// Same function, with another declared arity, which is what [curry()](crate::curry()) waits for
+    pub fn with_arity(self, arity: usize) -> JsFn {
+        JsFn { arity, ..self }
+    }
+    ///
+    pub fn call(&self, args: &[Value]) -> Value {
+        (self.func)(args)
+    }
+    /// A closure for the combinators taking `Vec<Value>`, like [flip()](crate::flip())
+    pub fn into_fn(self) -> impl Fn(Vec<Value>) -> Value {
+        move |args| self.call(&args)
+    }
+}
+impl fmt::Debug for JsFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsFn").field("arity", &self.arity).finish()
+    }
+}
+
+/// Lifts a closure of `Value` arguments into a [JsFn], its arity is the number of arguments
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::{chunk, get};
+/// let chunk = js_fn!(|array, size| chunk(array, size.as_u64().unwrap_or(1) as usize));
+/// assert_eq!(chunk.arity(), 2);
+/// assert_eq!(chunk.call(&[json!(["a", "b", "c"]), json!(2)]), json!([["a", "b"], ["c"]]));
+/// let get = js_fn!(|object, path, default| get(object, path, default));
+/// assert_eq!(get.call(&[json!({"a": [{"b": 3}]}), json!("a[0].b")]), json!(3));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(js_fn!().arity(), 0);
+/// assert_eq!(js_fn!().call(&[json!(1)]), json!(null));
+/// assert_eq!(js_fn!(|| json!(1)).call(&[]), json!(1));
+/// assert_eq!(js_fn!(|a| a).call(&[]), json!(null));
+/// assert_eq!(js_fn!(|a| a).call(&[json!(1), json!(2)]), json!(1));
+/// ```
+#[macro_export]
+macro_rules! js_fn {
+    () => {
+        $crate::JsFn::new(0, |_: &[$crate::lib::Value]| $crate::lib::Value::Null)
+    };
+    (|| $body:expr) => {
+        $crate::JsFn::new(0, move |_: &[$crate::lib::Value]| $body)
+    };
+    (|$($arg:ident),* $(,)*| $body:expr) => {
+        $crate::JsFn::new(
+            [$(stringify!($arg)),*].len(),
+            move |args: &[$crate::lib::Value]| {
+                #[allow(unused_mut, unused_variables)]
+                let mut args = args.iter().cloned();
+                $(let $arg = args.next().unwrap_or($crate::lib::Value::Null);)*
+                $body
+            },
+        )
+    };
+}
@@ -0,0 +1,353 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::lib::Value;
+use crate::internal::{mutex_lock, value_to_key};
+
+/// The cache of [memoize()], like lodash `memoize.Cache`
+///
+/// Keys are compared by value: `1` and `1.0` are the same key, and so are objects with the same entries.
+pub trait MemoizeCache {
+    /// Gets a cached value, which counts as a use for an LRU cache
+    fn get(&mut self, key: &Value) -> Option<Value>;
+    ///
+    fn set(&mut self, key: Value, value: Value);
+    ///
+    fn has(&self, key: &Value) -> bool;
+    /// Returns whether there was a value to delete
+    fn delete(&mut self, key: &Value) -> bool;
+    ///
+    fn clear(&mut self);
+}
+
+/// The default [MemoizeCache], unbounded, or dropping the least recently used entry past a capacity
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_json_lodash::{MemoCache, MemoizeCache};
+/// let mut cache = MemoCache::lru(2);
+/// cache.set(json!("a"), json!(1));
+/// cache.set(json!("b"), json!(2));
+/// assert_eq!(cache.get(&json!("a")), Some(json!(1)));
+/// cache.set(json!("c"), json!(3)); // "b" is the least recently used
+/// assert_eq!(cache.has(&json!("b")), false);
+/// assert_eq!(cache.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MemoCache {
+    // canonical key => (key, value, last use)
+    entries: HashMap<String, (Value, Value, u64)>,
+    // last use => canonical key
+    uses: BTreeMap<u64, String>,
+    clock: u64,
+    capacity: Option<usize>,
+}
+impl MemoCache {
+    ///
+    pub fn new() -> MemoCache {
+        MemoCache::default()
+    }
+    /// A cache of at most `capacity` entries
+    pub fn lru(capacity: usize) -> MemoCache {
+        MemoCache {
+            capacity: Some(capacity),
+            ..MemoCache::default()
+        }
+    }
+    ///
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// The cached keys and values, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&Value, &Value)> {
+        self.entries.values().map(|(key, value, _)| (key, value))
+    }
+    fn touch(&mut self, canonical: &str) {
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(canonical) {
+            self.uses.remove(&entry.2);
+            entry.2 = self.clock;
+            self.uses.insert(self.clock, canonical.to_owned());
+        }
+    }
+}
+impl MemoizeCache for MemoCache {
+    fn get(&mut self, key: &Value) -> Option<Value> {
+        let canonical = value_to_key(key);
+        self.touch(&canonical);
+        self.entries
+            .get(&canonical)
+            .map(|(_, value, _)| value.clone())
+    }
+    fn set(&mut self, key: Value, value: Value) {
+        if self.capacity == Some(0) {
+            return;
+        }
+        let canonical = value_to_key(&key);
+        if let Some((_, _, used)) = self.entries.insert(canonical.clone(), (key, value, 0)) {
+            self.uses.remove(&used);
+        }
+        self.touch(&canonical);
+        if let Some(capacity) = self.capacity {
+            while self.entries.len() > capacity {
+                let oldest = *self.uses.keys().next().unwrap();
+                let canonical = self.uses.remove(&oldest).unwrap();
+                self.entries.remove(&canonical);
+            }
+        }
+    }
+    fn has(&self, key: &Value) -> bool {
+        self.entries.contains_key(&value_to_key(key))
+    }
+    fn delete(&mut self, key: &Value) -> bool {
+        match self.entries.remove(&value_to_key(key)) {
+            Some((_, _, used)) => {
+                self.uses.remove(&used);
+                true
+            }
+            None => false,
+        }
+    }
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.uses.clear();
+    }
+}
+
+/// The memoized function returned by [memoize()]
+#[derive(Clone, Debug)]
+pub struct Memoized<F, R, C = MemoCache> {
+    func: F,
+    resolver: R,
+    cache: C,
+}
+impl<F: Fn(&Value) -> Value, R: Fn(&Value) -> Value, C: MemoizeCache> Memoized<F, R, C> {
+    ///
+    pub fn call(&mut self, value: &Value) -> Value {
+        let key = (self.resolver)(value);
+        if let Some(result) = self.cache.get(&key) {
+            return result;
+        }
+        let result = (self.func)(value);
+        self.cache.set(key, result.clone());
+        result
+    }
+}
+impl<F, R, C> Memoized<F, R, C> {
+    /// Same memoized function, with another cache
+    pub fn with_cache<D>(self, cache: D) -> Memoized<F, R, D> {
+        Memoized {
+            func: self.func,
+            resolver: self.resolver,
+            cache,
+        }
+    }
+    ///
+    pub fn cache(&self) -> &C {
+        &self.cache
+    }
+    /// Like lodash `memoized.cache`, to change or clear the cached results
+    pub fn cache_mut(&mut self) -> &mut C {
+        &mut self.cache
+    }
+}
+
+/// The memoized function returned by [memoize_sync()], which can be shared between threads
+///
+/// The cache is not locked while `func` runs, so threads asking for the same missing key could both call `func`.
+#[derive(Debug)]
+pub struct SyncMemoized<F, R, C = MemoCache> {
+    func: F,
+    resolver: R,
+    cache: Mutex<C>,
+}
+impl<F: Fn(&Value) -> Value, R: Fn(&Value) -> Value, C: MemoizeCache> SyncMemoized<F, R, C> {
+    ///
+    pub fn call(&self, value: &Value) -> Value {
+        let key = (self.resolver)(value);
+        if let Some(result) = self.cache().get(&key) {
+            return result;
+        }
+        let result = (self.func)(value);
+        self.cache().set(key, result.clone());
+        result
+    }
+}
+impl<F, R, C> SyncMemoized<F, R, C> {
+    /// Same memoized function, with another cache
+    pub fn with_cache<D>(self, cache: D) -> SyncMemoized<F, R, D> {
+        SyncMemoized {
+            func: self.func,
+            resolver: self.resolver,
+            cache: Mutex::new(cache),
+        }
+    }
+    /// Locks the cache, to look at, change or clear the cached results
+    pub fn cache(&self) -> MutexGuard<'_, C> {
+        mutex_lock(&self.cache)
+    }
+}
+
+/// See lodash [memoize](https://lodash.com/docs/#memoize)
+///
+/// Results are cached by the key `resolver` computes from the argument, keys are compared by value,
+/// where lodash compares objects by reference.
+pub fn memoize<F: Fn(&Value) -> Value, R: Fn(&Value) -> Value>(
+    func: F,
+    resolver: R,
+) -> Memoized<F, R> {
+    Memoized {
+        func,
+        resolver,
+        cache: MemoCache::new(),
+    }
+}
+/// Same as [memoize()], but the memoized function can be shared between threads
+pub fn memoize_sync<F: Fn(&Value) -> Value, R: Fn(&Value) -> Value>(
+    func: F,
+    resolver: R,
+) -> SyncMemoized<F, R> {
+    SyncMemoized {
+        func,
+        resolver,
+        cache: Mutex::new(MemoCache::new()),
+    }
+}
+
+/// Based on [memoize()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{MemoCache, MemoizeCache};
+/// let object = json!({ "a": 1, "b": 2 });
+/// let other = json!({ "c": 3, "d": 4 });
+/// let mut values = memoize!(|o: &Value| json!(o.as_object().unwrap().values().collect::<Vec<_>>()));
+/// assert_eq!(values.call(&object), json!([1, 2]));
+/// assert_eq!(values.call(&other), json!([3, 4]));
+///
+/// // Modify the result cache.
+/// values.cache_mut().set(object.clone(), json!(["a", "b"]));
+/// assert_eq!(values.call(&object), json!(["a", "b"]));
+///
+/// // Replacing `_.memoize.Cache`.
+/// let mut values = values.with_cache(MemoCache::lru(1));
+/// assert_eq!(values.call(&object), json!([1, 2]));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::MemoizeCache;
+/// use std::cell::Cell;
+/// assert_eq!(memoize!().call(&json!(1)), json!(1));
+/// let calls = Cell::new(0);
+/// let mut double = memoize!(|v: &Value| {
+///   calls.set(calls.get() + 1);
+///   json!(v.as_f64().unwrap() * 2.0)
+/// });
+/// assert_eq!(double.call(&json!(1)), json!(2.0));
+/// assert_eq!(double.call(&json!(1.0)), json!(2.0));
+/// assert_eq!(calls.get(), 1);
+/// assert_eq!(double.cache().len(), 1);
+/// double.cache_mut().clear();
+/// assert_eq!(double.call(&json!(1)), json!(2.0));
+/// assert_eq!(calls.get(), 2);
+///
+/// let mut by_id = memoize!(|v: &Value| v["name"].clone(), |v: &Value| v["id"].clone());
+/// assert_eq!(by_id.call(&json!({"id": 1, "name": "fred"})), json!("fred"));
+/// assert_eq!(by_id.call(&json!({"id": 1, "name": "barney"})), json!("fred"));
+/// assert_eq!(by_id.cache().has(&json!(1)), true);
+/// assert_eq!(by_id.cache_mut().delete(&json!(1)), true);
+/// assert_eq!(by_id.call(&json!({"id": 1, "name": "barney"})), json!("barney"));
+///
+/// let mut keys = memoize!(|v: &Value| json!(v.as_object().unwrap().len()));
+/// keys.call(&json!({"a": 1, "b": [1, {"c": 2}]}));
+/// assert_eq!(keys.cache().has(&json!({"b": [1.0, {"c": 2}], "a": 1})), true);
+/// assert_eq!(keys.cache().has(&json!({"a": "1", "b": [1, {"c": 2}]})), false);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    () => {
+        $crate::memoize(
+            |v: &$crate::lib::Value| v.clone(),
+            |v: &$crate::lib::Value| v.clone(),
+        )
+    };
+    ($a:expr $(,)*) => {
+        $crate::memoize($a, |v: &$crate::lib::Value| v.clone())
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::memoize($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::memoize($a, $b)
+    };
+}
+/// Based on [memoize_sync()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::MemoCache;
+/// use std::sync::Arc;
+/// use std::thread;
+/// let square = Arc::new(memoize_sync!(|v: &Value| json!(v.as_i64().unwrap().pow(2))).with_cache(MemoCache::lru(8)));
+/// let handles: Vec<_> = (0..4)
+///   .map(|i| {
+///     let square = square.clone();
+///     thread::spawn(move || square.call(&json!(i % 2)))
+///   })
+///   .collect();
+/// for handle in handles {
+///   handle.join().unwrap();
+/// }
+/// assert_eq!(square.cache().len(), 2);
+/// assert_eq!(square.call(&json!(3)), json!(9));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::MemoizeCache;
+/// assert_eq!(memoize_sync!().call(&json!(1)), json!(1));
+/// let first = memoize_sync!(|v: &Value| v[1].clone(), |v: &Value| v[0].clone());
+/// assert_eq!(first.call(&json!(["a", 1])), json!(1));
+/// assert_eq!(first.call(&json!(["a", 2])), json!(1));
+/// first.cache().clear();
+/// assert_eq!(first.call(&json!(["a", 2])), json!(2));
+/// ```
+#[macro_export]
+macro_rules! memoize_sync {
+    () => {
+        $crate::memoize_sync(
+            |v: &$crate::lib::Value| v.clone(),
+            |v: &$crate::lib::Value| v.clone(),
+        )
+    };
+    ($a:expr $(,)*) => {
+        $crate::memoize_sync($a, |v: &$crate::lib::Value| v.clone())
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::memoize_sync($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::memoize_sync($a, $b)
+    };
+}
@@ -1,15 +1,11 @@
-#[doc(hidden)]
-pub fn after() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn ary() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn before() {
-    todo!()
-}
+mod js_fn;
+pub use js_fn::*;
+mod after;
+pub use after::*;
+mod ary;
+pub use ary::*;
+mod before;
+pub use before::*;
 #[doc(hidden)]
 pub fn bind() {
     todo!()
@@ -20,80 +16,42 @@ pub fn bind_key() {
 }
 #[doc(hidden)]
 pub use bind_key as bindKey;
-#[doc(hidden)]
-pub fn curry() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn curry_right() {
-    todo!()
-}
-#[doc(hidden)]
-pub use curry_right as curryRight;
-#[doc(hidden)]
-pub fn debounce() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn defer() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn delay() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn flip() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn memoize() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn negate() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn once() {
-    todo!()
-}
+mod clock;
+pub use clock::*;
+mod curry;
+pub use curry::*;
+mod debounce;
+pub use debounce::*;
+mod defer;
+pub use defer::*;
+mod delay;
+pub use delay::*;
+mod flip;
+pub use flip::*;
+mod memoize;
+pub use memoize::*;
+mod negate;
+pub use negate::*;
+mod once;
+pub use once::*;
 #[doc(hidden)]
 pub fn over_args() {
     todo!()
 }
 #[doc(hidden)]
 pub use over_args as overArgs;
-#[doc(hidden)]
-pub fn partial() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn partial_right() {
-    todo!()
-}
-#[doc(hidden)]
-pub use partial_right as partialRight;
-#[doc(hidden)]
-pub fn rearg() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn rest() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn spread() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn throttle() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn unary() {
-    todo!()
-}
+mod partial;
+pub use partial::*;
+mod rearg;
+pub use rearg::*;
+mod rest;
+pub use rest::*;
+mod spread;
+pub use spread::*;
+mod throttle;
+pub use throttle::*;
+mod unary;
+pub use unary::*;
 #[doc(hidden)]
 pub fn wrap() {
     todo!()
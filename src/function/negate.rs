@@ -0,0 +1,46 @@
+use crate::lib::Value;
+
+/// See lodash [negate](https://lodash.com/docs/#negate)
+pub fn negate<P: Fn(&Value) -> bool>(predicate: P) -> impl Fn(&Value) -> bool {
+    move |value| !predicate(value)
+}
+
+/// Based on [negate()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let is_even = |n: &Value| n.as_i64().unwrap() % 2 == 0;
+/// assert_eq!(
+///   vec![json!(1), json!(2), json!(3), json!(4), json!(5), json!(6)]
+///     .into_iter()
+///     .filter(negate!(is_even))
+///     .collect::<Vec<Value>>(),
+///   vec![json!(1), json!(3), json!(5)]
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(negate!()(&json!(1)), true);
+/// assert_eq!(negate!(|v: &Value| v.is_null())(&json!(null)), false);
+/// assert_eq!(negate!(negate!(|v: &Value| v.is_null()))(&json!(null)), true);
+/// assert_eq!([json!(null), json!(0)].iter().position(negate!(|v: &Value| v.is_null())), Some(1));
+/// ```
+#[macro_export]
+macro_rules! negate {
+    () => {
+        $crate::negate(|_: &$crate::lib::Value| false)
+    };
+    ($a:expr $(,)*) => {
+        $crate::negate($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::negate($a)
+    };
+}
@@ -0,0 +1,61 @@
+use crate::lib::Value;
+
+/// See lodash [once](https://lodash.com/docs/#once)
+///
+/// Calls after the first one return the value of the first call.
+pub fn once<F: FnOnce(Vec<Value>) -> Value>(func: F) -> impl FnMut(Vec<Value>) -> Value {
+    let mut func = Some(func);
+    let mut result = Value::Null;
+    move |args| {
+        if let Some(func) = func.take() {
+            result = func(args);
+        }
+        result.clone()
+    }
+}
+
+/// Based on [once()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let mut count = 0;
+/// let mut initialize = once!(|_: Vec<Value>| {
+///   count += 1; // createApplication
+///   json!("app")
+/// });
+/// assert_eq!(initialize(vec![]), json!("app"));
+/// assert_eq!(initialize(vec![]), json!("app"));
+/// drop(initialize);
+/// // => `createApplication` is invoked once
+/// assert_eq!(count, 1);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(once!()(vec![json!(1)]), json!(null));
+/// let mut first = once!(|args: Vec<Value>| args[0].clone());
+/// assert_eq!(first(vec![json!(1)]), json!(1));
+/// assert_eq!(first(vec![json!(2)]), json!(1));
+/// let v = vec![json!(1)];
+/// let mut take = once!(move |_: Vec<Value>| Value::Array(v)); // `FnOnce` is enough
+/// assert_eq!(take(vec![]), json!([1]));
+/// assert_eq!(take(vec![]), json!([1]));
+/// ```
+#[macro_export]
+macro_rules! once {
+    () => {
+        $crate::once(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::once($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::once($a)
+    };
+}
@@ -0,0 +1,106 @@
+use crate::lib::Value;
+use crate::JsFn;
+
+/// See lodash [partial](https://lodash.com/docs/#partial)
+///
+/// The `_` placeholder of lodash is not implemented.
+pub fn partial(func: JsFn, partials: Vec<Value>) -> JsFn {
+    let arity = func.arity().saturating_sub(partials.len());
+    JsFn::new(arity, move |args| {
+        let args: Vec<Value> = partials.iter().chain(args.iter()).cloned().collect();
+        func.call(&args)
+    })
+}
+/// See lodash [partialRight](https://lodash.com/docs/#partialRight)
+///
+/// The `_` placeholder of lodash is not implemented.
+pub fn partial_right(func: JsFn, partials: Vec<Value>) -> JsFn {
+    let arity = func.arity().saturating_sub(partials.len());
+    JsFn::new(arity, move |args| {
+        let args: Vec<Value> = args.iter().chain(partials.iter()).cloned().collect();
+        func.call(&args)
+    })
+}
+
+/// Based on [partial()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let greet = js_fn!(|greeting, name| json!(format!("{} {}", greeting.as_str().unwrap(), name.as_str().unwrap())));
+/// let say_hello_to = partial!(greet, json!("hello"));
+/// assert_eq!(say_hello_to.call(&[json!("fred")]), json!("hello fred"));
+/// // Partially applied with placeholders is not implemented
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(partial!().call(&[json!(1)]), json!(null));
+/// let all = js_fn!(|a, b, c| json!([a, b, c]));
+/// assert_eq!(partial!(all.clone()).arity(), 3);
+/// assert_eq!(partial!(all.clone(), json!(1)).arity(), 2);
+/// assert_eq!(partial!(all.clone(), json!(1), json!(2)).call(&[json!(3)]), json!([1, 2, 3]));
+/// assert_eq!(partial!(all.clone(), json!(1), json!(2)).call(&[]), json!([1, 2, null]));
+/// assert_eq!(partial!(all.clone(), json!(1), json!(2), json!(3), json!(4)).arity(), 0);
+/// let chunk = js_fn!(|array, size| chunk!(array, size.as_u64().unwrap_or(1) as usize));
+/// let pairs = partial_right!(chunk, json!(2));
+/// assert_eq!(pairs.call(&[json!([1, 2, 3])]), json!([[1, 2], [3]]));
+/// assert_eq!(curry!(partial!(all, json!(1))).call(&[json!(2)]).call(&[json!(3)]).value(), Some(json!([1, 2, 3])));
+/// ```
+#[macro_export]
+macro_rules! partial {
+    () => {
+        $crate::partial($crate::js_fn!(), vec![])
+    };
+    ($a:expr $(,)*) => {
+        $crate::partial($a, vec![])
+    };
+    ($a:expr, $($b:expr),+ $(,)*) => {
+        $crate::partial($a, vec![$($b),+])
+    };
+}
+/// Based on [partial_right()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let greet = js_fn!(|greeting, name| json!(format!("{} {}", greeting.as_str().unwrap(), name.as_str().unwrap())));
+/// let greet_fred = partial_right!(greet, json!("fred"));
+/// assert_eq!(greet_fred.call(&[json!("hi")]), json!("hi fred"));
+/// // Partially applied with placeholders is not implemented
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(partial_right!().call(&[json!(1)]), json!(null));
+/// let all = js_fn!(|a, b, c| json!([a, b, c]));
+/// assert_eq!(partial_right!(all.clone()).arity(), 3);
+/// assert_eq!(partial_right!(all.clone(), json!(2), json!(3)).call(&[json!(1)]), json!([1, 2, 3]));
+/// // like js, the partials are appended after the given arguments, not at the end of the parameters
+/// assert_eq!(partial_right!(all.clone(), json!(3)).call(&[json!(1)]), json!([1, 3, null]));
+/// let get = js_fn!(|object, path, default| get!(object, path, default));
+/// let get_or_zero = partial_right!(get, json!(0));
+/// assert_eq!(get_or_zero.call(&[json!({"a": 1}), json!("b")]), json!(0));
+/// ```
+#[macro_export]
+macro_rules! partial_right {
+    () => {
+        $crate::partial_right($crate::js_fn!(), vec![])
+    };
+    ($a:expr $(,)*) => {
+        $crate::partial_right($a, vec![])
+    };
+    ($a:expr, $($b:expr),+ $(,)*) => {
+        $crate::partial_right($a, vec![$($b),+])
+    };
+}
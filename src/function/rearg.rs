@@ -0,0 +1,53 @@
+use crate::lib::Value;
+use crate::JsFn;
+
+/// See lodash [rearg](https://lodash.com/docs/#rearg)
+///
+/// The argument at `indexes[i]` becomes the `i`th, an index out of the arguments gives `null`.
+pub fn rearg(func: JsFn, indexes: Vec<usize>) -> JsFn {
+    let arity = func.arity();
+    JsFn::new(arity, move |args| {
+        let mut reordered = args.to_vec();
+        for (i, index) in indexes.iter().take(args.len()).enumerate() {
+            reordered[i] = args.get(*index).cloned().unwrap_or(Value::Null);
+        }
+        func.call(&reordered)
+    })
+}
+
+/// Based on [rearg()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let rearged = rearg!(js_fn!(|a, b, c| json!([a, b, c])), 2, 0, 1);
+/// assert_eq!(rearged.call(&[json!("b"), json!("c"), json!("a")]), json!(["a", "b", "c"]));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(rearg!().call(&[json!(1)]), json!(null));
+/// let all = js_fn!(|a, b, c| json!([a, b, c]));
+/// assert_eq!(rearg!(all.clone()).call(&[json!(1), json!(2)]), json!([1, 2, null]));
+/// assert_eq!(rearg!(all.clone(), 1, 0).call(&[json!(1), json!(2), json!(3)]), json!([2, 1, 3]));
+/// assert_eq!(rearg!(all.clone(), 5).call(&[json!(1), json!(2)]), json!([null, 2, null]));
+/// assert_eq!(rearg!(all.clone(), 2, 1, 0).call(&[json!(1)]), json!([null, null, null]));
+/// assert_eq!(rearg!(all, 0, 0, 0).call(&[json!(1), json!(2), json!(3)]), json!([1, 1, 1]));
+/// ```
+#[macro_export]
+macro_rules! rearg {
+    () => {
+        $crate::rearg($crate::js_fn!(), vec![])
+    };
+    ($a:expr $(,)*) => {
+        $crate::rearg($a, vec![])
+    };
+    ($a:expr, $($b:expr),+ $(,)*) => {
+        $crate::rearg($a, vec![$($b),+])
+    };
+}
@@ -0,0 +1,68 @@
+use crate::lib::Value;
+
+/// See lodash [rest](https://lodash.com/docs/#rest)
+///
+/// `func` gets the first `start` arguments, padded with `null`, and then an array of the others.
+pub fn rest<F: Fn(Vec<Value>) -> Value>(func: F, start: usize) -> impl Fn(Vec<Value>) -> Value {
+    move |mut args| {
+        let others = if args.len() > start {
+            args.split_off(start)
+        } else {
+            vec![]
+        };
+        args.resize(start, Value::Null);
+        args.push(Value::Array(others));
+        func(args)
+    }
+}
+
+/// Based on [rest()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let say = rest!(|args: Vec<Value>| {
+///   let names: Vec<&str> = args[1].as_array().unwrap().iter().map(|v| v.as_str().unwrap()).collect();
+///   let (last, initial) = names.split_last().unwrap();
+///   json!(format!(
+///     "{} {}{}{}",
+///     args[0].as_str().unwrap(),
+///     initial.join(", "),
+///     if names.len() > 1 { ", & " } else { "" },
+///     last
+///   ))
+/// }, 1);
+/// assert_eq!(
+///   say(vec![json!("hello"), json!("fred"), json!("barney"), json!("pebbles")]),
+///   json!("hello fred, barney, & pebbles")
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(rest!()(vec![json!(1)]), json!(null));
+/// assert_eq!(rest!(Value::Array)(vec![json!(1), json!(2)]), json!([[1, 2]]));
+/// assert_eq!(rest!(Value::Array, 1)(vec![json!(1), json!(2), json!(3)]), json!([1, [2, 3]]));
+/// assert_eq!(rest!(Value::Array, 1)(vec![json!(1)]), json!([1, []]));
+/// assert_eq!(rest!(Value::Array, 2)(vec![json!(1)]), json!([1, null, []]));
+/// ```
+#[macro_export]
+macro_rules! rest {
+    () => {
+        $crate::rest(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null, 0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::rest($a, 0)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::rest($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::rest($a, $b)
+    };
+}
@@ -0,0 +1,64 @@
+use crate::lib::Value;
+
+/// See lodash [spread](https://lodash.com/docs/#spread)
+///
+/// `func` gets the first `start` arguments, followed by the items of the array at `start`,
+/// a non-array there adds nothing, and the arguments after it are dropped.
+pub fn spread<F: Fn(Vec<Value>) -> Value>(func: F, start: usize) -> impl Fn(Vec<Value>) -> Value {
+    move |mut args| {
+        let spread = if args.len() > start {
+            args.drain(start..).next()
+        } else {
+            None
+        };
+        if let Some(Value::Array(vec)) = spread {
+            args.extend(vec);
+        }
+        func(args)
+    }
+}
+
+/// Based on [spread()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let say = spread!(|args: Vec<Value>| {
+///   json!(format!("{} says {}", args[0].as_str().unwrap(), args[1].as_str().unwrap()))
+/// });
+/// assert_eq!(say(vec![json!(["fred", "hello"])]), json!("fred says hello"));
+///
+/// let numbers = vec![json!(1), json!(2)];
+/// let add = spread!(|args: Vec<Value>| add!(args[0].clone(), args[1].clone()), 0);
+/// assert_eq!(add(vec![json!(numbers)]), json!(3));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(spread!()(vec![json!([1])]), json!(null));
+/// assert_eq!(spread!(Value::Array)(vec![]), json!([]));
+/// assert_eq!(spread!(Value::Array)(vec![json!(1)]), json!([]));
+/// assert_eq!(spread!(Value::Array)(vec![json!([1, 2]), json!(3)]), json!([1, 2]));
+/// assert_eq!(spread!(Value::Array, 1)(vec![json!(0), json!([1, 2]), json!(3)]), json!([0, 1, 2]));
+/// assert_eq!(spread!(Value::Array, 2)(vec![json!(0)]), json!([0]));
+/// ```
+#[macro_export]
+macro_rules! spread {
+    () => {
+        $crate::spread(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null, 0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::spread($a, 0)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::spread($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::spread($a, $b)
+    };
+}
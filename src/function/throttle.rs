@@ -0,0 +1,199 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::lib::Value;
+use crate::{debounce_with, Clock, DebounceOptions, Debounced, SystemClock};
+
+/// The options of [throttle()]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottleOptions {
+    /// Invoke on the leading edge of the timeout
+    pub leading: bool,
+    /// Invoke on the trailing edge of the timeout
+    pub trailing: bool,
+}
+impl Default for ThrottleOptions {
+    fn default() -> ThrottleOptions {
+        ThrottleOptions {
+            leading: true,
+            trailing: true,
+        }
+    }
+}
+
+/// Same as [throttle()], but timed by `clock`
+pub fn throttle_with<F: FnMut(Vec<Value>) -> Value + Send + 'static>(
+    clock: Arc<dyn Clock>,
+    func: F,
+    wait: Duration,
+    options: ThrottleOptions,
+) -> Debounced<F> {
+    debounce_with(
+        clock,
+        func,
+        wait,
+        DebounceOptions {
+            leading: options.leading,
+            trailing: options.trailing,
+            max_wait: Some(wait),
+        },
+    )
+}
+/// See lodash [throttle](https://lodash.com/docs/#throttle)
+///
+/// Timed by a [SystemClock], the delayed invocations run in their own threads.
+pub fn throttle<F: FnMut(Vec<Value>) -> Value + Send + 'static>(
+    func: F,
+    wait: Duration,
+    options: ThrottleOptions,
+) -> Debounced<F> {
+    throttle_with(Arc::new(SystemClock::new()), func, wait, options)
+}
+
+/// Based on [throttle_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{ManualClock, ThrottleOptions};
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+/// let ms = Duration::from_millis;
+/// let clock = ManualClock::new();
+///
+/// // Avoid excessively updating the position while scrolling.
+/// let updates = Arc::new(Mutex::new(vec![]));
+/// let log = updates.clone();
+/// let update_position = move |args: Vec<Value>| { log.lock().unwrap().push(args[0].clone()); json!(null) };
+/// let on_scroll = throttle_with!(Arc::new(clock.clone()), update_position, ms(100));
+/// for i in 0..10 {
+///   on_scroll.call(vec![json!(i)]); // a scroll event every 30ms
+///   clock.advance(ms(30));
+/// }
+/// clock.advance(ms(100));
+/// assert_eq!(*updates.lock().unwrap(), vec![json!(0), json!(3), json!(7), json!(9)]);
+///
+/// // Invoke `renewToken` when the click event is fired, but not more than once every 5 minutes.
+/// let renewed = Arc::new(Mutex::new(0));
+/// let count = renewed.clone();
+/// let renew_token = move |_: Vec<Value>| { *count.lock().unwrap() += 1; json!(null) };
+/// let throttled = throttle_with!(Arc::new(clock.clone()), renew_token, ms(300_000), ThrottleOptions {
+///   trailing: false,
+///   ..ThrottleOptions::default()
+/// });
+/// throttled.call(vec![]);
+/// throttled.call(vec![]);
+/// clock.advance(ms(300_000));
+/// assert_eq!(*renewed.lock().unwrap(), 1);
+///
+/// // Cancel the trailing throttled invocation.
+/// throttled.call(vec![]);
+/// throttled.call(vec![]);
+/// throttled.cancel();
+/// clock.advance(ms(300_000));
+/// assert_eq!(*renewed.lock().unwrap(), 2);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// # use serde_json_lodash::{ManualClock, ThrottleOptions};
+/// # use std::sync::Arc;
+/// # use std::time::Duration;
+/// let ms = Duration::from_millis;
+/// let clock = ManualClock::new();
+/// let first = |args: Vec<Value>| args.get(0).cloned().unwrap_or_default();
+/// let throttled = throttle_with!(Arc::new(clock.clone()), first, ms(10));
+/// assert_eq!(throttled.call(vec![json!(1)]), json!(1));
+/// assert_eq!(throttled.call(vec![json!(2)]), json!(1));
+/// assert_eq!(throttled.flush(), json!(2));
+/// let trailing = throttle_with!(Arc::new(clock.clone()), first, ms(10), ThrottleOptions {
+///   leading: false,
+///   ..ThrottleOptions::default()
+/// });
+/// assert_eq!(trailing.call(vec![json!(1)]), json!(null));
+/// clock.advance(ms(5));
+/// assert_eq!(trailing.call(vec![json!(2)]), json!(null));
+/// clock.advance(ms(5));
+/// assert_eq!(trailing.call(vec![json!(3)]), json!(2));
+/// assert_eq!(throttle_with!(Arc::new(clock.clone())).call(vec![]), json!(null));
+/// ```
+#[macro_export]
+macro_rules! throttle_with {
+    ($c:expr $(,)*) => {
+        $crate::throttle_with(
+            $c,
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            $crate::ThrottleOptions::default(),
+        )
+    };
+    ($c:expr, $a:expr $(,)*) => {
+        $crate::throttle_with(
+            $c,
+            $a,
+            std::time::Duration::default(),
+            $crate::ThrottleOptions::default(),
+        )
+    };
+    ($c:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::throttle_with($c, $a, $b, $crate::ThrottleOptions::default())
+    };
+    ($c:expr, $a:expr, $b:expr, $d:expr $(,)*) => {
+        $crate::throttle_with($c, $a, $b, $d)
+    };
+    ($c:expr, $a:expr, $b:expr, $d:expr, $($rest:tt)*) => {
+        $crate::throttle_with($c, $a, $b, $d)
+    };
+}
+/// Based on [throttle()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// use std::time::Duration;
+/// let throttled = throttle!(|args: Vec<Value>| args[0].clone(), Duration::from_secs(60));
+/// assert_eq!(throttled.call(vec![json!(1)]), json!(1));
+/// assert_eq!(throttled.call(vec![json!(2)]), json!(1));
+/// throttled.cancel();
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(throttle!().call(vec![]), json!(null));
+/// ```
+#[macro_export]
+macro_rules! throttle {
+    () => {
+        $crate::throttle(
+            |_: Vec<$crate::lib::Value>| $crate::lib::Value::Null,
+            std::time::Duration::default(),
+            $crate::ThrottleOptions::default(),
+        )
+    };
+    ($a:expr $(,)*) => {
+        $crate::throttle(
+            $a,
+            std::time::Duration::default(),
+            $crate::ThrottleOptions::default(),
+        )
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::throttle($a, $b, $crate::ThrottleOptions::default())
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::throttle($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::throttle($a, $b, $c)
+    };
+}
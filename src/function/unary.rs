@@ -0,0 +1,48 @@
+use crate::lib::Value;
+
+/// See lodash [unary](https://lodash.com/docs/#unary)
+///
+/// Gives a closure of one argument, which calls `func` with only that argument.
+pub fn unary<F: Fn(Vec<Value>) -> Value>(func: F) -> impl Fn(Value) -> Value {
+    move |value| func(vec![value])
+}
+
+/// Based on [unary()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// // `parseInt(string, radix)`
+/// let parse_int = |args: Vec<Value>| match args.get(1).and_then(Value::as_u64).unwrap_or(0) {
+///   0 => json!(args[0].as_str().unwrap().parse::<i64>().ok()),
+///   radix @ 2..=36 => json!(i64::from_str_radix(args[0].as_str().unwrap(), radix as u32).ok()),
+///   _ => json!(null),
+/// };
+/// assert_eq!(
+///   vec![json!("6"), json!("8"), json!("10")].into_iter().map(unary!(parse_int)).collect::<Vec<Value>>(),
+///   vec![json!(6), json!(8), json!(10)]
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(unary!()(json!(1)), json!(null));
+/// assert_eq!(unary!(Value::Array)(json!(1)), json!([1]));
+/// ```
+#[macro_export]
+macro_rules! unary {
+    () => {
+        $crate::unary(|_: Vec<$crate::lib::Value>| $crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::unary($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::unary($a)
+    };
+}
@@ -1,11 +1,38 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
 use crate::lib::{json, Value, Number};
+use crate::{
+    json_array_to_string_x, get, FromValue, Iteratee, JsNumber, NonFiniteError, NonFinitePolicy,
+    ValueKey,
+};
+
+pub mod decimal;
+use decimal::Decimal;
 
-pub const MAX_SAFE_INTEGER: usize = u64::MAX as usize;
-pub const MIN_SAFE_INTEGER: isize = i64::MIN as isize;
+#[cfg(not(feature = "native_i64"))]
+pub const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991; // 2^53 - 1
+#[cfg(not(feature = "native_i64"))]
+pub const MIN_SAFE_INTEGER: i64 = -9_007_199_254_740_991;
+#[cfg(feature = "native_i64")]
+pub const MAX_SAFE_INTEGER: i64 = i64::MAX;
+#[cfg(feature = "native_i64")]
+pub const MIN_SAFE_INTEGER: i64 = i64::MIN;
 pub const MAX_VALUE: f64 = f64::MAX;
 pub const MIN_VALUE: f64 = f64::MIN;
+pub const MAX_INTEGER: f64 = f64::MAX;
+pub const MAX_ARRAY_LENGTH: usize = 4_294_967_295;
+pub const MAX_EXACT_INTEGER_F64: f64 = 9_007_199_254_740_992.0; // 2^53
 
-pub const MAX_SAFE_INTEGER_DIGITS: isize = 20; // 18_446_744_073_709_551_615
+#[cfg(not(feature = "native_i64"))]
+pub const MAX_SAFE_INTEGER_DIGITS: isize = 16; // 9_007_199_254_740_991
+#[cfg(not(feature = "native_i64"))]
+pub const MIN_SAFE_INTEGER_DIGITS: isize = -16; // -9_007_199_254_740_991
+#[cfg(feature = "native_i64")]
+pub const MAX_SAFE_INTEGER_DIGITS: isize = 19; // 9_223_372_036_854_775_807
+#[cfg(feature = "native_i64")]
 pub const MIN_SAFE_INTEGER_DIGITS: isize = -19; // -9_223_372_036_854_775_808
 pub const MAX_VALUE_DIGITS: isize = f64::MAX_10_EXP as isize;
 pub const MIN_VALUE_DIGITS: isize = f64::MIN_10_EXP as isize;
@@ -17,43 +44,79 @@ pub fn value_undefined() -> Value {
     json!(null)
 }
 pub fn value_nan() -> Value {
-    json!(f64::NAN)
+    JsNumber::NaN.into_value()
 }
 pub fn value_infinity() -> Value {
-    json!(f64::INFINITY)
-}
-pub fn number_nan() -> Number {
-    // Number::from_f64(f64::NAN).unwrap()
-    Number::from(0)
-}
-pub fn number_infinity() -> Number {
-    Number::from_f64(f64::INFINITY).unwrap()
-}
-pub fn number_neg_infinity() -> Number {
-    Number::from_f64(f64::NEG_INFINITY).unwrap()
-}
-pub fn string_to_option_number(s: String) -> Option<Number> {
-    if s.is_empty() {
-        Some(0.into())
-    } else if let Ok(n) = s.parse::<usize>() {
-        Some(n.into())
-    } else if let Ok(n) = s.parse::<isize>() {
-        Some(n.into())
-    } else if let Ok(n) = s.parse::<f64>() {
-        Number::from_f64(n)
+    JsNumber::Infinity.into_value()
+}
+// js `Boolean(value)`
+pub fn value_is_truthy(v: &Value) -> bool {
+    match v {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => number_to_f64(n) != 0.0,
+        Value::String(s) => !s.is_empty(),
+        Value::Array(_) | Value::Object(_) => true,
+    }
+}
+// The last argument of the variadic `_by` macros, an iteratee, or one more array when there is none,
+// compared by identity like lodash does
+pub trait ByLast {
+    type Iteratee: Iteratee;
+    fn split(self, arrays: &mut Vec<Value>) -> Self::Iteratee;
+}
+impl ByLast for Value {
+    type Iteratee = fn(&Value) -> Value;
+    fn split(self, arrays: &mut Vec<Value>) -> Self::Iteratee {
+        arrays.push(self);
+        Value::clone
+    }
+}
+impl<I: Iteratee> ByLast for I {
+    type Iteratee = I;
+    fn split(self, _: &mut Vec<Value>) -> I {
+        self
+    }
+}
+// `toSafeInteger` as an `isize`, saturated where `isize` is narrower than the safe integers
+pub fn value_to_safe_isize(v: Value) -> isize {
+    let i: i64 = crate::to_safe_integer(v);
+    isize::try_from(i).unwrap_or(if i < 0 { isize::MIN } else { isize::MAX })
+}
+pub fn f64_to_option_number(f: f64) -> Option<Number> {
+    if f.fract() == 0.0 && f.abs() <= MAX_EXACT_INTEGER_F64 {
+        if f < 0.0 {
+            Some((f as i64).into())
+        } else {
+            Some((f as u64).into())
+        }
     } else {
-        None
+        Number::from_f64(f)
+    }
+}
+// `Number::as_f64()` is `None` when an `arbitrary_precision` number overflows f64, js makes it Infinity
+pub fn number_to_f64(n: &Number) -> f64 {
+    n.as_f64()
+        .unwrap_or_else(|| n.to_string().parse().unwrap_or(f64::NAN))
+}
+pub fn number_to_option_i128(n: &Number) -> Option<i128> {
+    if let Some(u) = n.as_u64() {
+        Some(u.into())
+    } else {
+        n.as_i64().map(|i| i.into())
     }
 }
-pub fn vec_value_to_option_number(vec: Vec<Value>) -> Option<Number> {
-    match vec.len() {
-        0 => Some(value_null_to_number()),
-        1 => value_to_option_number(vec[0].clone()),
-        _ => None,
+pub fn i128_to_option_number(i: i128) -> Option<Number> {
+    if let Ok(u) = u64::try_from(i) {
+        Some(u.into())
+    } else if let Ok(i) = i64::try_from(i) {
+        Some(i.into())
+    } else {
+        None
     }
 }
-pub fn value_null_to_number() -> Number {
-    Number::from(0)
+pub fn f64_to_value(f: f64) -> Value {
+    JsNumber::from_f64(f).into_value()
 }
 pub fn bool_to_number(b: bool) -> Number {
     if b {
@@ -62,13 +125,518 @@ pub fn bool_to_number(b: bool) -> Number {
         Number::from(0)
     }
 }
-pub fn value_to_option_number(value: Value) -> Option<Number> {
+
+#[derive(Clone, Copy)]
+pub enum Rounding {
+    Ceil,
+    Floor,
+    Round,
+}
+impl Rounding {
+    fn round_f64(self, f: f64) -> f64 {
+        match self {
+            Rounding::Ceil => f.ceil(),
+            Rounding::Floor => f.floor(),
+            // js Math.round, halves go to +Infinity
+            Rounding::Round => {
+                let floor = f.floor();
+                if f - floor >= 0.5 {
+                    floor + 1.0
+                } else {
+                    floor
+                }
+            }
+        }
+    }
+    fn round_i128(self, i: i128, unit: i128) -> i128 {
+        let floor = i - i.rem_euclid(unit);
+        let rest = i - floor;
+        match self {
+            Rounding::Ceil if rest > 0 => floor + unit,
+            Rounding::Round if rest * 2 >= unit => floor + unit,
+            _ => floor,
+        }
+    }
+}
+// Shifts the decimal exponent through the string form (like lodash's createRound), so 1.005 is shifted to exactly 100.5
+fn shift_f64_exponent(f: f64, by: isize) -> f64 {
+    let s = format!("{:e}", f);
+    let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+    let exp = exp[1..].parse::<isize>().unwrap();
+    format!("{}e{}", mantissa, exp + by).parse::<f64>().unwrap()
+}
+pub fn number_round_by(n: Number, precision: isize, rounding: Rounding) -> JsNumber {
+    if cfg!(feature = "arbitrary_precision") {
+        let precision = precision.clamp(i32::MIN as isize, i32::MAX as isize) as i64;
+        return JsNumber::Finite(Decimal::from(&n).round(precision, rounding).into());
+    }
+    let precision = precision.clamp(-1000, 292);
+    if let Some(i) = number_to_option_i128(&n) {
+        if precision >= 0 {
+            return JsNumber::Finite(n);
+        }
+        if precision >= -18 {
+            let unit = 10_i128.pow(-precision as u32);
+            if let Some(n) = i128_to_option_number(rounding.round_i128(i, unit)) {
+                return JsNumber::Finite(n);
+            }
+        }
+    }
+    let f = number_to_f64(&n);
+    if precision == 0 || !f.is_finite() {
+        return JsNumber::from_f64(rounding.round_f64(f));
+    }
+    let shifted = shift_f64_exponent(f, precision);
+    if !shifted.is_finite() {
+        // only happens with a huge `f`, which has no decimals to round anyway
+        return JsNumber::from_f64(f);
+    }
+    JsNumber::from_f64(shift_f64_exponent(rounding.round_f64(shifted), -precision))
+}
+
+// Array.prototype.join(), what js does when an array is compared or converted to a primitive
+fn js_array_join(vec: &[Value]) -> String {
+    vec.iter()
+        .map(|v| match v {
+            Value::Null => "".to_owned(),
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => s.to_owned(),
+            Value::Array(vec) => js_array_join(vec),
+            Value::Object(_) => "[object Object]".to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+// https://262.ecma-international.org/11.0/#sec-abstract-relational-comparison
+// `None` means `undefined` in the spec, e.g. NaN is involved
+pub fn js_compare(a: &Value, b: &Value) -> Option<Ordering> {
+    fn to_primitive(v: &Value) -> Result<Number, String> {
+        match v {
+            Value::Null => Ok(Number::from(0)),
+            Value::Bool(b) => Ok(bool_to_number(*b)),
+            Value::Number(n) => Ok(n.clone()),
+            Value::String(s) => Err(s.to_owned()),
+            Value::Array(vec) => Err(js_array_join(vec)),
+            Value::Object(_) => Err("[object Object]".to_owned()),
+        }
+    }
+    let (pa, pb) = (to_primitive(a), to_primitive(b));
+    if let (Err(sa), Err(sb)) = (&pa, &pb) {
+        // js compares strings by utf-16 code units
+        return Some(sa.encode_utf16().cmp(sb.encode_utf16()));
+    }
+    let to_js_number = |p: Result<Number, String>| match p {
+        Ok(n) => JsNumber::Finite(n),
+        Err(s) => JsNumber::from(s.as_str()),
+    };
+    to_js_number(pa).js_cmp(&to_js_number(pb))
+}
+// A total order across all values, used to binary search sorted arrays, see `sorted_index()` for what it is
+pub fn value_sort_cmp(a: &Value, b: &Value) -> Ordering {
+    fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Bool(_) => 0,
+            Value::Number(_) => 1,
+            Value::String(_) => 2,
+            Value::Array(_) => 3,
+            Value::Object(_) => 4,
+            Value::Null => 5,
+        }
+    }
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(_), Value::Number(_)) => js_compare(a, b).unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a.encode_utf16().cmp(b.encode_utf16()),
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| value_sort_cmp(a, b))
+            .find(|o| *o != Ordering::Equal)
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(a), Value::Object(b)) => {
+            let mut a: Vec<_> = a.iter().collect();
+            let mut b: Vec<_> = b.iter().collect();
+            a.sort_by(|x, y| x.0.encode_utf16().cmp(y.0.encode_utf16()));
+            b.sort_by(|x, y| x.0.encode_utf16().cmp(y.0.encode_utf16()));
+            a.iter()
+                .zip(&b)
+                .map(|((ka, va), (kb, vb))| {
+                    ka.encode_utf16()
+                        .cmp(kb.encode_utf16())
+                        .then_with(|| value_sort_cmp(va, vb))
+                })
+                .find(|o| *o != Ordering::Equal)
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+// baseSortedIndex of lodash, the first index where `value` could be inserted, or the last one with `ret_highest`
+pub fn base_sorted_index(array: &[Value], value: &Value, ret_highest: bool) -> usize {
+    array.partition_point(|v| match value_sort_cmp(v, value) {
+        Ordering::Less => true,
+        Ordering::Equal => ret_highest,
+        Ordering::Greater => false,
+    })
+}
+// What js `+` produces, a number is kept as a JsNumber so NaN and Infinity survive the next additions
+pub enum JsSum {
+    Value(Value),
+    Number(JsNumber),
+}
+impl JsSum {
+    pub fn into_value(self) -> Value {
+        match self {
+            JsSum::Value(v) => v,
+            JsSum::Number(n) => n.into_value(),
+        }
+    }
+    pub fn try_into_value(self, policy: NonFinitePolicy) -> Result<Value, NonFiniteError> {
+        match self {
+            JsSum::Value(v) => Ok(v),
+            JsSum::Number(n) => n.try_into_value(policy),
+        }
+    }
+    pub fn into_js_number(self) -> JsNumber {
+        match self {
+            JsSum::Value(v) => JsNumber::from(v),
+            JsSum::Number(n) => n,
+        }
+    }
+    pub fn convert<R: FromValue>(self) -> R {
+        match self {
+            JsSum::Value(v) => R::from_value(v),
+            JsSum::Number(n) => R::from_js_number(n),
+        }
+    }
+}
+// js `+`, but an object is NaN instead of "[object Object]"
+pub fn js_add(augend: JsSum, addend: Value) -> JsSum {
+    match (augend, addend) {
+        (JsSum::Number(n), Value::String(s)) => JsSum::Value(Value::String(format!("{}{}", n, s))),
+        (JsSum::Number(n), addend) => JsSum::Number(n + JsNumber::from(addend)),
+        (JsSum::Value(Value::Object(_)), _) => JsSum::Number(JsNumber::NaN),
+        (JsSum::Value(Value::String(mut s)), addend) => {
+            s.push_str(&String::from_value(addend));
+            JsSum::Value(Value::String(s))
+        }
+        (JsSum::Value(augend), Value::String(s)) => {
+            let prefix = match augend {
+                Value::Null => "null".to_owned(),
+                Value::Array(vec) => json_array_to_string_x(vec),
+                _ => String::from_value(augend),
+            };
+            JsSum::Value(Value::String(format!("{}{}", prefix, s)))
+        }
+        (JsSum::Value(augend), addend) => {
+            JsSum::Number(JsNumber::from(augend) + JsNumber::from(addend))
+        }
+    }
+}
+// baseSum of lodash, but `null` is treated as `undefined` and skipped
+pub fn base_sum<I: Iterator<Item = Value>>(iter: I) -> Option<JsSum> {
+    let mut result: Option<JsSum> = None;
+    for current in iter {
+        if current.is_null() {
+            continue;
+        }
+        result = Some(match result {
+            None => JsSum::Value(current),
+            Some(prev) => js_add(prev, current),
+        });
+    }
+    result
+}
+// baseExtremum of lodash, returns the item which has the `wanted` computed value
+pub fn base_extremum<'a, I: Iterator<Item = (&'a Value, Value)>>(
+    iter: I,
+    wanted: Ordering,
+) -> Option<&'a Value> {
+    let mut result: Option<(&Value, Value)> = None;
+    for (item, current) in iter {
+        if current.is_null() {
+            continue;
+        }
+        let replace = match result {
+            None => true,
+            Some((_, ref computed)) => js_compare(&current, computed) == Some(wanted),
+        };
+        if replace {
+            result = Some((item, current));
+        }
+    }
+    result.map(|(item, _)| item)
+}
+// A lodash `start` or `end`, counted from the end when negative, and clamped to `0..=len`
+pub fn relative_index(index: isize, len: usize) -> usize {
+    if index < 0 {
+        len.saturating_sub(index.unsigned_abs())
+    } else {
+        (index as usize).min(len)
+    }
+}
+// A lodash `n` of items to take or drop, nothing when negative
+pub fn clamp_count(n: isize, len: usize) -> usize {
+    (n.max(0) as usize).min(len)
+}
+// baseSlice of lodash, on indexes already normalized by `relative_index` or `clamp_count`
+pub fn base_slice(mut vec: Vec<Value>, start: usize, end: usize) -> Vec<Value> {
+    if start >= end {
+        return vec![];
+    }
+    vec.truncate(end);
+    vec.drain(..start);
+    vec
+}
+// baseWhile of lodash, the `start..end` range to keep once the items matching `predicate` are taken or dropped
+pub fn base_while<I: Iteratee>(
+    vec: &[Value],
+    predicate: I,
+    is_drop: bool,
+    from_right: bool,
+) -> (usize, usize) {
+    let len = vec.len();
+    let matches = |v: &Value| value_is_truthy(&predicate.call(v));
+    if from_right {
+        let index = vec.iter().rposition(|v| !matches(v)).map_or(0, |i| i + 1);
+        if is_drop {
+            (0, index)
+        } else {
+            (index, len)
+        }
+    } else {
+        let index = vec.iter().position(|v| !matches(v)).unwrap_or(len);
+        if is_drop {
+            (index, len)
+        } else {
+            (0, index)
+        }
+    }
+}
+// baseDifference of lodash, keeps the items whose key isn't the key of an excluded value, and every item without a key
+pub fn base_difference<K: Fn(&Value) -> Option<ValueKey>>(
+    vec: Vec<Value>,
+    excluded: &[Value],
+    key: K,
+) -> Vec<Value> {
+    let excluded: HashSet<ValueKey> = excluded.iter().filter_map(&key).collect();
+    vec.into_iter()
+        .filter(|v| key(v).is_none_or(|k| !excluded.contains(&k)))
+        .collect()
+}
+// baseIntersection of lodash for two arrays, keeps the first item of each key which is also the key of an item of `others`
+pub fn base_intersection<K: Fn(&Value) -> Option<ValueKey>>(
+    vec: Vec<Value>,
+    others: &[Value],
+    key: K,
+) -> Vec<Value> {
+    let others: HashSet<ValueKey> = others.iter().filter_map(&key).collect();
+    let mut seen = HashSet::new();
+    vec.into_iter()
+        .filter(|v| key(v).is_some_and(|k| others.contains(&k) && seen.insert(k)))
+        .collect()
+}
+// baseUniq of lodash, keeps the first item of each key, and every item without a key
+pub fn base_uniq<K: Fn(&Value) -> Option<ValueKey>>(vec: Vec<Value>, key: K) -> Vec<Value> {
+    let mut seen = HashSet::new();
+    vec.into_iter()
+        .filter(|v| key(v).is_none_or(|k| seen.insert(k)))
+        .collect()
+}
+// baseSortedUniq of lodash, only compares an item to the one before, so equal items must be next to each other
+pub fn base_sorted_uniq<K: Fn(&Value) -> Option<ValueKey>>(vec: Vec<Value>, key: K) -> Vec<Value> {
+    let mut previous = None;
+    vec.into_iter()
+        .filter(|v| {
+            let current = key(v);
+            let keep = current.is_none() || current != previous;
+            previous = current;
+            keep
+        })
+        .collect()
+}
+// The items of every array, lodash skips the arguments which aren't arrays
+pub fn flatten_arrays(arrays: Vec<Value>) -> Vec<Value> {
+    arrays
+        .into_iter()
+        .filter_map(|v| match v {
+            Value::Array(vec) => Some(vec),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+// baseXor of lodash, keeps the items whose key is in no other array, then the first item of each key
+pub fn base_xor<K: Fn(&Value) -> Option<ValueKey>>(arrays: Vec<Value>, key: K) -> Vec<Value> {
+    let keyed: Vec<Vec<(Value, Option<ValueKey>)>> = arrays
+        .into_iter()
+        .filter_map(|v| match v {
+            Value::Array(vec) => Some(
+                vec.into_iter()
+                    .map(|v| {
+                        let k = key(&v);
+                        (v, k)
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .collect();
+    // The only array a key is in, `None` once it is in two of them
+    let mut owners: HashMap<&ValueKey, Option<usize>> = HashMap::new();
+    for (i, vec) in keyed.iter().enumerate() {
+        for k in vec.iter().filter_map(|(_, k)| k.as_ref()) {
+            let owner = owners.entry(k).or_insert(Some(i));
+            if *owner != Some(i) {
+                *owner = None;
+            }
+        }
+    }
+    let mut seen = HashSet::new();
+    let mut result = vec![];
+    for (i, vec) in keyed.iter().enumerate() {
+        for (v, k) in vec {
+            let keep = match k {
+                Some(k) => owners[k] == Some(i) && seen.insert(k),
+                None => true,
+            };
+            if keep {
+                result.push(v.clone());
+            }
+        }
+    }
+    result
+}
+// Same as `base_xor`, but compares every pair of items with `comparator`, in O(n²)
+pub fn base_xor_with<C: Fn(&Value, &Value) -> bool>(
+    arrays: Vec<Value>,
+    comparator: C,
+) -> Vec<Value> {
+    let vecs: Vec<Vec<Value>> = arrays
+        .into_iter()
+        .filter_map(|v| match v {
+            Value::Array(vec) => Some(vec),
+            _ => None,
+        })
+        .collect();
+    let mut result: Vec<Value> = vec![];
+    for (i, vec) in vecs.iter().enumerate() {
+        for v in vec {
+            let in_others = vecs
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != i && other.iter().any(|o| comparator(v, o)));
+            if !in_others && !result.iter().any(|seen| comparator(v, seen)) {
+                result.push(v.clone());
+            }
+        }
+    }
+    result
+}
+// lodash `stringToPath`
+pub fn string_to_path(s: &str) -> Vec<String> {
+    let mut vec = vec![];
+    let mut prev = "".to_owned();
+    let mut current = "".to_owned();
+    let mut in_square = false;
+    for c in s.chars() {
+        match c {
+            '.' => {
+                if in_square {
+                    current.push(c);
+                } else if prev != "]" {
+                    vec.push(current);
+                    current = "".to_owned();
+                }
+            }
+            '[' => {
+                in_square = true;
+                if !current.is_empty() {
+                    vec.push(current);
+                    current = "".to_owned();
+                }
+            }
+            ']' => {
+                in_square = false;
+                if prev == "]" {
+                    break;
+                }
+                let len = current.len();
+                if len >= 2 {
+                    let mut cs = current.chars();
+                    let first = cs.next().unwrap();
+                    let last = cs.last().unwrap();
+                    if (first == '"' && last == '"') || (first == '\'' && last == '\'') {
+                        current = current[1..(len - 1)].to_owned();
+                    }
+                }
+                vec.push(current);
+                current = "".to_owned();
+            }
+            _ => {
+                current.push(c);
+            }
+        }
+        prev = c.to_string();
+    }
+    if prev == "." || !current.is_empty() {
+        vec.push(current);
+    }
+    vec
+}
+// lodash `castPath`
+pub fn value_to_path(value: Value) -> Vec<String> {
     match value {
-        Value::Null => Some(value_null_to_number()),
-        Value::Bool(b) => Some(bool_to_number(b)),
-        Value::Number(n) => Some(n),
-        Value::String(s) => string_to_option_number(s),
-        Value::Array(vec) => vec_value_to_option_number(vec),
-        Value::Object(_) => None,
+        Value::Null => vec![],
+        Value::Bool(_) | Value::Number(_) | Value::Object(_) => vec![String::from_value(value)],
+        Value::String(s) => string_to_path(&s),
+        Value::Array(vec) => vec.into_iter().map(String::from_value).collect(),
     }
 }
+// Same as `get`, but borrows the object and takes a parsed path
+pub fn value_get_path(object: &Value, path: &[String]) -> Value {
+    let mut cur = object;
+    for (i, k) in path.iter().enumerate() {
+        cur = match cur {
+            Value::Array(vec) => match k.parse::<usize>().ok().and_then(|n| vec.get(n)) {
+                Some(v) => v,
+                None => return value_undefined(),
+            },
+            Value::Object(map) => match map.get(k) {
+                Some(v) => v,
+                None => return value_undefined(),
+            },
+            Value::String(_) => return get(cur.clone(), json!(path[i..]), value_undefined()),
+            _ => return value_undefined(),
+        }
+    }
+    cur.clone()
+}
+// A string equal for equal values, numbers compare like js (`1` and `1.0`), objects ignore key order
+pub fn value_to_key(v: &Value) -> String {
+    match v {
+        Value::Number(n) if cfg!(feature = "arbitrary_precision") => {
+            Number::from(Decimal::from(n)).to_string()
+        }
+        Value::Number(n) if n.is_f64() => f64_to_value(number_to_f64(n)).to_string(),
+        Value::String(s) => Value::String(s.clone()).to_string(),
+        Value::Array(vec) => format!(
+            "[{}]",
+            vec.iter().map(value_to_key).collect::<Vec<_>>().join(",")
+        ),
+        Value::Object(map) => {
+            let mut entries: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}:{}", Value::String(k.clone()), value_to_key(v)))
+                .collect();
+            entries.sort();
+            format!("{{{}}}", entries.join(","))
+        }
+        _ => v.to_string(),
+    }
+}
+// A panic in a user closure shouldn't make the state it guards unusable
+pub fn mutex_lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,347 @@
+// Exact decimal math on the strings `arbitrary_precision` keeps in `Number`, used instead of f64 when the feature is on
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::lib::Number;
+use super::Rounding;
+
+// Past this many zeros, a number is written in exponential notation like js does past 1e21
+const MAX_PLAIN_ZEROS: i64 = 20;
+// Exponents are saturated at this, so that adding two of them, or a digit count, never overflows
+const MAX_EXPONENT: i64 = i64::MAX / 4;
+// Past this many zeros between two operands, the smaller one is negligible, see `Decimal::add`
+const MAX_ALIGN_ZEROS: i64 = 1000;
+
+// (-1)^negative * digits * 10^exponent, `digits` is little-endian without trailing (most significant) zeros,
+// and zero has no digit at all
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decimal {
+    negative: bool,
+    digits: Vec<u8>,
+    exponent: i64,
+}
+
+fn trim_mag(mut a: Vec<u8>) -> Vec<u8> {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+    a
+}
+fn cmp_mag(a: &[u8], b: &[u8]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+fn add_mag(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let d = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        result.push(d % 10);
+        carry = d / 10;
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+// `a` must not be smaller than `b`
+fn sub_mag(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &d) in a.iter().enumerate() {
+        let mut d = d as i8 - *b.get(i).unwrap_or(&0) as i8 - borrow;
+        borrow = 0;
+        if d < 0 {
+            d += 10;
+            borrow = 1;
+        }
+        result.push(d as u8);
+    }
+    trim_mag(result)
+}
+fn mul_mag(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = vec![0_u32; a.len() + b.len()];
+    for (i, &da) in a.iter().enumerate() {
+        for (j, &db) in b.iter().enumerate() {
+            result[i + j] += da as u32 * db as u32;
+        }
+        // keep every digit small enough, so that no sum could overflow
+        let mut carry = 0;
+        for d in result.iter_mut().skip(i) {
+            *d += carry;
+            carry = *d / 10;
+            *d %= 10;
+        }
+    }
+    trim_mag(result.into_iter().map(|d| d as u8).collect())
+}
+
+impl Decimal {
+    fn new(negative: bool, digits: Vec<u8>, exponent: i64) -> Decimal {
+        let mut digits = trim_mag(digits);
+        let zeros = digits.iter().take_while(|d| **d == 0).count();
+        digits.drain(..zeros);
+        if digits.is_empty() {
+            return Decimal {
+                negative: false,
+                digits,
+                exponent: 0,
+            };
+        }
+        Decimal {
+            negative,
+            digits,
+            exponent: exponent
+                .saturating_add(zeros as i64)
+                .clamp(-MAX_EXPONENT, MAX_EXPONENT),
+        }
+    }
+    // JSON number grammar, which is what `Number` holds, an exponent too big for `i64` is saturated
+    pub fn parse(s: &str) -> Option<Decimal> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], parse_exponent(&s[i + 1..])?),
+            None => (s, 0),
+        };
+        let (int, frac) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+        if int.is_empty() || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let digits = int
+            .bytes()
+            .chain(frac.bytes())
+            .rev()
+            .map(|b| b - b'0')
+            .collect();
+        Some(Decimal::new(
+            negative,
+            digits,
+            exponent.saturating_sub(frac.len() as i64),
+        ))
+    }
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+    // Both digits scaled to the smaller exponent
+    fn aligned(&self, other: &Decimal) -> (Vec<u8>, Vec<u8>, i64) {
+        let exponent = self.exponent.min(other.exponent);
+        let scale = |d: &Decimal| {
+            let mut digits = vec![0; (d.exponent - exponent) as usize];
+            digits.extend_from_slice(&d.digits);
+            digits
+        };
+        (scale(self), scale(other), exponent)
+    }
+    // The position past the most significant digit, e.g. 1 for 5 and 0.5, 0 for 0.05
+    fn magnitude(&self) -> i64 {
+        self.exponent + self.digits.len() as i64
+    }
+    // Keeps `precision` decimals, a negative `precision` rounds to tens, hundreds...
+    pub fn round(&self, precision: i64, rounding: Rounding) -> Decimal {
+        let dropped = -precision - self.exponent;
+        if dropped <= 0 {
+            return self.clone();
+        }
+        let dropped = dropped as usize;
+        let (rest, kept) = if dropped < self.digits.len() {
+            self.digits.split_at(dropped)
+        } else {
+            (&self.digits[..], &[][..])
+        };
+        // `rest` is not empty, and its most significant digit is only there if `dropped` covers it
+        let rest_is_half = if dropped > self.digits.len() {
+            Ordering::Less
+        } else {
+            let mut half = vec![0; dropped];
+            half[dropped - 1] = 5;
+            cmp_mag(&trim_mag(rest.to_vec()), &half)
+        };
+        let up = match rounding {
+            Rounding::Ceil => !self.negative,
+            Rounding::Floor => self.negative,
+            // js Math.round, halves go to +Infinity
+            Rounding::Round => {
+                rest_is_half == Ordering::Greater
+                    || (rest_is_half == Ordering::Equal && !self.negative)
+            }
+        };
+        let digits = if up {
+            add_mag(kept, &[1])
+        } else {
+            kept.to_vec()
+        };
+        Decimal::new(self.negative, digits, -precision)
+    }
+    // Truncated, saturated at i128::MIN..=i128::MAX
+    pub fn to_i128(&self) -> i128 {
+        let int = match self.exponent {
+            // past 10^39, which is more than i128::MAX
+            e if e >= 0 && self.magnitude() > 39 => i128::MAX,
+            e if e >= 0 => (0..e).fold(
+                self.digits.iter().rev().fold(0_i128, |i, d| {
+                    i.saturating_mul(10).saturating_add(*d as i128)
+                }),
+                |i, _| i.saturating_mul(10),
+            ),
+            e => self
+                .digits
+                .iter()
+                .skip(usize::try_from(-e).unwrap_or(usize::MAX))
+                .rev()
+                .fold(0_i128, |i, d| {
+                    i.saturating_mul(10).saturating_add(*d as i128)
+                }),
+        };
+        if self.negative {
+            int.saturating_neg()
+        } else {
+            int
+        }
+    }
+}
+
+// The exponent of the JSON number grammar, `[+-]digits`, saturated instead of overflowing
+fn parse_exponent(s: &str) -> Option<i64> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some(digits.bytes().fold(0_i64, |e, b| {
+        let d = (b - b'0') as i64;
+        if negative {
+            e.saturating_mul(10).saturating_sub(d)
+        } else {
+            e.saturating_mul(10).saturating_add(d)
+        }
+    }))
+}
+
+impl From<&Number> for Decimal {
+    fn from(n: &Number) -> Decimal {
+        Decimal::parse(&n.to_string()).unwrap()
+    }
+}
+impl From<Decimal> for Number {
+    fn from(d: Decimal) -> Number {
+        let mut s = String::new();
+        if d.negative {
+            s.push('-');
+        }
+        let digits: String = d.digits.iter().rev().map(|d| (d + b'0') as char).collect();
+        let len = digits.len() as i64;
+        if d.is_zero() {
+            s.push('0');
+        } else if d.exponent >= 0 && d.exponent <= MAX_PLAIN_ZEROS {
+            s.push_str(&digits);
+            s.push_str(&"0".repeat(d.exponent as usize));
+        } else if d.exponent < 0 && len + d.exponent > 0 {
+            let (int, frac) = digits.split_at((len + d.exponent) as usize);
+            s.push_str(int);
+            s.push('.');
+            s.push_str(frac);
+        } else if d.exponent < 0 && len + d.exponent > -MAX_PLAIN_ZEROS {
+            s.push_str("0.");
+            s.push_str(&"0".repeat((-d.exponent - len) as usize));
+            s.push_str(&digits);
+        } else {
+            s.push_str(&digits[..1]);
+            if len > 1 {
+                s.push('.');
+                s.push_str(&digits[1..]);
+            }
+            s.push_str(&format!("e{}", d.exponent + len - 1));
+        }
+        s.parse().unwrap()
+    }
+}
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let sign = |d: &Decimal| match (d.is_zero(), d.negative) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        sign(self).cmp(&sign(other)).then_with(|| {
+            // the least significant digit isn't zero, so when the magnitudes are equal,
+            // the digits compare from the most significant one, and a prefix is smaller
+            let ordering = self
+                .magnitude()
+                .cmp(&other.magnitude())
+                .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()));
+            if self.negative {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    }
+}
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Neg for Decimal {
+    type Output = Decimal;
+    fn neg(self) -> Decimal {
+        Decimal::new(!self.negative, self.digits, self.exponent)
+    }
+}
+// Exact, unless one operand is more than `MAX_ALIGN_ZEROS` zeros below the last digit of the other,
+// then the result is the other one, which is the sum rounded to `MAX_ALIGN_ZEROS` more digits than it has,
+// instead of holding all those zeros
+impl Add for Decimal {
+    type Output = Decimal;
+    fn add(self, rhs: Decimal) -> Decimal {
+        if self.is_zero() {
+            return rhs;
+        }
+        if rhs.is_zero() {
+            return self;
+        }
+        let (big, small) = if self.magnitude() >= rhs.magnitude() {
+            (&self, &rhs)
+        } else {
+            (&rhs, &self)
+        };
+        if small.magnitude() <= big.exponent - MAX_ALIGN_ZEROS {
+            return big.clone();
+        }
+        let (a, b, exponent) = self.aligned(&rhs);
+        if self.negative == rhs.negative {
+            return Decimal::new(self.negative, add_mag(&a, &b), exponent);
+        }
+        match cmp_mag(&trim_mag(a.clone()), &trim_mag(b.clone())) {
+            Ordering::Less => Decimal::new(rhs.negative, sub_mag(&b, &a), exponent),
+            _ => Decimal::new(self.negative, sub_mag(&a, &b), exponent),
+        }
+    }
+}
+impl Sub for Decimal {
+    type Output = Decimal;
+    fn sub(self, rhs: Decimal) -> Decimal {
+        self + -rhs
+    }
+}
+impl Mul for Decimal {
+    type Output = Decimal;
+    fn mul(self, rhs: Decimal) -> Decimal {
+        Decimal::new(
+            self.negative != rhs.negative,
+            mul_mag(&self.digits, &rhs.digits),
+            self.exponent + rhs.exponent,
+        )
+    }
+}
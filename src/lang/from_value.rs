@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+
+use crate::lib::Value;
+use crate::internal::{type_name, value_is_truthy};
+use crate::{json_array_to_string_x, JsNumber};
+
+/// The output of the generic functions, like [capitalize()](crate::capitalize()), anything a [Value] can become
+///
+/// It's what frees those functions from their `_x` flavours: the caller picks the type of the result.
+/// The conversions are those of js, a `String` is what [to_string()](crate::to_string()) gives,
+/// an `f64` or a [JsNumber] what [to_number()](crate::to_number()) gives, an integer what [to_integer()](crate::to_integer()) gives,
+/// saturated to the type, and a `bool` whether the value is truthy. A `Vec` is the converted items of an array,
+/// and empty for anything else.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_json_lodash::FromValue;
+/// assert_eq!(String::from_value(json!([1, 2, 3])), "1,2,3".to_owned());
+/// assert_eq!(f64::from_value(json!("3.2")), 3.2);
+/// assert_eq!(i32::from_value(json!("3.2")), 3);
+/// assert_eq!(u8::from_value(json!(-1)), 0);
+/// assert_eq!(bool::from_value(json!("")), false);
+/// assert_eq!(Vec::<String>::from_value(json!(["a", 1])), vec!["a".to_owned(), "1".to_owned()]);
+/// assert_eq!(Vec::<String>::from_value(json!("a")), Vec::<String>::new());
+/// ```
+pub trait FromValue {
+    /// Converts from a [Value]
+    fn from_value(v: Value) -> Self;
+    /// Converts from a [JsNumber], which, unlike a [Value], can be `NaN` or infinite
+    fn from_js_number(n: JsNumber) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_value(n.into_value())
+    }
+}
+impl FromValue for Value {
+    fn from_value(v: Value) -> Self {
+        v
+    }
+}
+impl FromValue for String {
+    fn from_value(v: Value) -> Self {
+        match v {
+            Value::Null => "".into(),
+            Value::Bool(b) => {
+                if b {
+                    "true".into()
+                } else {
+                    "false".into()
+                }
+            }
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => s,
+            Value::Array(vec) => json_array_to_string_x(vec),
+            Value::Object(o) => type_name(&o).into(), // I don't think put [object Object] here is a good idea, so...
+        }
+    }
+    fn from_js_number(n: JsNumber) -> Self {
+        n.to_string()
+    }
+}
+impl FromValue for bool {
+    fn from_value(v: Value) -> Self {
+        value_is_truthy(&v)
+    }
+    fn from_js_number(n: JsNumber) -> Self {
+        let f = n.as_f64();
+        !(f.is_nan() || f == 0.0)
+    }
+}
+impl FromValue for JsNumber {
+    fn from_value(v: Value) -> Self {
+        JsNumber::from(v)
+    }
+    fn from_js_number(n: JsNumber) -> Self {
+        n
+    }
+}
+impl FromValue for f64 {
+    fn from_value(v: Value) -> Self {
+        JsNumber::from(v).as_f64()
+    }
+    fn from_js_number(n: JsNumber) -> Self {
+        n.as_f64()
+    }
+}
+macro_rules! impl_from_value_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl FromValue for $t {
+                fn from_value(v: Value) -> Self {
+                    match v {
+                        // exact, instead of rounding it to f64 first
+                        Value::Number(ref n) if !n.is_f64() => match n.as_i64() {
+                            Some(i) => <$t>::try_from(i).unwrap_or(if i < 0 { <$t>::MIN } else { <$t>::MAX }),
+                            None => <$t>::try_from(n.as_u64().unwrap_or(u64::MAX)).unwrap_or(<$t>::MAX),
+                        },
+                        // `as` saturates and makes `NaN` 0, like `toInteger` then clamping
+                        _ => f64::from_value(v) as $t,
+                    }
+                }
+                fn from_js_number(n: JsNumber) -> Self {
+                    match n {
+                        JsNumber::Finite(n) => Self::from_value(Value::Number(n)),
+                        n => n.as_f64() as $t,
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_from_value_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(v: Value) -> Self {
+        match v {
+            Value::Array(vec) => vec.into_iter().map(T::from_value).collect(),
+            _ => vec![],
+        }
+    }
+}
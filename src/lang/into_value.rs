@@ -0,0 +1,33 @@
+use crate::lib::Value;
+use crate::JsNumber;
+
+/// The input of the generic functions, like [capitalize()](crate::capitalize()), anything which can become a [Value]
+///
+/// It's what frees those functions from their `x_` flavours: they take a `&str` as well as a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_json_lodash::{IntoValue, JsNumber};
+/// assert_eq!("fred".into_value(), json!("fred"));
+/// assert_eq!(String::from("fred").into_value(), json!("fred"));
+/// assert_eq!(3u8.into_value(), json!(3));
+/// assert_eq!(vec![1, 2].into_value(), json!([1, 2]));
+/// assert_eq!(json!({"a": 1}).into_value(), json!({"a": 1}));
+/// assert_eq!(IntoValue::into_value(JsNumber::NaN), json!(null));
+/// ```
+pub trait IntoValue {
+    /// Converts into a [Value]
+    fn into_value(self) -> Value;
+}
+impl<T: Into<Value>> IntoValue for T {
+    fn into_value(self) -> Value {
+        self.into()
+    }
+}
+impl IntoValue for JsNumber {
+    fn into_value(self) -> Value {
+        JsNumber::into_value(self)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::lib::{Value};
+use crate::internal::{number_to_f64, MAX_SAFE_INTEGER, MIN_SAFE_INTEGER};
+
+/// See lodash [isSafeInteger](https://lodash.com/docs/#isSafeInteger)
+pub fn is_safe_integer(v: Value) -> bool {
+    match v {
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                (MIN_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&i)
+            } else if n.is_u64() {
+                false
+            } else {
+                let f = number_to_f64(&n);
+                // compared as integers, `i64::MAX as f64` rounds up to 2^63, which isn't an `i64`
+                f.fract() == 0.0
+                    && (MIN_SAFE_INTEGER as i128..=MAX_SAFE_INTEGER as i128).contains(&(f as i128))
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Based on [is_safe_integer()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   is_safe_integer!(json!(3)),
+///   true
+/// );
+/// assert_eq!(
+///   is_safe_integer!(json!(5e-324)),
+///   false
+/// );
+/// //assert_eq!(
+/// //  is_safe_integer!(json!(f64::INFINITY)), // serde_json will convert this to Value::Null
+/// //  false
+/// //);
+/// assert_eq!(
+///   is_safe_integer!(json!("3")),
+///   false
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(is_safe_integer!(), false);
+/// assert_eq!(is_safe_integer!(json!(null)), false);
+/// assert_eq!(is_safe_integer!(json!(true)), false);
+/// assert_eq!(is_safe_integer!(json!(-3)), true);
+/// assert_eq!(is_safe_integer!(json!(3.0)), true);
+/// assert_eq!(is_safe_integer!(json!(3.5)), false);
+/// assert_eq!(is_safe_integer!(json!(9007199254740991_i64)), true);
+/// assert_eq!(is_safe_integer!(json!(-9007199254740991_i64)), true);
+/// assert_eq!(is_safe_integer!(json!(u64::MAX)), false);
+/// assert_eq!(is_safe_integer!(json!([3])), false);
+/// assert_eq!(is_safe_integer!(json!({})), false);
+/// # #[cfg(not(feature = "native_i64"))] {
+/// assert_eq!(is_safe_integer!(json!(9007199254740992_i64)), false);
+/// assert_eq!(is_safe_integer!(json!(i64::MIN)), false);
+/// assert_eq!(is_safe_integer!(json!(9007199254740992.0)), false);
+/// assert_eq!(is_safe_integer!(json!(1e300)), false);
+/// # }
+/// # #[cfg(feature = "native_i64")] {
+/// assert_eq!(is_safe_integer!(json!(9007199254740992_i64)), true);
+/// assert_eq!(is_safe_integer!(json!(i64::MIN)), true);
+/// assert_eq!(is_safe_integer!(json!(1e300)), false);
+/// assert_eq!(is_safe_integer!(json!(9223372036854775808.0)), false); // 2^63
+/// assert_eq!(is_safe_integer!(json!(-9223372036854775808.0)), true);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! is_safe_integer {
+    () => {
+        false
+    };
+    ($a:expr $(,)*) => {
+        $crate::is_safe_integer($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::is_safe_integer($a)
+    };
+}
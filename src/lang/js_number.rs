@@ -0,0 +1,270 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::cmp::Ordering;
+
+use crate::lib::{Value, Number};
+use crate::internal::{f64_to_option_number, number_to_f64, number_to_option_i128};
+use crate::internal::decimal::Decimal;
+use crate::{x_checked_add_x, x_checked_subtract_x, x_checked_multiply_x, x_checked_divide_x};
+
+/// A js number, which unlike [Number] can also be `NaN`, `Infinity`, `-Infinity` and `-0`
+///
+/// Math and conversions compute with it, so a non-finite result survives until it is written back
+/// into a [Value], see [NonFinitePolicy] for how that happens.
+///
+/// Unlike js, `NaN` is equal to `NaN`, like its default [Value] `null` is equal to `null`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Number};
+/// use serde_json_lodash::JsNumber;
+/// assert_eq!(JsNumber::from(json!("12")), Number::from(12));
+/// assert_eq!(JsNumber::from(json!("a")), JsNumber::NaN);
+/// assert_eq!(JsNumber::from(json!(1)) / JsNumber::from(json!("-0")), JsNumber::NegInfinity);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(JsNumber::from(json!(u64::MAX)) + JsNumber::from(json!(1)), Number::from_f64(18446744073709551616.0).unwrap());
+/// assert_eq!(JsNumber::NaN.to_string(), "NaN");
+/// assert_eq!(JsNumber::Infinity.into_value(), json!(null));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsNumber {
+    /// Any finite number but `-0`, integers stay exact as long as they fit in `i64`/`u64`
+    Finite(Number),
+    ///
+    NaN,
+    ///
+    Infinity,
+    ///
+    NegInfinity,
+    /// Written into a [Value] as `0`, but still tells `1 / -0` is `-Infinity`
+    NegZero,
+}
+
+impl JsNumber {
+    ///
+    pub fn from_f64(f: f64) -> JsNumber {
+        if f.is_nan() {
+            JsNumber::NaN
+        } else if f == f64::INFINITY {
+            JsNumber::Infinity
+        } else if f == f64::NEG_INFINITY {
+            JsNumber::NegInfinity
+        } else if f == 0.0 && f.is_sign_negative() {
+            JsNumber::NegZero
+        } else {
+            JsNumber::Finite(f64_to_option_number(f).unwrap())
+        }
+    }
+    ///
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            JsNumber::Finite(n) => number_to_f64(n),
+            JsNumber::NaN => f64::NAN,
+            JsNumber::Infinity => f64::INFINITY,
+            JsNumber::NegInfinity => f64::NEG_INFINITY,
+            JsNumber::NegZero => -0.0,
+        }
+    }
+    ///
+    pub fn is_nan(&self) -> bool {
+        matches!(self, JsNumber::NaN)
+    }
+    /// `NaN`, `Infinity` and `-Infinity` are not finite, `-0` is
+    pub fn is_finite(&self) -> bool {
+        matches!(self, JsNumber::Finite(_) | JsNumber::NegZero)
+    }
+    /// js `<`, `>` and `==` on numbers, `None` if either one is `NaN`
+    ///
+    /// Integers are compared exactly, even past 2^53.
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use serde_json::Number;
+    /// use serde_json_lodash::JsNumber;
+    /// assert_eq!(JsNumber::from(Number::from(u64::MAX)).js_cmp(&JsNumber::from(Number::from(u64::MAX - 1))), Some(Ordering::Greater));
+    /// assert_eq!(JsNumber::NegZero.js_cmp(&JsNumber::from(Number::from(0))), Some(Ordering::Equal));
+    /// assert_eq!(JsNumber::NegInfinity.js_cmp(&JsNumber::from(-1e300)), Some(Ordering::Less));
+    /// assert_eq!(JsNumber::NaN.js_cmp(&JsNumber::NaN), None);
+    /// ```
+    pub fn js_cmp(&self, other: &JsNumber) -> Option<Ordering> {
+        if let (JsNumber::Finite(a), JsNumber::Finite(b)) = (self, other) {
+            if let (Some(a), Some(b)) = (number_to_option_i128(a), number_to_option_i128(b)) {
+                return Some(a.cmp(&b));
+            }
+            if cfg!(feature = "arbitrary_precision") {
+                return Some(Decimal::from(a).cmp(&Decimal::from(b)));
+            }
+        }
+        self.as_f64().partial_cmp(&other.as_f64())
+    }
+    /// Writes it into a [Value], `NaN`, `Infinity` and `-Infinity` as `null` like `json!(f64::NAN)` does
+    ///
+    /// See [JsNumber::try_into_value()] for another [NonFinitePolicy].
+    pub fn into_value(self) -> Value {
+        match self {
+            JsNumber::Finite(n) => Value::Number(n),
+            JsNumber::NegZero => Value::Number(Number::from(0)),
+            _ => Value::Null,
+        }
+    }
+    /// Writes it into a [Value], following `policy`
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// use serde_json::json;
+    /// use serde_json_lodash::{JsNumber, NonFinitePolicy};
+    /// assert_eq!(JsNumber::NaN.try_into_value(NonFinitePolicy::Null).unwrap(), json!(null));
+    /// assert_eq!(JsNumber::NegInfinity.try_into_value(NonFinitePolicy::String).unwrap(), json!("-Infinity"));
+    /// assert!(JsNumber::Infinity.try_into_value(NonFinitePolicy::Error).is_err());
+    /// assert_eq!(JsNumber::NegZero.try_into_value(NonFinitePolicy::Error).unwrap(), json!(0));
+    /// ```
+    pub fn try_into_value(self, policy: NonFinitePolicy) -> Result<Value, NonFiniteError> {
+        if self.is_finite() {
+            return Ok(self.into_value());
+        }
+        match policy {
+            NonFinitePolicy::Null => Ok(Value::Null),
+            NonFinitePolicy::String => Ok(Value::String(self.to_string())),
+            NonFinitePolicy::Error => Err(NonFiniteError(self)),
+        }
+    }
+    // The exact integer result of `checked` is used when there is one, and the f64 one otherwise
+    fn binary_op(
+        self,
+        rhs: JsNumber,
+        checked: fn(Number, Number) -> Option<Number>,
+        op: fn(f64, f64) -> f64,
+    ) -> JsNumber {
+        let f = op(self.as_f64(), rhs.as_f64());
+        if let (JsNumber::Finite(n), JsNumber::Finite(n2)) = (self, rhs) {
+            if let Some(n) = checked(n, n2) {
+                if f != 0.0 || f.is_sign_positive() {
+                    return JsNumber::Finite(n);
+                }
+            }
+        }
+        JsNumber::from_f64(f)
+    }
+}
+
+/// js ToNumber, see [to_number()](crate::to_number())
+impl From<Value> for JsNumber {
+    fn from(v: Value) -> JsNumber {
+        match v {
+            Value::Null => JsNumber::Finite(Number::from(0)),
+            Value::Bool(b) => JsNumber::Finite(Number::from(b as u8)),
+            Value::Number(n) => JsNumber::Finite(n),
+            Value::String(s) => JsNumber::from(s.as_str()),
+            // ToPrimitive of an array is its `join(',')`, so only zero or one item could be a number
+            Value::Array(mut vec) => match vec.len() {
+                0 => JsNumber::Finite(Number::from(0)),
+                1 => match vec.pop().unwrap() {
+                    Value::Bool(_) | Value::Object(_) => JsNumber::NaN,
+                    v => JsNumber::from(v),
+                },
+                _ => JsNumber::NaN,
+            },
+            Value::Object(_) => JsNumber::NaN,
+        }
+    }
+}
+impl From<Number> for JsNumber {
+    fn from(n: Number) -> JsNumber {
+        JsNumber::Finite(n)
+    }
+}
+impl From<f64> for JsNumber {
+    fn from(f: f64) -> JsNumber {
+        JsNumber::from_f64(f)
+    }
+}
+impl PartialEq<Number> for JsNumber {
+    fn eq(&self, other: &Number) -> bool {
+        match self {
+            JsNumber::Finite(n) => n == other,
+            JsNumber::NegZero => *other == Number::from(0),
+            _ => false,
+        }
+    }
+}
+/// js `String(number)`
+impl fmt::Display for JsNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsNumber::Finite(n) => write!(f, "{}", n),
+            JsNumber::NaN => write!(f, "NaN"),
+            JsNumber::Infinity => write!(f, "Infinity"),
+            JsNumber::NegInfinity => write!(f, "-Infinity"),
+            JsNumber::NegZero => write!(f, "0"),
+        }
+    }
+}
+impl Add for JsNumber {
+    type Output = JsNumber;
+    fn add(self, rhs: JsNumber) -> JsNumber {
+        self.binary_op(rhs, x_checked_add_x, |a, b| a + b)
+    }
+}
+impl Sub for JsNumber {
+    type Output = JsNumber;
+    fn sub(self, rhs: JsNumber) -> JsNumber {
+        self.binary_op(rhs, x_checked_subtract_x, |a, b| a - b)
+    }
+}
+impl Mul for JsNumber {
+    type Output = JsNumber;
+    fn mul(self, rhs: JsNumber) -> JsNumber {
+        self.binary_op(rhs, x_checked_multiply_x, |a, b| a * b)
+    }
+}
+impl Div for JsNumber {
+    type Output = JsNumber;
+    fn div(self, rhs: JsNumber) -> JsNumber {
+        self.binary_op(rhs, x_checked_divide_x, |a, b| a / b)
+    }
+}
+
+/// How a `NaN`, `Infinity` or `-Infinity` result is written into a [Value], which can't hold them
+///
+/// The functions returning a [Value], like [add()](crate::add()), write them as `null`, what `json!(f64::NAN)` does.
+/// Their `try_` variants, like [try_add()](crate::try_add()), take the policy to follow instead.
+/// `-0` is always written as `0`.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::{NonFinitePolicy, NonFiniteError, JsNumber};
+/// assert_eq!(divide!(json!(1), json!(0)), json!(null));
+/// assert_eq!(try_divide!(NonFinitePolicy::String, json!(1), json!(0)), Ok(json!("Infinity")));
+/// assert_eq!(try_add!(NonFinitePolicy::String, json!(1), json!({})), Ok(json!("NaN")));
+/// assert_eq!(try_ceil!(NonFinitePolicy::String, json!("-Infinity")), Ok(json!("-Infinity")));
+/// assert_eq!(try_to_number!(NonFinitePolicy::String, json!("NaN")), Ok(json!("NaN")));
+/// assert_eq!(try_divide!(NonFinitePolicy::Error, json!(0), json!(0)), Err(NonFiniteError(JsNumber::NaN)));
+/// assert_eq!(try_divide!(NonFinitePolicy::Error, json!(1), json!(2)), Ok(json!(0.5)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NonFinitePolicy {
+    /// `null`, so it can't be told from `undefined`
+    Null,
+    /// `"NaN"`, `"Infinity"` or `"-Infinity"`, which [to_number()](crate::to_number()) reads back
+    String,
+    /// A [NonFiniteError]
+    Error,
+}
+
+/// A non-finite [JsNumber] which had to be written into a [Value] under [NonFinitePolicy::Error]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonFiniteError(pub JsNumber);
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} can't be written into a Value", self.0)
+    }
+}
+impl std::error::Error for NonFiniteError {}
@@ -218,12 +218,8 @@ pub fn is_reg_exp() {
 }
 #[doc(hidden)]
 pub use is_reg_exp as isRegExp;
-#[doc(hidden)]
-pub fn is_safe_integer() {
-    todo!()
-}
-#[doc(hidden)]
-pub use is_safe_integer as isSafeInteger;
+mod is_safe_integer;
+pub use is_safe_integer::*;
 #[doc(hidden)]
 pub fn is_set() {
     todo!()
@@ -266,6 +262,14 @@ pub fn is_weak_set() {
 }
 #[doc(hidden)]
 pub use is_weak_set as isWeakSet;
+mod from_value;
+pub use from_value::*;
+mod into_value;
+pub use into_value::*;
+mod js_number;
+pub use js_number::*;
+mod value_key;
+pub use value_key::*;
 #[doc(hidden)]
 pub fn lt() {
     todo!()
@@ -280,30 +284,14 @@ pub fn to_array() {
 }
 #[doc(hidden)]
 pub use to_array as toArray;
-#[doc(hidden)]
-pub fn to_finite() {
-    todo!()
-}
-#[doc(hidden)]
-pub use to_finite as toFinite;
-#[doc(hidden)]
-pub fn to_integer() {
-    todo!()
-}
-#[doc(hidden)]
-pub use to_integer as toInteger;
-#[doc(hidden)]
-pub fn to_length() {
-    todo!()
-}
-#[doc(hidden)]
-pub use to_length as toLength;
-#[doc(hidden)]
-pub fn to_number() {
-    todo!()
-}
-#[doc(hidden)]
-pub use to_number as toNumber;
+mod to_finite;
+pub use to_finite::*;
+mod to_integer;
+pub use to_integer::*;
+mod to_length;
+pub use to_length::*;
+mod to_number;
+pub use to_number::*;
 #[doc(hidden)]
 pub fn to_plain_object() {
     todo!()
@@ -0,0 +1,142 @@
+use crate::lib::{Value};
+use crate::internal::{f64_to_value, MAX_INTEGER};
+use crate::{to_number, FromValue, IntoValue};
+
+///
+#[deprecated(note = "use `to_finite::<f64>()`")]
+pub fn to_finite_x(v: Value) -> f64 {
+    to_finite(v)
+}
+/// See lodash [toFinite](https://lodash.com/docs/#toFinite)
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_finite;
+/// assert_eq!(to_finite::<f64>(json!(3.2)), 3.2);
+/// assert_eq!(to_finite::<f64>("Infinity"), f64::MAX);
+/// assert_eq!(to_finite::<Value>("a"), json!(0));
+/// ```
+pub fn to_finite<R: FromValue>(value: impl IntoValue) -> R {
+    let v = value.into_value();
+    R::from_value(match v {
+        Value::Number(_) => v,
+        _ => {
+            let n: f64 = to_number(v);
+            f64_to_value(if n.is_nan() {
+                0.0
+            } else if n.is_infinite() {
+                MAX_INTEGER.copysign(n)
+            } else {
+                n
+            })
+        }
+    })
+}
+
+/// Based on [to_finite_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_finite_x!(json!(3.2)),
+///   3.2
+/// );
+/// assert_eq!(
+///   to_finite_x!(json!(5e-324)),
+///   5e-324
+/// );
+/// assert_eq!(
+///   to_finite_x!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   1.7976931348623157e+308
+/// );
+/// assert_eq!(
+///   to_finite_x!(json!("3.2")),
+///   3.2
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_finite_x!(), 0.0);
+/// assert_eq!(to_finite_x!(json!(null)), 0.0);
+/// assert_eq!(to_finite_x!(json!(true)), 1.0);
+/// assert_eq!(to_finite_x!(json!("-Infinity")), -1.7976931348623157e+308);
+/// assert_eq!(to_finite_x!(json!("a")), 0.0);
+/// assert_eq!(to_finite_x!(json!([" 2 "])), 2.0);
+/// assert_eq!(to_finite_x!(json!({})), 0.0);
+/// ```
+#[deprecated(note = "use `to_finite::<f64>()`")]
+#[macro_export]
+macro_rules! to_finite_x {
+    () => {
+        0.0_f64
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_finite_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_finite_x($a);
+        result
+    }};
+}
+/// Based on [to_finite()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_finite!(json!(3.2)),
+///   json!(3.2)
+/// );
+/// assert_eq!(
+///   to_finite!(json!(5e-324)),
+///   json!(5e-324)
+/// );
+/// assert_eq!(
+///   to_finite!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   json!(1.7976931348623157e+308)
+/// );
+/// assert_eq!(
+///   to_finite!(json!("3.2")),
+///   json!(3.2)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_finite!(), json!(0));
+/// assert_eq!(to_finite!(json!(null)), json!(0));
+/// assert_eq!(to_finite!(json!(false)), json!(0));
+/// assert_eq!(to_finite!(json!("0x10")), json!(16));
+/// assert_eq!(to_finite!(json!("a")), json!(0));
+/// assert_eq!(to_finite!(json!([])), json!(0));
+/// assert_eq!(to_finite!(json!({})), json!(0));
+/// ```
+#[macro_export]
+macro_rules! to_finite {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::to_finite::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::to_finite::<$crate::lib::Value>($a)
+    };
+}
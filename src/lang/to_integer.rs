@@ -0,0 +1,135 @@
+use crate::lib::{Value};
+use crate::internal::{f64_to_value};
+use crate::{to_finite, FromValue, IntoValue};
+
+///
+#[deprecated(note = "use `to_integer::<f64>()`")]
+pub fn to_integer_x(v: Value) -> f64 {
+    to_integer(v)
+}
+/// See lodash [toInteger](https://lodash.com/docs/#toInteger)
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_integer;
+/// assert_eq!(to_integer::<f64>(json!(3.2)), 3.0);
+/// assert_eq!(to_integer::<Value>("3.2"), json!(3));
+/// assert_eq!(to_integer::<u8>("-Infinity"), 0);
+/// assert_eq!(to_integer::<i128>(json!(u64::MAX)), u64::MAX as i128);
+/// ```
+pub fn to_integer<R: FromValue>(value: impl IntoValue) -> R {
+    let v = value.into_value();
+    R::from_value(match v {
+        Value::Number(ref n) if !n.is_f64() => v,
+        _ => f64_to_value(to_finite::<f64>(v).trunc()),
+    })
+}
+
+/// Based on [to_integer_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_integer_x!(json!(3.2)),
+///   3.0
+/// );
+/// assert_eq!(
+///   to_integer_x!(json!(5e-324)),
+///   0.0
+/// );
+/// assert_eq!(
+///   to_integer_x!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   1.7976931348623157e+308
+/// );
+/// assert_eq!(
+///   to_integer_x!(json!("3.2")),
+///   3.0
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_integer_x!(), 0.0);
+/// assert_eq!(to_integer_x!(json!(null)), 0.0);
+/// assert_eq!(to_integer_x!(json!(-3.7)), -3.0);
+/// assert_eq!(to_integer_x!(json!("-Infinity")), -1.7976931348623157e+308);
+/// assert_eq!(to_integer_x!(json!("a")), 0.0);
+/// assert_eq!(to_integer_x!(json!({})), 0.0);
+/// ```
+#[deprecated(note = "use `to_integer::<f64>()`")]
+#[macro_export]
+macro_rules! to_integer_x {
+    () => {
+        0.0_f64
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_integer_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_integer_x($a);
+        result
+    }};
+}
+/// Based on [to_integer()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_integer!(json!(3.2)),
+///   json!(3)
+/// );
+/// assert_eq!(
+///   to_integer!(json!(5e-324)),
+///   json!(0)
+/// );
+/// assert_eq!(
+///   to_integer!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   json!(1.7976931348623157e+308)
+/// );
+/// assert_eq!(
+///   to_integer!(json!("3.2")),
+///   json!(3)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_integer!(), json!(0));
+/// assert_eq!(to_integer!(json!(null)), json!(0));
+/// assert_eq!(to_integer!(json!(true)), json!(1));
+/// assert_eq!(to_integer!(json!(-3.7)), json!(-3));
+/// assert_eq!(to_integer!(json!(18446744073709551615_u64)), json!(18446744073709551615_u64));
+/// assert_eq!(to_integer!(json!(" 0x1F ")), json!(31));
+/// assert_eq!(to_integer!(json!("a")), json!(0));
+/// assert_eq!(to_integer!(json!(["2.5"])), json!(2));
+/// assert_eq!(to_integer!(json!({})), json!(0));
+/// ```
+#[macro_export]
+macro_rules! to_integer {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::to_integer::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::to_integer::<$crate::lib::Value>($a)
+    };
+}
@@ -0,0 +1,138 @@
+use crate::lib::{json, Value};
+use crate::internal::{MAX_ARRAY_LENGTH};
+use crate::{to_integer, FromValue, IntoValue};
+
+fn base_to_length(v: Value) -> usize {
+    let n: f64 = to_integer(v);
+    if n <= 0.0 {
+        0
+    } else if n >= MAX_ARRAY_LENGTH as f64 {
+        MAX_ARRAY_LENGTH
+    } else {
+        n as usize
+    }
+}
+///
+#[deprecated(note = "use `to_length::<usize>()`")]
+pub fn to_length_x(v: Value) -> usize {
+    to_length(v)
+}
+/// See lodash [toLength](https://lodash.com/docs/#toLength)
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_length;
+/// assert_eq!(to_length::<usize>(json!(3.2)), 3);
+/// assert_eq!(to_length::<usize>("Infinity"), 4294967295);
+/// assert_eq!(to_length::<Value>(-1), json!(0));
+/// ```
+pub fn to_length<R: FromValue>(value: impl IntoValue) -> R {
+    R::from_value(json!(base_to_length(value.into_value())))
+}
+
+/// Based on [to_length_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_length_x!(json!(3.2)),
+///   3
+/// );
+/// assert_eq!(
+///   to_length_x!(json!(5e-324)),
+///   0
+/// );
+/// assert_eq!(
+///   to_length_x!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   4294967295
+/// );
+/// assert_eq!(
+///   to_length_x!(json!("3.2")),
+///   3
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_length_x!(), 0);
+/// assert_eq!(to_length_x!(json!(null)), 0);
+/// assert_eq!(to_length_x!(json!(-1)), 0);
+/// assert_eq!(to_length_x!(json!(4294967296_u64)), 4294967295);
+/// assert_eq!(to_length_x!(json!("a")), 0);
+/// assert_eq!(to_length_x!(json!({})), 0);
+/// ```
+#[deprecated(note = "use `to_length::<usize>()`")]
+#[macro_export]
+macro_rules! to_length_x {
+    () => {
+        0_usize
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_length_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_length_x($a);
+        result
+    }};
+}
+/// Based on [to_length()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_length!(json!(3.2)),
+///   json!(3)
+/// );
+/// assert_eq!(
+///   to_length!(json!(5e-324)),
+///   json!(0)
+/// );
+/// assert_eq!(
+///   to_length!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   json!(4294967295_u32)
+/// );
+/// assert_eq!(
+///   to_length!(json!("3.2")),
+///   json!(3)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_length!(), json!(0));
+/// assert_eq!(to_length!(json!(null)), json!(0));
+/// assert_eq!(to_length!(json!(true)), json!(1));
+/// assert_eq!(to_length!(json!(-3)), json!(0));
+/// assert_eq!(to_length!(json!(" 0b11 ")), json!(3));
+/// assert_eq!(to_length!(json!([2])), json!(2));
+/// assert_eq!(to_length!(json!({})), json!(0));
+/// ```
+#[macro_export]
+macro_rules! to_length {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::to_length::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::to_length::<$crate::lib::Value>($a)
+    };
+}
@@ -0,0 +1,397 @@
+use crate::lib::{Value};
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+// https://262.ecma-international.org/11.0/#sec-white-space
+// https://262.ecma-international.org/11.0/#sec-line-terminators
+fn is_js_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0009}'
+            | '\u{000A}'
+            | '\u{000B}'
+            | '\u{000C}'
+            | '\u{000D}'
+            | '\u{0020}'
+            | '\u{00A0}'
+            | '\u{1680}'
+            | '\u{2000}'
+            ..='\u{200A}'
+                | '\u{2028}'
+                | '\u{2029}'
+                | '\u{202F}'
+                | '\u{205F}'
+                | '\u{3000}'
+                | '\u{FEFF}'
+    )
+}
+
+// StrUnsignedDecimalLiteral, without the `Infinity` branch
+fn is_unsigned_decimal_literal(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut digits = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        i += 1;
+        digits += 1;
+    }
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+            digits += 1;
+        }
+    }
+    if digits == 0 {
+        return false;
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+            i += 1;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == start {
+            return false;
+        }
+    }
+    i == bytes.len()
+}
+
+// NonDecimalIntegerLiteral (`0b`, `0o`, `0x`), rounded to nearest like JS does
+fn parse_non_decimal(digits: &str, bits_per_digit: u32) -> f64 {
+    let radix = 1 << bits_per_digit;
+    let digits = digits.trim_start_matches('0');
+    let mut top: u64 = 0;
+    let mut top_bits = 0;
+    let mut shift: i32 = 0;
+    let mut sticky = false;
+    for c in digits.chars() {
+        let d = match c.to_digit(radix) {
+            Some(d) => d as u64,
+            None => return f64::NAN,
+        };
+        if top_bits + bits_per_digit <= 64 {
+            top = (top << bits_per_digit) | d;
+            top_bits += bits_per_digit;
+        } else {
+            sticky |= d != 0;
+            shift += bits_per_digit as i32;
+        }
+    }
+    if sticky {
+        // More than 53 significant bits are kept in `top`, so marking the lowest bit is enough to break ties
+        top |= 1;
+    }
+    top as f64 * 2_f64.powi(shift)
+}
+
+fn str_to_f64(s: &str) -> f64 {
+    let s = s.trim_matches(is_js_whitespace);
+    if s.is_empty() {
+        return 0.0;
+    }
+    if s.len() > 2 && s.as_bytes()[0] == b'0' {
+        // Only sliced once byte 1 is known to be ASCII, so byte 2 is a char boundary
+        match s.as_bytes()[1] {
+            b'x' | b'X' => return parse_non_decimal(&s[2..], 4),
+            b'o' | b'O' => return parse_non_decimal(&s[2..], 3),
+            b'b' | b'B' => return parse_non_decimal(&s[2..], 1),
+            _ => (),
+        }
+    }
+    let (negative, unsigned) = match s.as_bytes()[0] {
+        b'+' => (false, &s[1..]),
+        b'-' => (true, &s[1..]),
+        _ => (false, s),
+    };
+    let n = if unsigned == "Infinity" {
+        f64::INFINITY
+    } else if is_unsigned_decimal_literal(unsigned) {
+        unsigned.parse::<f64>().unwrap_or(f64::NAN)
+    } else {
+        return f64::NAN;
+    };
+    if negative {
+        -n
+    } else {
+        n
+    }
+}
+/// js ToNumber of a string, see [to_number()](crate::to_number())
+impl From<&str> for JsNumber {
+    fn from(s: &str) -> JsNumber {
+        JsNumber::from_f64(str_to_f64(s))
+    }
+}
+///
+#[deprecated(note = "use `to_number::<f64>()`, it takes a `&str` too")]
+pub fn x_to_number_x(s: &str) -> f64 {
+    to_number(s)
+}
+///
+#[deprecated(note = "use `to_number()`, it takes a `&str` too")]
+pub fn x_to_number(s: &str) -> Value {
+    to_number(s)
+}
+///
+#[deprecated(note = "use `to_number::<f64>()`")]
+pub fn to_number_x(v: Value) -> f64 {
+    to_number(v)
+}
+/// See lodash [toNumber](https://lodash.com/docs/#toNumber)
+///
+/// An `f64` result keeps `NaN` and `Infinity`, see [NonFinitePolicy](crate::NonFinitePolicy) for how a [Value] writes them.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_number;
+/// assert_eq!(to_number::<f64>(" 0x1F "), 31.0);
+/// assert_eq!(to_number::<Value>(json!("3.2")), json!(3.2));
+/// assert!(to_number::<f64>("a").is_nan());
+/// assert_eq!(to_number::<Value>("a"), json!(null));
+/// assert_eq!(to_number::<f64>("-Infinity"), f64::NEG_INFINITY);
+/// assert_eq!(to_number::<i32>("3.7"), 3);
+/// assert_eq!(to_number::<i64>(json!(9007199254740993_i64)), 9007199254740993);
+/// ```
+pub fn to_number<R: FromValue>(value: impl IntoValue) -> R {
+    R::from_js_number(JsNumber::from(value.into_value()))
+}
+///
+pub fn try_to_number(
+    policy: NonFinitePolicy,
+    value: impl IntoValue,
+) -> Result<Value, NonFiniteError> {
+    JsNumber::from(value.into_value()).try_into_value(policy)
+}
+
+/// Based on [x_to_number_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// assert_eq!(
+///   x_to_number_x!("3.2"),
+///   3.2
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// assert_eq!(x_to_number_x!(), 0.0);
+/// assert_eq!(x_to_number_x!(""), 0.0);
+/// assert_eq!(x_to_number_x!(" \n\t "), 0.0);
+/// assert_eq!(x_to_number_x!(" 12 "), 12.0);
+/// assert_eq!(x_to_number_x!("\u{feff}12\u{3000}"), 12.0);
+/// assert_eq!(x_to_number_x!("+1.5"), 1.5);
+/// assert!(x_to_number_x!("0é").is_nan());
+/// assert!(x_to_number_x!("0ñ1").is_nan());
+/// assert_eq!(x_to_number_x!("-.5"), -0.5);
+/// assert_eq!(x_to_number_x!("5."), 5.0);
+/// assert_eq!(x_to_number_x!("1e3"), 1000.0);
+/// assert_eq!(x_to_number_x!("1E-3"), 0.001);
+/// assert_eq!(x_to_number_x!("1e400"), f64::INFINITY);
+/// assert_eq!(x_to_number_x!("0x1F"), 31.0);
+/// assert_eq!(x_to_number_x!("0XfF"), 255.0);
+/// assert_eq!(x_to_number_x!("0b101"), 5.0);
+/// assert_eq!(x_to_number_x!("0o17"), 15.0);
+/// assert_eq!(x_to_number_x!("0x20000000000001"), 9007199254740992.0);
+/// assert_eq!(x_to_number_x!("0x20000000000003"), 9007199254740996.0);
+/// assert_eq!(x_to_number_x!("0x200000000000010000000000000001"), 166153499473114520000000000000000000.0);
+/// assert_eq!(x_to_number_x!("Infinity"), f64::INFINITY);
+/// assert_eq!(x_to_number_x!("+Infinity"), f64::INFINITY);
+/// assert_eq!(x_to_number_x!("-Infinity"), f64::NEG_INFINITY);
+/// assert!(x_to_number_x!("-0").is_sign_negative());
+/// assert!(x_to_number_x!("-0x1F").is_nan());
+/// assert!(x_to_number_x!("0x").is_nan());
+/// assert!(x_to_number_x!("0b102").is_nan());
+/// assert!(x_to_number_x!("infinity").is_nan());
+/// assert!(x_to_number_x!("inf").is_nan());
+/// assert!(x_to_number_x!("NaN").is_nan());
+/// assert!(x_to_number_x!(".").is_nan());
+/// assert!(x_to_number_x!("e5").is_nan());
+/// assert!(x_to_number_x!("1e").is_nan());
+/// assert!(x_to_number_x!("1_000").is_nan());
+/// assert!(x_to_number_x!("12px").is_nan());
+/// assert!(x_to_number_x!("1 2").is_nan());
+/// ```
+#[deprecated(note = "use `to_number::<f64>()`, it takes a `&str` too")]
+#[macro_export]
+macro_rules! x_to_number_x {
+    () => {
+        0.0_f64
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_number_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_number_x($a);
+        result
+    }};
+}
+/// Based on [x_to_number()]
+#[deprecated(note = "use `to_number!()`, it takes a `&str` too")]
+#[macro_export]
+macro_rules! x_to_number {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_number($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_number($a);
+        result
+    }};
+}
+/// Based on [to_number_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_number_x!(json!(3.2)),
+///   3.2
+/// );
+/// assert_eq!(
+///   to_number_x!(json!(5e-324)),
+///   5e-324
+/// );
+/// assert_eq!(
+///   to_number_x!(json!("3.2")),
+///   3.2
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert!(to_number_x!().is_nan());
+/// assert_eq!(to_number_x!(json!(null)), 0.0);
+/// assert_eq!(to_number_x!(json!(true)), 1.0);
+/// assert_eq!(to_number_x!(json!("Infinity")), f64::INFINITY);
+/// assert_eq!(to_number_x!(json!([])), 0.0);
+/// assert_eq!(to_number_x!(json!([null])), 0.0);
+/// assert_eq!(to_number_x!(json!([" 7 "])), 7.0);
+/// assert_eq!(to_number_x!(json!([[7]])), 7.0);
+/// assert!(to_number_x!(json!([true])).is_nan());
+/// assert!(to_number_x!(json!([1, 2])).is_nan());
+/// assert!(to_number_x!(json!({})).is_nan());
+/// ```
+#[deprecated(note = "use `to_number::<f64>()`")]
+#[macro_export]
+macro_rules! to_number_x {
+    () => {
+        f64::NAN
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_number_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_number_x($a);
+        result
+    }};
+}
+/// Based on [to_number()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   to_number!(json!(3.2)),
+///   json!(3.2)
+/// );
+/// assert_eq!(
+///   to_number!(json!(5e-324)),
+///   json!(5e-324)
+/// );
+/// //assert_eq!(
+/// //  to_number!(json!(f64::INFINITY)), // serde_json will convert this to Value::Null
+/// //  json!(f64::INFINITY)
+/// //);
+/// assert_eq!(
+///   to_number!(json!("3.2")),
+///   json!(3.2)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(to_number!(), json!(null)); // NaN
+/// assert_eq!(to_number!(json!(null)), json!(0));
+/// assert_eq!(to_number!(json!(false)), json!(0));
+/// assert_eq!(to_number!(json!(18446744073709551615_u64)), json!(18446744073709551615_u64));
+/// assert_eq!(to_number!(json!("")), json!(0));
+/// assert_eq!(to_number!(json!(" 12 ")), json!(12));
+/// assert_eq!(to_number!(json!("-12")), json!(-12));
+/// assert_eq!(to_number!(json!("0x1F")), json!(31));
+/// assert_eq!(to_number!(json!("0b101")), json!(5));
+/// assert_eq!(to_number!(json!("1e3")), json!(1000));
+/// assert_eq!(to_number!(json!("Infinity")), json!(null)); // Infinity
+/// assert_eq!(to_number!(json!("a")), json!(null)); // NaN
+/// assert_eq!(to_number!(json!([])), json!(0));
+/// assert_eq!(to_number!(json!(["1.5"])), json!(1.5));
+/// assert_eq!(to_number!(json!([1, 2])), json!(null)); // NaN
+/// assert_eq!(to_number!(json!({})), json!(null)); // NaN
+/// assert_eq!(to_number!(" 12 "), json!(12));
+/// ```
+#[macro_export]
+macro_rules! to_number {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::to_number::<$crate::lib::Value>($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::to_number::<$crate::lib::Value>($a)
+    };
+}
+/// Based on [try_to_number()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_to_number!(NonFinitePolicy::Error, json!("3.2")), Ok(json!(3.2)));
+/// assert!(try_to_number!(NonFinitePolicy::Error, json!("a")).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_to_number {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_to_number($p, $a)
+    };
+    ($p:expr, $a:expr, $($rest:tt)*) => {
+        $crate::try_to_number($p, $a)
+    };
+}
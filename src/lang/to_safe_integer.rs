@@ -1,44 +1,49 @@
 use crate::lib::{json, Value};
+use crate::internal::{MAX_SAFE_INTEGER, MIN_SAFE_INTEGER};
+use crate::internal::decimal::Decimal;
+use crate::{to_integer, FromValue, IntoValue};
 
-///
-pub fn to_safe_integer_x(v: Value) -> isize {
+fn base_to_safe_integer(v: Value) -> i64 {
     match v {
-        Value::Null => 0,
-        Value::Bool(b) => {
-            if b {
-                1
-            } else {
-                0
-            }
-        }
-        Value::Number(n) => {
-            if n.is_u64() {
-                n.as_u64().unwrap() as isize
-            } else if n.is_i64() {
-                n.as_i64().unwrap() as isize
-            } else {
-                n.as_f64().unwrap() as isize
-            }
-        }
-        Value::String(s) => {
-            if let Ok(v) = s.parse::<isize>() {
-                v
-            } else if let Ok(v) = s.parse::<f64>() {
-                v as isize
+        // exact, instead of rounding it to f64 first
+        Value::Number(ref n) if cfg!(feature = "arbitrary_precision") => Decimal::from(n)
+            .to_i128()
+            .clamp(MIN_SAFE_INTEGER as i128, MAX_SAFE_INTEGER as i128)
+            as i64,
+        Value::Number(ref n) if !n.is_f64() => match n.as_i64() {
+            Some(i) => i.clamp(MIN_SAFE_INTEGER, MAX_SAFE_INTEGER),
+            None => MAX_SAFE_INTEGER, // bigger than i64::MAX
+        },
+        _ => {
+            let n: f64 = to_integer(v);
+            if n >= MAX_SAFE_INTEGER as f64 {
+                MAX_SAFE_INTEGER
+            } else if n <= MIN_SAFE_INTEGER as f64 {
+                MIN_SAFE_INTEGER
             } else {
-                0
+                n as i64
             }
         }
-        Value::Array(vec) => match vec.len() {
-            1 => to_safe_integer_x(vec[0].clone()),
-            _ => 0,
-        },
-        Value::Object(_) => 0,
     }
 }
+///
+#[deprecated(note = "use `to_safe_integer::<i64>()`")]
+pub fn to_safe_integer_x(v: Value) -> i64 {
+    to_safe_integer(v)
+}
 /// See lodash [toSafeInteger](https://lodash.com/docs/#toSafeInteger)
-pub fn to_safe_integer(v: Value) -> Value {
-    json!(to_safe_integer_x(v))
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_safe_integer;
+/// assert_eq!(to_safe_integer::<i64>(json!(3.2)), 3);
+/// assert_eq!(to_safe_integer::<Value>("3.2"), json!(3));
+/// assert_eq!(to_safe_integer::<u8>(-1), 0);
+/// ```
+pub fn to_safe_integer<R: FromValue>(value: impl IntoValue) -> R {
+    R::from_value(json!(base_to_safe_integer(value.into_value())))
 }
 
 /// Based on [to_safe_integer_x()]
@@ -46,21 +51,21 @@ pub fn to_safe_integer(v: Value) -> Value {
 /// Examples:
 ///
 /// ```rust
-/// # #![allow(overflowing_literals)]
 /// #[macro_use] extern crate serde_json_lodash;
 /// use serde_json::json;
 /// assert_eq!(
 ///   to_safe_integer_x!(json!(3.2)),
 ///   3
 /// );
-/// //assert_eq!(
-/// //  to_safe_integer_x!(json!(isize::MIN)), // serde_json will convert this to Number(-9223372036854775808)
-/// //  0
-/// //);
-/// //assert_eq!(
-/// //  to_safe_integer_x!(json!(f64::INFINITY)), // serde_json will convert this to Value::Null
-/// //  9007199254740991 // serde_json will convert this to Number(-1)
-/// //);
+/// assert_eq!(
+///   to_safe_integer_x!(json!(5e-324)),
+///   0
+/// );
+/// # #[cfg(not(feature = "native_i64"))]
+/// assert_eq!(
+///   to_safe_integer_x!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   9007199254740991
+/// );
 /// assert_eq!(
 ///   to_safe_integer_x!(json!("3.2")),
 ///   3
@@ -73,39 +78,73 @@ pub fn to_safe_integer(v: Value) -> Value {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// assert_eq!(to_safe_integer_x!(), 0);
+/// assert_eq!(to_safe_integer_x!(json!(-3.7)), -3);
+/// # #[cfg(not(feature = "native_i64"))] {
+/// assert_eq!(to_safe_integer_x!(json!("-Infinity")), -9007199254740991);
+/// assert_eq!(to_safe_integer_x!(json!(9007199254740993_u64)), 9007199254740991);
+/// assert_eq!(to_safe_integer_x!(json!(u64::MAX)), 9007199254740991);
+/// assert_eq!(to_safe_integer_x!(json!(i64::MIN)), -9007199254740991);
+/// assert_eq!(to_safe_integer_x!(json!(1e300)), 9007199254740991);
+/// # }
+/// # #[cfg(feature = "native_i64")] {
+/// assert_eq!(to_safe_integer_x!(json!("-Infinity")), i64::MIN);
+/// assert_eq!(to_safe_integer_x!(json!(9007199254740993_u64)), 9007199254740993);
+/// assert_eq!(to_safe_integer_x!(json!(u64::MAX)), i64::MAX);
+/// assert_eq!(to_safe_integer_x!(json!(i64::MIN)), i64::MIN);
+/// assert_eq!(to_safe_integer_x!(json!(1e300)), i64::MAX);
+/// # }
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(to_safe_integer_x!(n("9007199254740990.999")), 9007199254740990);
+/// assert_eq!(to_safe_integer_x!(n("-9007199254740990.999")), -9007199254740990);
+/// assert!(to_safe_integer_x!(n("1e99999999999999999999")) >= 9007199254740991);
+/// assert_eq!(to_safe_integer_x!(n("1e-99999999999999999999")), 0);
+/// # }
 /// ```
+#[deprecated(note = "use `to_safe_integer::<i64>()`")]
 #[macro_export]
 macro_rules! to_safe_integer_x {
     () => {
-        0
-    };
-    ($a:expr $(,)*) => {
-        $crate::to_safe_integer_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::to_safe_integer_x($a)
+        0_i64
     };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_safe_integer_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_safe_integer_x($a);
+        result
+    }};
 }
 /// Based on [to_safe_integer()]
 ///
 /// Examples:
 ///
 /// ```rust
-/// # #![allow(overflowing_literals)]
 /// #[macro_use] extern crate serde_json_lodash;
 /// use serde_json::json;
 /// assert_eq!(
 ///   to_safe_integer!(json!(3.2)),
 ///   json!(3)
 /// );
-/// //assert_eq!(
-/// //  to_safe_integer!(json!(isize::MIN)), // serde_json will convert this to Number(-9223372036854775808)
-/// //  json!(0)
-/// //);
-/// //assert_eq!(
-/// //  to_safe_integer!(json!(f64::INFINITY)), // serde_json will convert this to Value::Null
-/// //  json!(9007199254740991) // serde_json will convert this to Number(-1)
-/// //);
+/// assert_eq!(
+///   to_safe_integer!(json!(5e-324)),
+///   json!(0)
+/// );
+/// # #[cfg(not(feature = "native_i64"))]
+/// assert_eq!(
+///   to_safe_integer!(json!("Infinity")), // json!(f64::INFINITY) is Value::Null
+///   json!(9007199254740991_i64)
+/// );
 /// assert_eq!(
 ///   to_safe_integer!(json!("3.2")),
 ///   json!(3)
@@ -118,6 +157,15 @@ macro_rules! to_safe_integer_x {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// assert_eq!(to_safe_integer!(), json!(0));
+/// assert_eq!(to_safe_integer!(json!(null)), json!(0));
+/// assert_eq!(to_safe_integer!(json!(true)), json!(1));
+/// assert_eq!(to_safe_integer!(json!(" 12 ")), json!(12));
+/// assert_eq!(to_safe_integer!(json!("0x1F")), json!(31));
+/// assert_eq!(to_safe_integer!(json!("1e3")), json!(1000));
+/// assert_eq!(to_safe_integer!(json!("a")), json!(0));
+/// assert_eq!(to_safe_integer!(json!(["-2.5"])), json!(-2));
+/// assert_eq!(to_safe_integer!(json!([true])), json!(0));
+/// assert_eq!(to_safe_integer!(json!({})), json!(0));
 /// ```
 #[macro_export]
 macro_rules! to_safe_integer {
@@ -125,9 +173,9 @@ macro_rules! to_safe_integer {
         json!(0)
     };
     ($a:expr $(,)*) => {
-        $crate::to_safe_integer($a)
+        $crate::to_safe_integer::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::to_safe_integer($a)
+        $crate::to_safe_integer::<$crate::lib::Value>($a)
     };
 }
@@ -1,10 +1,5 @@
-use crate::lib::{json, Value};
-use crate::internal::{type_name};
-
-///
-pub fn x_to_string(v: &str) -> Value {
-    json!(v)
-}
+use crate::lib::Value;
+use crate::{FromValue, IntoValue};
 
 #[doc(hidden)]
 pub fn json_array_to_string_x(vec: Vec<Value>) -> String {
@@ -15,14 +10,14 @@ pub fn json_array_to_string_x(vec: Vec<Value>) -> String {
             if v.is_null() {
                 s.push_str("null");
             } else {
-                s.push_str(&*to_string_x(v));
+                s.push_str(&String::from_value(v));
             }
             for v in iter {
                 s.push(',');
                 if v.is_null() {
                     s.push_str("null");
                 } else {
-                    s.push_str(&*to_string_x(v));
+                    s.push_str(&String::from_value(v));
                 }
             }
             s
@@ -31,39 +26,49 @@ pub fn json_array_to_string_x(vec: Vec<Value>) -> String {
     }
 }
 ///
+#[deprecated(note = "use `to_string()`, it takes a `&str` too")]
+pub fn x_to_string(v: &str) -> Value {
+    to_string(v)
+}
+///
+#[deprecated(note = "use `to_string::<String>()`")]
 pub fn to_string_x(v: Value) -> String {
-    match v {
-        Value::Null => "".into(),
-        Value::Bool(b) => {
-            if b {
-                "true".into()
-            } else {
-                "false".into()
-            }
-        }
-        Value::Number(n) => n.to_string(),
-        Value::String(s) => s,
-        Value::Array(vec) => json_array_to_string_x(vec),
-        Value::Object(o) => type_name(&o).into(), // I don't think put [object Object] here is a good idea, so...
-    }
+    to_string(v)
 }
 /// See lodash [toString](https://lodash.com/docs/#toString)
-pub fn to_string(v: Value) -> Value {
-    Value::String(to_string_x(v))
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `String` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_string;
+/// assert_eq!(to_string::<String>(json!([1, 2, 3])), "1,2,3".to_owned());
+/// assert_eq!(to_string::<Value>("a"), json!("a"));
+/// assert_eq!(to_string::<String>(-1), "-1".to_owned());
+/// ```
+pub fn to_string<R: FromValue>(value: impl IntoValue) -> R {
+    R::from_value(Value::String(String::from_value(value.into_value())))
 }
 
 /// Based on [x_to_string()]
+#[deprecated(note = "use `to_string!()`, it takes a `&str` too")]
 #[macro_export]
 macro_rules! x_to_string {
     () => {
         json!("")
     };
-    ($a:expr $(,)*) => {
-        $crate::x_to_string($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_to_string($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_string($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_string($a);
+        result
+    }};
 }
 /// Based on [to_string_x()]
 ///
@@ -93,17 +98,22 @@ macro_rules! x_to_string {
 /// # use serde_json::json;
 /// assert_eq!(to_string_x!(), "".to_owned());
 /// ```
+#[deprecated(note = "use `to_string::<String>()`")]
 #[macro_export]
 macro_rules! to_string_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::to_string_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::to_string_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_string_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_string_x($a);
+        result
+    }};
 }
 /// Based on [to_string()]
 ///
@@ -140,6 +150,8 @@ macro_rules! to_string_x {
 /// assert_eq!(to_string!(json!([])), json!(""));
 /// assert_eq!(to_string!(json!([null,"A",{}])), json!("null,A,serde_json::map::Map<alloc::string::String, serde_json::value::Value>"));
 /// assert_eq!(to_string!(json!({})), json!("serde_json::map::Map<alloc::string::String, serde_json::value::Value>"));
+/// assert_eq!(to_string!("a"), json!("a"));
+/// assert_eq!(to_string!(1.5), json!("1.5"));
 /// ```
 #[macro_export]
 macro_rules! to_string {
@@ -147,9 +159,9 @@ macro_rules! to_string {
         json!("")
     };
     ($a:expr $(,)*) => {
-        $crate::to_string($a)
+        $crate::to_string::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::to_string($a)
+        $crate::to_string::<$crate::lib::Value>($a)
     };
 }
@@ -0,0 +1,59 @@
+use crate::lib::Value;
+use crate::internal::value_to_key;
+
+/// How the set operations like [intersection()](crate::intersection()), [difference()](crate::difference())
+/// and [pull_all()](crate::pull_all()) tell whether two values are equal
+///
+/// Those functions use [EqualityMode::SameValueZero], what lodash does, and their `_in` flavours,
+/// like [intersection_in()](crate::intersection_in()), take the mode of each call.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::EqualityMode;
+/// assert_eq!(intersection!(json!([1, {"a": 1}]), json!([1.0, {"a": 1}])), json!([1]));
+/// assert_eq!(
+///   intersection_in!(EqualityMode::Structural, json!([1, {"a": 1}]), json!([1.0, {"a": 1}])),
+///   json!([1, {"a": 1}])
+/// );
+/// assert_eq!(difference_in!(EqualityMode::Structural, json!([[1], [2]]), json!([[2]])), json!([[1]]));
+/// assert_eq!(EqualityMode::default(), EqualityMode::SameValueZero);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EqualityMode {
+    /// js `SameValueZero`, where an object or array is only equal to itself,
+    /// which, for values which aren't references, means it is never equal to another one
+    #[default]
+    SameValueZero,
+    /// Objects and arrays are equal when their items are, like [is_equal](https://lodash.com/docs/#isEqual)
+    Structural,
+}
+
+/// A hashable key of a [Value], equal keys are equal values
+///
+/// Numbers are compared like js, so `1` and `1.0` have the same key, and object keys are sorted.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_json_lodash::{EqualityMode, ValueKey};
+/// let key = |v| ValueKey::new(&v, EqualityMode::Structural);
+/// assert_eq!(key(json!(1)), key(json!(1.0)));
+/// assert_eq!(key(json!({"a": 1, "b": [0]})), key(json!({"b": [-0.0], "a": 1})));
+/// assert_ne!(key(json!("1")), key(json!(1)));
+/// assert_eq!(ValueKey::new(&json!([]), EqualityMode::SameValueZero), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValueKey(String);
+
+impl ValueKey {
+    /// `None` when the value is equal to no other value, which is an object or array under [EqualityMode::SameValueZero]
+    pub fn new(value: &Value, mode: EqualityMode) -> Option<ValueKey> {
+        match (value, mode) {
+            (Value::Array(_), EqualityMode::SameValueZero)
+            | (Value::Object(_), EqualityMode::SameValueZero) => None,
+            _ => Some(ValueKey(value_to_key(value))),
+        }
+    }
+}
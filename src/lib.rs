@@ -21,16 +21,18 @@
 //!     json!({'a': 1, 'b': 2})
 //!   );
 //!
-//!   // `x_`, `_x` helpers for simple types
+//!   // generic over simple types, see `IntoValue` and `FromValue`
+//!   use serde_json_lodash::capitalize;
 //!   assert_eq!(capitalize!(json!("FRED")), json!("Fred"));
-//!   assert_eq!(x_capitalize!("FRED"), json!("Fred"));
-//!   assert_eq!(capitalize_x!(json!("FRED")), "Fred".to_owned());
-//!   assert_eq!(x_capitalize_x!("FRED"), "Fred".to_owned());
+//!   assert_eq!(capitalize!("FRED"), json!("Fred"));
+//!   assert_eq!(capitalize::<String>(json!("FRED")), "Fred".to_owned());
+//!   assert_eq!(capitalize::<String>("FRED"), "Fred".to_owned());
 //! }
 //! ```
 
 #![deny(missing_docs)]
 #![deny(warnings)]
+#![allow(clippy::empty_docs)]
 
 #[cfg(feature = "camel")]
 extern crate paste;
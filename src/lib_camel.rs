@@ -50,23 +50,66 @@ macro_rules! build_multi {
 }
 
 build_multi![
+    difference_by
+    difference_with
+    drop_right_while
+    drop_while
     find_index
     find_last_index
     flatten_deep
     flatten_depth
     from_pairs
     index_of
+    intersection_by
+    intersection_with
     last_index_of
     pull_all
     pull_all_by
     pull_all_with
     pull_at
+    sorted_index
+    sorted_index_by
+    sorted_index_of
+    sorted_last_index
+    sorted_last_index_by
+    sorted_last_index_of
+    sorted_uniq
+    sorted_uniq_by
+    take_right
+    take_right_while
+    take_while
+    union_by
+    union_with
+    uniq_by
+    uniq_with
+    unzip_with
+    xor_by
+    xor_with
+    zip_object
+    zip_object_deep
+    zip_with
 
+    is_safe_integer
+    to_finite
+    to_integer
+    to_length
+    to_number
     to_safe_integer
     to_string
 
+    max_by
+    mean_by
+    min_by
+    sum_by
+
+    in_range
+
+    curry_right
+    partial_right
+
     to_lower
 
+    flow_right
     range_right
     stub_array
     stub_false
@@ -10,4 +10,5 @@ pub use crate::seq::*;
 pub use crate::string::*;
 pub use crate::util::*;
 pub use crate::properties::*;
+#[allow(unused_imports)]
 pub use crate::methods::*;
@@ -1,171 +1,54 @@
-use crate::lib::{json, Value, Number};
-use crate::internal::{value_nan, value_to_option_number, vec_value_to_option_number};
-use crate::{to_string_x, json_array_to_string_x};
+use crate::lib::{Value, Number};
+use crate::internal::{number_to_f64, number_to_option_i128, i128_to_option_number, js_add, JsSum};
+use crate::internal::decimal::Decimal;
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
 
 ///
-pub fn x_add_x(n: Number, n2: Number) -> Number {
-    if n.is_u64() {
-        let inner_n = n.as_u64().unwrap();
-        if n2.is_u64() {
-            (inner_n + n2.as_u64().unwrap()).into()
-        } else if n2.is_i64() {
-            (inner_n as i64 + n2.as_i64().unwrap()).into()
-        } else {
-            Number::from_f64(inner_n as f64 + n2.as_f64().unwrap()).unwrap()
-        }
-    } else if n.is_i64() {
-        let inner_n = n.as_i64().unwrap();
-        if n2.is_u64() {
-            (inner_n as u64 + n2.as_u64().unwrap()).into()
-        } else if n2.is_i64() {
-            (inner_n + n2.as_i64().unwrap()).into()
-        } else {
-            Number::from_f64(inner_n as f64 + n2.as_f64().unwrap()).unwrap()
-        }
-    } else {
-        let inner_n = n.as_f64().unwrap();
-        if n2.is_u64() {
-            (inner_n as u64 + n2.as_u64().unwrap()).into()
-        } else if n2.is_i64() {
-            (inner_n as i64 + n2.as_i64().unwrap()).into()
-        } else {
-            Number::from_f64(inner_n + n2.as_f64().unwrap()).unwrap()
-        }
+pub fn x_checked_add_x(n: Number, n2: Number) -> Option<Number> {
+    if cfg!(feature = "arbitrary_precision") {
+        return Some((Decimal::from(&n) + Decimal::from(&n2)).into());
     }
-}
-
-fn x_add_bool_x(n: Number, b: bool) -> Number {
-    if n.is_u64() {
-        let v: u64 = if b { 1 } else { 0 };
-        (n.as_u64().unwrap() + v).into()
-    } else if n.is_i64() {
-        let v: i64 = if b { 1 } else { 0 };
-        (n.as_i64().unwrap() + v).into()
-    } else {
-        let v: f64 = if b { 1.0 } else { 0.0 };
-        Number::from_f64(n.as_f64().unwrap() + v).unwrap()
+    match (number_to_option_i128(&n), number_to_option_i128(&n2)) {
+        (Some(i), Some(i2)) => i128_to_option_number(i + i2),
+        _ => Number::from_f64(number_to_f64(&n) + number_to_f64(&n2)),
     }
 }
-fn array_to_value_number(vec: Vec<Value>) -> Value {
-    match vec_value_to_option_number(vec) {
-        Some(n) => Value::Number(n),
-        None => value_nan(),
-    }
-}
-fn value_to_value_number(value: Value) -> Value {
-    match value_to_option_number(value) {
-        Some(n) => Value::Number(n),
-        None => value_nan(),
-    }
+///
+#[deprecated(note = "use `add::<JsNumber>()`")]
+pub fn x_add_x(n: Number, n2: Number) -> JsNumber {
+    add(n, n2)
 }
 
 /// See lodash [add](https://lodash.com/docs/#add)
-pub fn add(augend: Value, addend: Value) -> Value {
-    match augend {
-        Value::Null => match addend {
-            Value::Null => json!(0),
-            Value::Bool(b) => {
-                if b {
-                    json!(1)
-                } else {
-                    json!(0)
-                }
-            }
-            Value::Number(_) => addend,
-            Value::String(s) => json!(format!("null{}", s)),
-            Value::Array(_) => value_to_value_number(addend),
-            Value::Object(_) => value_nan(),
-        },
-        Value::Bool(b) => match addend {
-            Value::Null => json!(0),
-            Value::Bool(b2) => {
-                if b && b2 {
-                    json!(2)
-                } else if b || b2 {
-                    json!(1)
-                } else {
-                    json!(0)
-                }
-            }
-            Value::Number(n) => Value::Number(x_add_bool_x(n, b)),
-            Value::String(s) => {
-                json!(format!("{}{}", b, s))
-            }
-            Value::Array(vec) => {
-                if b {
-                    if let Some(n) = vec_value_to_option_number(vec) {
-                        Value::Number(x_add_bool_x(n, b))
-                    } else {
-                        value_nan()
-                    }
-                } else {
-                    array_to_value_number(vec)
-                }
-            }
-            Value::Object(_) => value_nan(),
-        },
-        Value::Number(n) => match addend {
-            Value::Null => Value::Number(n),
-            Value::Bool(b) => Value::Number(x_add_bool_x(n, b)),
-            Value::Number(n2) => json!(x_add_x(n, n2)),
-            Value::String(s) => json!(format!("{}{}", n, s)),
-            Value::Array(vec) => match vec.len() {
-                0 => Value::Number(n),
-                1 => {
-                    if let Some(n2) = vec_value_to_option_number(vec) {
-                        Value::Number(x_add_x(n, n2))
-                    } else {
-                        value_nan()
-                    }
-                }
-                _ => value_nan(),
-            },
-            Value::Object(_) => value_nan(),
-        },
-        Value::String(mut s) => {
-            s.push_str(&to_string_x(addend));
-            Value::String(s)
-        }
-        Value::Array(vec) => match addend {
-            Value::Null => array_to_value_number(vec),
-            Value::Bool(b) => {
-                if b {
-                    if let Some(n) = vec_value_to_option_number(vec) {
-                        Value::Number(x_add_bool_x(n, b))
-                    } else {
-                        value_nan()
-                    }
-                } else {
-                    array_to_value_number(vec)
-                }
-            }
-            Value::Number(n) => match vec.len() {
-                0 => Value::Number(n),
-                1 => {
-                    if let Some(n2) = vec_value_to_option_number(vec) {
-                        Value::Number(x_add_x(n2, n))
-                    } else {
-                        value_nan()
-                    }
-                }
-                _ => value_nan(),
-            },
-            Value::String(s) => Value::String(format!("{}{}", json_array_to_string_x(vec), s)),
-            Value::Array(vec2) => {
-                if let Some(n) = vec_value_to_option_number(vec) {
-                    if let Some(n2) = vec_value_to_option_number(vec2) {
-                        return Value::Number(x_add_x(n, n2));
-                    }
-                }
-                value_nan()
-            }
-            Value::Object(_) => value_nan(),
-        },
-        Value::Object(_) => value_nan(),
-    }
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{add, JsNumber};
+/// assert_eq!(add::<f64>(6, 4), 10.0);
+/// assert_eq!(add::<Value>(json!(6), json!(4)), json!(10));
+/// assert_eq!(add::<JsNumber>(9007199254740993_i64, 1), JsNumber::from(json!(9007199254740994_i64)));
+/// ```
+pub fn add<R: FromValue>(augend: impl IntoValue, addend: impl IntoValue) -> R {
+    js_add(JsSum::Value(augend.into_value()), addend.into_value()).convert()
+}
+///
+pub fn try_add(
+    policy: NonFinitePolicy,
+    augend: Value,
+    addend: Value,
+) -> Result<Value, NonFiniteError> {
+    js_add(JsSum::Value(augend), addend).try_into_value(policy)
 }
 
-/// Based on [x_add_x()]
+/// Based on [x_checked_add_x()]
+///
+/// Same as [add()], but returns `None` instead of promoting to f64 if the sum doesn't fit in `i64`/`u64`,
+/// or if it isn't finite. With the `arbitrary_precision` feature, it is exact on decimals, but when one operand is
+/// more than 1000 zeros below the last digit of the other, it's negligible and the result is the other one.
 ///
 /// Examples:
 ///
@@ -173,8 +56,8 @@ pub fn add(augend: Value, addend: Value) -> Value {
 /// #[macro_use] extern crate serde_json_lodash;
 /// use serde_json::Number;
 /// assert_eq!(
-///   x_add_x!(Number::from(6), Number::from(4)),
-///   Number::from(10)
+///   x_checked_add_x!(Number::from(6), Number::from(4)),
+///   Some(Number::from(10))
 /// );
 /// ```
 ///
@@ -182,23 +65,82 @@ pub fn add(augend: Value, addend: Value) -> Value {
 ///
 /// ```rust
 /// # #[macro_use] extern crate serde_json_lodash;
-/// # use serde_json::json;
+/// # use serde_json::Number;
+/// assert_eq!(x_checked_add_x!(), Some(Number::from(0)));
+/// assert_eq!(x_checked_add_x!(Number::from(u64::MAX), Number::from(-1)), Some(Number::from(u64::MAX - 1)));
+/// assert_eq!(x_checked_add_x!(Number::from(i64::MIN), Number::from(u64::MAX)), Some(Number::from(i64::MAX as u64)));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_add_x!(Number::from(u64::MAX), Number::from(1)), None);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_add_x!(Number::from(i64::MIN), Number::from(-1)), None);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_add_x!(Number::from_f64(f64::MAX).unwrap(), Number::from_f64(f64::MAX).unwrap()), None);
 /// ```
 #[macro_export]
-macro_rules! x_add_x {
+macro_rules! x_checked_add_x {
     () => {
-        Number::from(0)
+        Some($crate::lib::Number::from(0))
     };
     ($a:expr $(,)*) => {
-        $a
+        Some($a)
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::x_add_x($a, $b)
+        $crate::x_checked_add_x($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::x_add_x($a, $b)
+        $crate::x_checked_add_x($a, $b)
     };
 }
+/// Based on [x_add_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_add_x!(Number::from(6), Number::from(4)),
+///   Number::from(10)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Number};
+/// assert_eq!(x_add_x!(Number::from(-1), Number::from(5)), Number::from(4));
+/// assert_eq!(x_add_x!(Number::from(5), Number::from(-6)), Number::from(-1));
+/// assert_eq!(x_add_x!(Number::from_f64(1.5).unwrap(), Number::from(1)), Number::from_f64(2.5).unwrap());
+/// assert_eq!(x_add_x!(Number::from(1), Number::from_f64(-1.5).unwrap()), Number::from_f64(-0.5).unwrap());
+/// // promote to f64 like js does if it overflows
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_add_x!(Number::from(u64::MAX), Number::from(1)), Number::from_f64(18446744073709551616.0).unwrap());
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_add_x!(Number::from(i64::MIN), Number::from(i64::MIN)), Number::from_f64(-18446744073709551616.0).unwrap());
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_add_x!(Number::from(u64::MAX), Number::from(1)).into_value(), json!(18446744073709551616.0));
+/// ```
+#[deprecated(note = "use `add::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_add_x {
+    () => {
+        $crate::JsNumber::Finite($crate::lib::Number::from(0))
+    };
+    ($a:expr $(,)*) => {
+        $crate::JsNumber::Finite($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_add_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_add_x($a, $b);
+        result
+    }};
+}
 /// Based on [add()]
 ///
 /// Examples:
@@ -246,6 +188,40 @@ macro_rules! x_add_x {
 /// assert_eq!(add!(json!([1]),json!([2])), json!(3));
 /// assert_eq!(add!(json!({})), json!({}));
 /// assert_eq!(add!(json!({}),json!(1)), json!(null)); // NaN
+/// assert_eq!(add!(json!(1.5),json!(true)), json!(2.5));
+/// assert_eq!(add!(json!(-1),json!(-2)), json!(-3));
+/// assert_eq!(add!(json!(1),json!([" 2 "])), json!(3));
+/// assert_eq!(add!(json!(1),json!(["0x1F"])), json!(32));
+/// assert_eq!(add!(json!(1),json!(["1e3"])), json!(1001));
+/// assert_eq!(add!(json!(1),json!([true])), json!(null)); // NaN
+/// assert_eq!(add!(json!([" 2 "]),json!(null)), json!(2));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(add!(json!(u64::MAX),json!(1)), json!(18446744073709551616.0));
+/// assert_eq!(add!(json!(i64::MIN),json!(u64::MAX)), json!(i64::MAX));
+/// assert_eq!(add!(json!(-1),json!(u64::MAX)), json!(u64::MAX - 1));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(add!(json!(1.7976931348623157e308),json!(1.7976931348623157e308)), json!(null)); // Infinity
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(add!(n("12345678901234567890.123456789"), n("0.000000001")), n("12345678901234567890.12345679"));
+/// assert_eq!(add!(json!(0.1), json!(0.2)), n("0.3"));
+/// assert_eq!(add!(json!(u64::MAX), json!(1)), n("18446744073709551616"));
+/// assert_eq!(add!(n("1e500"), json!(1)), n(&format!("1{}1", "0".repeat(499))));
+/// assert_eq!(add!(n("1e1000"), json!(1)), n(&format!("1{}1", "0".repeat(999))));
+/// // 1 is negligible, the exact sum would take 300 MB
+/// assert_eq!(add!(n("1e300000000"), json!(1)), n("1e300000000"));
+/// assert_eq!(add!(json!(-1), n("1e2000")), n("1e2000"));
+/// assert_eq!(add!(n("1.5e2000"), n("2.5e2000")), n("4e2000"));
+/// assert_eq!(add!(n("1e99999999999999999999"), n("-1e99999999999999999999")), json!(0));
+/// # }
 /// ```
 #[macro_export]
 macro_rules! add {
@@ -253,12 +229,38 @@ macro_rules! add {
         json!(0)
     };
     ($a:expr $(,)*) => {
-        $a
+        $crate::IntoValue::into_value($a)
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::add($a, $b)
+        $crate::add::<$crate::lib::Value>($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::add($a, $b)
+        $crate::add::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_add()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_add!(NonFinitePolicy::Error, json!(6), json!(4)), Ok(json!(10)));
+/// assert!(try_add!(NonFinitePolicy::Error, json!(1), json!({})).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_add {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(0))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>($a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_add($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_add($p, $a, $b)
     };
 }
@@ -1,60 +1,48 @@
 use crate::lib::{Value, Number};
-use crate::internal::{number_nan, value_nan, value_to_option_number};
+use crate::internal::{number_round_by, Rounding};
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
 
 ///
-pub fn x_ceil_x(n: Number, precision: isize) -> Number {
-    if n.is_u64() {
-        if precision < 0 {
-            let f = n.as_f64().unwrap();
-            let x = 10_f64.powi(precision as i32);
-            let result = (f * x).ceil() / x;
-            return Number::from(result as u64);
-        } else {
-            return n;
-        }
-    }
-    if n.is_i64() {
-        if precision < 0 {
-            let f = n.as_f64().unwrap();
-            let x = 10_f64.powi(precision as i32);
-            let result = (f * x).ceil() / x;
-            return Number::from(result as i64);
-        } else {
-            return n;
-        }
-    }
-    // f64
-    if precision == 0 {
-        let result = n.as_f64().unwrap().ceil();
-        if result < 0.0 {
-            return Number::from(result as i64);
-        } else {
-            return Number::from(result as u64);
-        }
-    }
-    let f = n.as_f64().unwrap();
-    let x = 10_f64.powi(precision as i32);
-    let result = (f * x).ceil() / x;
-    if precision < 0 {
-        if result < 0.0 {
-            return Number::from(result as i64);
-        } else {
-            return Number::from(result as u64);
-        }
-    }
-    Number::from_f64(result).unwrap_or_else(number_nan)
+#[deprecated(note = "use `ceil::<JsNumber>()`")]
+pub fn x_ceil_x(n: Number, precision: isize) -> JsNumber {
+    ceil(n, precision)
 }
 ///
+#[deprecated(note = "use `ceil()`, it takes a `Number` too")]
 pub fn x_ceil(number: Number, precision: isize) -> Value {
-    Value::Number(x_ceil_x(number, precision))
+    ceil(number, precision)
 }
-/// See lodash [ceil](https://lodash.com/docs/#ceil)
-pub fn ceil(number: Value, precision: isize) -> Value {
-    match value_to_option_number(number) {
-        Some(n) => x_ceil(n, precision),
-        None => value_nan(),
+fn base_ceil(number: Value, precision: isize) -> JsNumber {
+    match JsNumber::from(number) {
+        JsNumber::Finite(n) => number_round_by(n, precision, Rounding::Ceil),
+        // NaN, Infinity and -0 have nothing to round
+        n => n,
     }
 }
+/// See lodash [ceil](https://lodash.com/docs/#ceil)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{ceil, JsNumber};
+/// assert_eq!(ceil::<f64>(4.006, 0), 5.0);
+/// assert_eq!(ceil::<Value>(json!(6.004), 2), json!(6.01));
+/// assert_eq!(ceil::<JsNumber>("a", 0), JsNumber::NaN);
+/// ```
+pub fn ceil<R: FromValue>(number: impl IntoValue, precision: isize) -> R {
+    R::from_js_number(base_ceil(number.into_value(), precision))
+}
+///
+pub fn try_ceil(
+    policy: NonFinitePolicy,
+    number: Value,
+    precision: isize,
+) -> Result<Value, NonFiniteError> {
+    base_ceil(number, precision).try_into_value(policy)
+}
 
 /// Based on [x_ceil_x()]
 ///
@@ -82,25 +70,45 @@ pub fn ceil(number: Value, precision: isize) -> Value {
 /// ```rust
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::{json, Number};
+/// # use serde_json_lodash::JsNumber;
 /// assert_eq!(
 ///   x_ceil_x!(),
-///   Number::from(0)
+///   JsNumber::NaN
 /// );
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_ceil_x!(Number::from(u64::MAX), -2), Number::from_f64(18446744073709552000.0).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from(u64::MAX - 100), -2), Number::from(u64::MAX - 15));
+/// assert_eq!(x_ceil_x!(Number::from(-6040), -2), Number::from(-6000));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_ceil_x!(Number::from(6040), -20), Number::from_f64(1e20).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(1.001).unwrap(), 2), Number::from_f64(1.01).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(0.1 + 0.2).unwrap(), 1), Number::from_f64(0.4).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(1e307).unwrap(), 2), Number::from_f64(1e307).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(1e30).unwrap()), Number::from_f64(1e30).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(-1e30).unwrap()), Number::from_f64(-1e30).unwrap());
+/// assert_eq!(x_ceil_x!(Number::from_f64(-4.006).unwrap()), Number::from(-4));
 /// ```
+#[deprecated(note = "use `ceil::<JsNumber>()`")]
 #[macro_export]
 macro_rules! x_ceil_x {
     () => {
-        $crate::internal::number_nan()
-    };
-    ($a:expr $(,)*) => {
-        $crate::x_ceil_x($a, 0)
-    };
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::x_ceil_x($a, $b)
-    };
-    ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::x_ceil_x($a, $b)
+        $crate::JsNumber::NaN
     };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_ceil_x($a, 0);
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_ceil_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_ceil_x($a, $b);
+        result
+    }};
 }
 /// Based on [ceil()]
 ///
@@ -142,6 +150,20 @@ macro_rules! x_ceil_x {
 /// assert_eq!(ceil!(json!(["a"])), json!(f64::NAN));
 /// assert_eq!(ceil!(json!({})), json!(f64::NAN));
 /// assert_eq!(ceil!(json!({"a":1})), json!(f64::NAN));
+/// assert_eq!(ceil!(json!("-Infinity"), 2), json!(f64::NEG_INFINITY));
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(ceil!(n("12345678901234567890.001"), 2), n("12345678901234567890.01"));
+/// assert_eq!(ceil!(n("-0.009"), 2), json!(0));
+/// # }
 /// ```
 #[macro_export]
 macro_rules! ceil {
@@ -149,12 +171,38 @@ macro_rules! ceil {
         $crate::internal::value_nan()
     };
     ($a:expr $(,)*) => {
-        $crate::ceil($a, 0)
+        $crate::ceil::<$crate::lib::Value>($a, 0)
     };
     ($a:expr, $b:expr $(,)*) => {
-        $crate::ceil($a, $b)
+        $crate::ceil::<$crate::lib::Value>($a, $b)
     };
     ($a:expr, $b:expr, $($rest:tt)*) => {
-        $crate::ceil($a, $b)
+        $crate::ceil::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_ceil()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_ceil!(NonFinitePolicy::Error, json!(4.5)), Ok(json!(5)));
+/// assert_eq!(try_ceil!(NonFinitePolicy::String, json!("Infinity")), Ok(json!("Infinity")));
+/// ```
+#[macro_export]
+macro_rules! try_ceil {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_ceil($p, $a, 0)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_ceil($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_ceil($p, $a, $b)
     };
 }
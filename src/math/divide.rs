@@ -0,0 +1,207 @@
+use crate::lib::{Value, Number};
+use crate::internal::{number_to_f64, number_to_option_i128, i128_to_option_number};
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+///
+pub fn x_checked_divide_x(n: Number, n2: Number) -> Option<Number> {
+    match (number_to_option_i128(&n), number_to_option_i128(&n2)) {
+        (Some(i), Some(i2)) if i2 != 0 && i % i2 == 0 => i128_to_option_number(i / i2),
+        _ => Number::from_f64(number_to_f64(&n) / number_to_f64(&n2)),
+    }
+}
+///
+#[deprecated(note = "use `divide::<JsNumber>()`")]
+pub fn x_divide_x(n: Number, n2: Number) -> JsNumber {
+    divide(n, n2)
+}
+
+/// See lodash [divide](https://lodash.com/docs/#divide)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{divide, JsNumber};
+/// assert_eq!(divide::<f64>(6, 4), 1.5);
+/// assert_eq!(divide::<Value>(json!(6), json!(4)), json!(1.5));
+/// assert_eq!(divide::<JsNumber>(1, 0), JsNumber::Infinity);
+/// ```
+pub fn divide<R: FromValue>(dividend: impl IntoValue, divisor: impl IntoValue) -> R {
+    R::from_js_number(JsNumber::from(dividend.into_value()) / JsNumber::from(divisor.into_value()))
+}
+///
+pub fn try_divide(
+    policy: NonFinitePolicy,
+    dividend: Value,
+    divisor: Value,
+) -> Result<Value, NonFiniteError> {
+    (JsNumber::from(dividend) / JsNumber::from(divisor)).try_into_value(policy)
+}
+
+/// Based on [x_checked_divide_x()]
+///
+/// Same as [divide()], but returns `None` instead of promoting to f64 if the result doesn't fit in `i64`/`u64`,
+/// or if it isn't finite.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_checked_divide_x!(Number::from(6), Number::from(4)),
+///   Some(Number::from_f64(1.5).unwrap())
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// assert_eq!(x_checked_divide_x!(), Some(Number::from(1)));
+/// assert_eq!(x_checked_divide_x!(Number::from(6), Number::from(-3)), Some(Number::from(-2)));
+/// assert_eq!(x_checked_divide_x!(Number::from(i64::MIN), Number::from(-1)), Some(Number::from(9223372036854775808_u64)));
+/// assert_eq!(x_checked_divide_x!(Number::from(1), Number::from(0)), None); // Infinity
+/// assert_eq!(x_checked_divide_x!(Number::from(0), Number::from(0)), None); // NaN
+/// ```
+#[macro_export]
+macro_rules! x_checked_divide_x {
+    () => {
+        Some($crate::lib::Number::from(1))
+    };
+    ($a:expr $(,)*) => {
+        Some($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::x_checked_divide_x($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::x_checked_divide_x($a, $b)
+    };
+}
+/// Based on [x_divide_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_divide_x!(Number::from(6), Number::from(4)),
+///   Number::from_f64(1.5).unwrap()
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// # use serde_json_lodash::JsNumber;
+/// assert_eq!(x_divide_x!(), Number::from(1));
+/// assert_eq!(x_divide_x!(Number::from(6), Number::from(3)), Number::from(2));
+/// assert_eq!(x_divide_x!(Number::from(-6), Number::from(4)), Number::from_f64(-1.5).unwrap());
+/// assert_eq!(x_divide_x!(Number::from(u64::MAX), Number::from(u64::MAX)), Number::from(1));
+/// assert_eq!(x_divide_x!(Number::from(u64::MAX), Number::from(-1)), Number::from_f64(-18446744073709551615.0).unwrap());
+/// assert_eq!(x_divide_x!(Number::from_f64(4.5).unwrap(), Number::from(3)), Number::from_f64(1.5).unwrap());
+/// assert_eq!(x_divide_x!(Number::from(1), Number::from(0)), JsNumber::Infinity);
+/// assert_eq!(x_divide_x!(Number::from(-1), Number::from(0)), JsNumber::NegInfinity);
+/// assert_eq!(x_divide_x!(Number::from(0), Number::from(0)), JsNumber::NaN);
+/// assert_eq!(x_divide_x!(Number::from(0), Number::from(-1)), JsNumber::NegZero);
+/// ```
+#[deprecated(note = "use `divide::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_divide_x {
+    () => {
+        $crate::JsNumber::Finite($crate::lib::Number::from(1))
+    };
+    ($a:expr $(,)*) => {
+        $crate::JsNumber::Finite($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_divide_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_divide_x($a, $b);
+        result
+    }};
+}
+/// Based on [divide()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   divide!(json!(6), json!(4)),
+///   json!(1.5)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(divide!(), json!(1));
+/// assert_eq!(divide!(json!(null)), json!(null));
+/// assert_eq!(divide!(json!(null), json!(5)), json!(0));
+/// assert_eq!(divide!(json!(6), json!(3)), json!(2));
+/// assert_eq!(divide!(json!(-6), json!(true)), json!(-6));
+/// assert_eq!(divide!(json!("9"), json!([" 3 "])), json!(3));
+/// assert_eq!(divide!(json!(1), json!(3)), json!(1.0 / 3.0));
+/// assert_eq!(divide!(json!(1), json!(0)), json!(null)); // Infinity
+/// assert_eq!(divide!(json!(1), json!("-0")), json!(null)); // -Infinity
+/// assert_eq!(divide!(json!(0), json!(-1)), json!(0)); // -0
+/// assert_eq!(divide!(json!(0), json!(null)), json!(null)); // NaN
+/// assert_eq!(divide!(json!("a"), json!(1)), json!(null)); // NaN
+/// assert_eq!(divide!(json!({}), json!(1)), json!(null)); // NaN
+/// ```
+#[macro_export]
+macro_rules! divide {
+    () => {
+        json!(1)
+    };
+    ($a:expr $(,)*) => {
+        $crate::IntoValue::into_value($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::divide::<$crate::lib::Value>($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::divide::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_divide()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_divide!(NonFinitePolicy::Error, json!(6), json!(4)), Ok(json!(1.5)));
+/// assert!(try_divide!(NonFinitePolicy::Error, json!(1), json!({})).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_divide {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(1))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>($a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_divide($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_divide($p, $a, $b)
+    };
+}
@@ -0,0 +1,199 @@
+use crate::lib::{Value, Number};
+use crate::internal::{number_round_by, Rounding};
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+///
+#[deprecated(note = "use `floor::<JsNumber>()`")]
+pub fn x_floor_x(n: Number, precision: isize) -> JsNumber {
+    floor(n, precision)
+}
+///
+#[deprecated(note = "use `floor()`, it takes a `Number` too")]
+pub fn x_floor(number: Number, precision: isize) -> Value {
+    floor(number, precision)
+}
+fn base_floor(number: Value, precision: isize) -> JsNumber {
+    match JsNumber::from(number) {
+        JsNumber::Finite(n) => number_round_by(n, precision, Rounding::Floor),
+        // NaN, Infinity and -0 have nothing to round
+        n => n,
+    }
+}
+/// See lodash [floor](https://lodash.com/docs/#floor)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{floor, JsNumber};
+/// assert_eq!(floor::<f64>(4.006, 0), 4.0);
+/// assert_eq!(floor::<Value>(json!(0.046), 2), json!(0.04));
+/// assert_eq!(floor::<JsNumber>("a", 0), JsNumber::NaN);
+/// ```
+pub fn floor<R: FromValue>(number: impl IntoValue, precision: isize) -> R {
+    R::from_js_number(base_floor(number.into_value(), precision))
+}
+///
+pub fn try_floor(
+    policy: NonFinitePolicy,
+    number: Value,
+    precision: isize,
+) -> Result<Value, NonFiniteError> {
+    base_floor(number, precision).try_into_value(policy)
+}
+
+/// Based on [x_floor_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_floor_x!(Number::from_f64(4.006).unwrap()),
+///   Number::from(4)
+/// );
+/// assert_eq!(
+///   x_floor_x!(Number::from_f64(0.046).unwrap(), 2),
+///   Number::from_f64(0.04).unwrap()
+/// );
+/// assert_eq!(
+///   x_floor_x!(Number::from(4060), -2),
+///   Number::from(4000)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Number};
+/// # use serde_json_lodash::JsNumber;
+/// assert_eq!(
+///   x_floor_x!(),
+///   JsNumber::NaN
+/// );
+/// assert_eq!(x_floor_x!(Number::from_f64(-4.006).unwrap()), Number::from(-5));
+/// assert_eq!(x_floor_x!(Number::from(-4060), -2), Number::from(-4100));
+/// assert_eq!(x_floor_x!(Number::from(u64::MAX), -1), Number::from(u64::MAX - 5));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_floor_x!(Number::from(i64::MIN), -1), Number::from_f64(-9223372036854775810.0).unwrap());
+/// assert_eq!(x_floor_x!(Number::from_f64(1.1).unwrap(), 1), Number::from_f64(1.1).unwrap());
+/// assert_eq!(x_floor_x!(Number::from_f64(0.1 + 0.7).unwrap(), 1), Number::from_f64(0.7).unwrap()); // 0.7999999999999999
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_floor_x!(Number::from_f64(5e-324).unwrap(), 400), Number::from(0)); // precision is capped at 292 like lodash
+/// ```
+#[deprecated(note = "use `floor::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_floor_x {
+    () => {
+        $crate::JsNumber::NaN
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_floor_x($a, 0);
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_floor_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_floor_x($a, $b);
+        result
+    }};
+}
+/// Based on [floor()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   floor!(json!(4.006)),
+///   json!(4)
+/// );
+/// assert_eq!(
+///   floor!(json!(0.046), 2),
+///   json!(0.04)
+/// );
+/// assert_eq!(
+///   floor!(json!(4060), -2),
+///   json!(4000)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(floor!(), json!(null)); // NaN
+/// assert_eq!(floor!(json!(null)), json!(0));
+/// assert_eq!(floor!(json!(true)), json!(1));
+/// assert_eq!(floor!(json!(-0.5)), json!(-1));
+/// assert_eq!(floor!(json!("1.9")), json!(1));
+/// assert_eq!(floor!(json!(" 0x1F "), -1), json!(30));
+/// assert_eq!(floor!(json!("a")), json!(null)); // NaN
+/// assert_eq!(floor!(json!([1.9])), json!(1));
+/// assert_eq!(floor!(json!({})), json!(null)); // NaN
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(floor!(n("12345678901234567890.999"), 2), n("12345678901234567890.99"));
+/// assert_eq!(floor!(n("-0.001"), 2), n("-0.01"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! floor {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::floor::<$crate::lib::Value>($a, 0)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::floor::<$crate::lib::Value>($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::floor::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_floor()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_floor!(NonFinitePolicy::Error, json!(4.5)), Ok(json!(4)));
+/// assert_eq!(try_floor!(NonFinitePolicy::String, json!("Infinity")), Ok(json!("Infinity")));
+/// ```
+#[macro_export]
+macro_rules! try_floor {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_floor($p, $a, 0)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_floor($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_floor($p, $a, $b)
+    };
+}
@@ -0,0 +1,67 @@
+use crate::lib::{Value};
+use crate::internal::{base_extremum, value_undefined};
+use std::cmp::Ordering;
+
+/// See lodash [max](https://lodash.com/docs/#max)
+///
+/// Items are compared like js `>` does, and `null` items are skipped.
+/// If there is no item left, the result is `null` (undefined).
+pub fn max(array: Value) -> Value {
+    match array {
+        Value::Array(vec) => base_extremum(vec.iter().map(|v| (v, v.clone())), Ordering::Greater)
+            .cloned()
+            .unwrap_or_else(value_undefined),
+        _ => value_undefined(),
+    }
+}
+
+/// Based on [max()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   max!(json!([4, 2, 8, 6])),
+///   json!(8)
+/// );
+/// assert_eq!(
+///   max!(json!([])),
+///   json!(null) // undefined
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(max!(), json!(null));
+/// assert_eq!(max!(json!(null)), json!(null));
+/// assert_eq!(max!(json!(1)), json!(null));
+/// assert_eq!(max!(json!("abc")), json!(null));
+/// assert_eq!(max!(json!({"a": 1})), json!(null));
+/// assert_eq!(max!(json!([null])), json!(null));
+/// assert_eq!(max!(json!([null, -1])), json!(-1));
+/// assert_eq!(max!(json!([1, 2.5, -3])), json!(2.5));
+/// assert_eq!(max!(json!([u64::MAX - 1, u64::MAX, i64::MIN])), json!(u64::MAX));
+/// assert_eq!(max!(json!(["b", "a", "c"])), json!("c"));
+/// assert_eq!(max!(json!([1, "10", [5]])), json!([5])); // like js, "5" > "10"
+/// assert_eq!(max!(json!([true, false])), json!(true));
+/// assert_eq!(max!(json!(["a", 1])), json!("a")); // "a" can't be compared with numbers, the first one wins
+/// assert_eq!(max!(json!([1, "a", 0])), json!(1));
+/// assert_eq!(max!(json!(["\u{ff61}", "\u{1f600}"])), json!("\u{ff61}")); // compared by utf-16
+/// ```
+#[macro_export]
+macro_rules! max {
+    () => {
+        $crate::internal::value_undefined()
+    };
+    ($a:expr $(,)*) => {
+        $crate::max($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::max($a)
+    };
+}
@@ -0,0 +1,68 @@
+use crate::lib::{Value};
+use crate::internal::{base_extremum, value_undefined};
+use crate::Iteratee;
+use std::cmp::Ordering;
+
+/// See lodash [maxBy](https://lodash.com/docs/#maxBy)
+///
+/// Same as [max()](crate::max()), but compares the computed values, and `null` computed values are skipped.
+pub fn max_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    match array {
+        Value::Array(vec) => {
+            base_extremum(vec.iter().map(|v| (v, iteratee.call(v))), Ordering::Greater)
+                .cloned()
+                .unwrap_or_else(value_undefined)
+        }
+        _ => value_undefined(),
+    }
+}
+
+/// Based on [max_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "n": 1 }, { "n": 2 }]);
+/// assert_eq!(
+///   max_by!(objects.clone(), |o: &Value| o["n"].clone()),
+///   json!({ "n": 2 })
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   max_by!(objects, "n"),
+///   json!({ "n": 2 })
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(max_by!(), json!(null));
+/// assert_eq!(max_by!(json!(null)), json!(null));
+/// assert_eq!(max_by!(json!([])), json!(null));
+/// assert_eq!(max_by!(json!({"n": 1}), "n"), json!(null));
+/// assert_eq!(max_by!(json!([3, 1, 2])), max!(json!([3, 1, 2])));
+/// assert_eq!(max_by!(json!([{}, {}]), "n"), json!(null));
+/// assert_eq!(max_by!(json!([{"n": 1}, {}, {"n": 3}, {"n": 3, "i": 1}]), "n"), json!({"n": 3}));
+/// assert_eq!(max_by!(json!([{"a": {"n": "b"}}, {"a": {"n": "c"}}]), "a.n"), json!({"a": {"n": "c"}}));
+/// assert_eq!(max_by!(json!(["a", "bbb", "cc"]), |s: &Value| json!(s.as_str().unwrap().len())), json!("bbb"));
+/// ```
+#[macro_export]
+macro_rules! max_by {
+    () => {
+        $crate::internal::value_undefined()
+    };
+    ($a:expr $(,)*) => {
+        $crate::max($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::max_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::max_by($a, $b)
+    };
+}
@@ -0,0 +1,94 @@
+use crate::lib::{Value, Number};
+use crate::internal::{base_sum};
+use crate::{JsNumber, NonFiniteError, NonFinitePolicy};
+
+/// See lodash [mean](https://lodash.com/docs/#mean)
+///
+/// The total is computed like [sum()](crate::sum()), then divided by the length of `array`.
+/// If `array` is empty, the result is `null` (NaN).
+pub fn mean(array: Value) -> Value {
+    base_mean(array).into_value()
+}
+///
+pub fn try_mean(policy: NonFinitePolicy, array: Value) -> Result<Value, NonFiniteError> {
+    base_mean(array).try_into_value(policy)
+}
+fn base_mean(array: Value) -> JsNumber {
+    match array {
+        Value::Array(vec) if !vec.is_empty() => {
+            let len = JsNumber::Finite(Number::from(vec.len()));
+            match base_sum(vec.into_iter()) {
+                Some(total) => total.into_js_number() / len,
+                None => JsNumber::NaN,
+            }
+        }
+        _ => JsNumber::NaN,
+    }
+}
+
+/// Based on [mean()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   mean!(json!([4, 2, 8, 6])),
+///   json!(5)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(mean!(), json!(null)); // NaN
+/// assert_eq!(mean!(json!(null)), json!(null)); // NaN
+/// assert_eq!(mean!(json!(1)), json!(null)); // NaN
+/// assert_eq!(mean!(json!({})), json!(null)); // NaN
+/// assert_eq!(mean!(json!([])), json!(null)); // NaN
+/// assert_eq!(mean!(json!([null])), json!(null)); // NaN
+/// assert_eq!(mean!(json!([1, 2])), json!(1.5));
+/// assert_eq!(mean!(json!([1, null, 2])), json!(1));
+/// assert_eq!(mean!(json!(["4", "2"])), json!(21)); // like js, "42" / 2
+/// assert_eq!(mean!(json!([1, {}])), json!(null)); // NaN
+/// assert_eq!(mean!(json!([u64::MAX, u64::MAX])), json!(18446744073709551616.0));
+/// assert_eq!(mean!(json!([9007199254740993_u64, 9007199254740995_u64])), json!(9007199254740994_u64));
+/// ```
+#[macro_export]
+macro_rules! mean {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::mean($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::mean($a)
+    };
+}
+/// Based on [try_mean()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_mean!(NonFinitePolicy::Error, json!([4, 2, 8, 6])), Ok(json!(5)));
+/// assert_eq!(try_mean!(NonFinitePolicy::String, json!([])), Ok(json!("NaN")));
+/// ```
+#[macro_export]
+macro_rules! try_mean {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_mean($p, $a)
+    };
+    ($p:expr, $a:expr, $($rest:tt)*) => {
+        $crate::try_mean($p, $a)
+    };
+}
@@ -0,0 +1,105 @@
+use crate::lib::{Value, Number};
+use crate::internal::{base_sum};
+use crate::{Iteratee, JsNumber, NonFiniteError, NonFinitePolicy};
+
+/// See lodash [meanBy](https://lodash.com/docs/#meanBy)
+///
+/// Same as [mean()](crate::mean()), but the total is computed like [sum_by()](crate::sum_by()).
+pub fn mean_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    base_mean_by(array, iteratee).into_value()
+}
+///
+pub fn try_mean_by<I: Iteratee>(
+    policy: NonFinitePolicy,
+    array: Value,
+    iteratee: I,
+) -> Result<Value, NonFiniteError> {
+    base_mean_by(array, iteratee).try_into_value(policy)
+}
+fn base_mean_by<I: Iteratee>(array: Value, iteratee: I) -> JsNumber {
+    match array {
+        Value::Array(vec) if !vec.is_empty() => {
+            let len = JsNumber::Finite(Number::from(vec.len()));
+            match base_sum(vec.iter().map(|v| iteratee.call(v))) {
+                Some(total) => total.into_js_number() / len,
+                None => JsNumber::NaN,
+            }
+        }
+        _ => JsNumber::NaN,
+    }
+}
+
+/// Based on [mean_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "n": 4 }, { "n": 2 }, { "n": 8 }, { "n": 6 }]);
+/// assert_eq!(
+///   mean_by!(objects.clone(), |o: &Value| o["n"].clone()),
+///   json!(5)
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   mean_by!(objects, "n"),
+///   json!(5)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(mean_by!(), json!(null)); // NaN
+/// assert_eq!(mean_by!(json!(null)), json!(null)); // NaN
+/// assert_eq!(mean_by!(json!([])), json!(null)); // NaN
+/// assert_eq!(mean_by!(json!([1, 2])), json!(1.5));
+/// assert_eq!(mean_by!(json!([{}, {}]), "n"), json!(null)); // NaN
+/// assert_eq!(mean_by!(json!([{"n": 1}, {"n": {}}]), "n"), json!(null)); // NaN
+/// assert_eq!(mean_by!(json!([{"n": 1}, {}, {"n": 5}]), "n"), json!(2));
+/// assert_eq!(mean_by!(json!([{"n": 1}, {"n": 2}]), |o: &Value| json!(o["n"].as_i64().unwrap() * 10)), json!(15));
+/// ```
+#[macro_export]
+macro_rules! mean_by {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::mean($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::mean_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::mean_by($a, $b)
+    };
+}
+/// Based on [try_mean_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_mean_by!(NonFinitePolicy::Error, json!([{"n": 4}, {"n": 6}]), "n"), Ok(json!(5)));
+/// assert!(try_mean_by!(NonFinitePolicy::Error, json!([{"n": 4}]), "m").is_err());
+/// ```
+#[macro_export]
+macro_rules! try_mean_by {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_mean($p, $a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_mean_by($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_mean_by($p, $a, $b)
+    };
+}
@@ -0,0 +1,66 @@
+use crate::lib::{Value};
+use crate::internal::{base_extremum, value_undefined};
+use std::cmp::Ordering;
+
+/// See lodash [min](https://lodash.com/docs/#min)
+///
+/// Items are compared like js `<` does, and `null` items are skipped.
+/// If there is no item left, the result is `null` (undefined).
+pub fn min(array: Value) -> Value {
+    match array {
+        Value::Array(vec) => base_extremum(vec.iter().map(|v| (v, v.clone())), Ordering::Less)
+            .cloned()
+            .unwrap_or_else(value_undefined),
+        _ => value_undefined(),
+    }
+}
+
+/// Based on [min()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   min!(json!([4, 2, 8, 6])),
+///   json!(2)
+/// );
+/// assert_eq!(
+///   min!(json!([])),
+///   json!(null) // undefined
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(min!(), json!(null));
+/// assert_eq!(min!(json!(null)), json!(null));
+/// assert_eq!(min!(json!(1)), json!(null));
+/// assert_eq!(min!(json!("abc")), json!(null));
+/// assert_eq!(min!(json!({"a": 1})), json!(null));
+/// assert_eq!(min!(json!([null])), json!(null));
+/// assert_eq!(min!(json!([null, 1])), json!(1));
+/// assert_eq!(min!(json!([1, -2.5, 3])), json!(-2.5));
+/// assert_eq!(min!(json!([u64::MAX, i64::MIN + 1, i64::MIN])), json!(i64::MIN));
+/// assert_eq!(min!(json!(["b", "a", "c"])), json!("a"));
+/// assert_eq!(min!(json!([10, "2", [5]])), json!("2"));
+/// assert_eq!(min!(json!([true, false])), json!(false));
+/// assert_eq!(min!(json!(["a", 1])), json!("a")); // "a" can't be compared with numbers, the first one wins
+/// assert_eq!(min!(json!([{}, []])), json!([])); // "[object Object]" > ""
+/// ```
+#[macro_export]
+macro_rules! min {
+    () => {
+        $crate::internal::value_undefined()
+    };
+    ($a:expr $(,)*) => {
+        $crate::min($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::min($a)
+    };
+}
@@ -0,0 +1,68 @@
+use crate::lib::{Value};
+use crate::internal::{base_extremum, value_undefined};
+use crate::Iteratee;
+use std::cmp::Ordering;
+
+/// See lodash [minBy](https://lodash.com/docs/#minBy)
+///
+/// Same as [min()](crate::min()), but compares the computed values, and `null` computed values are skipped.
+pub fn min_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    match array {
+        Value::Array(vec) => {
+            base_extremum(vec.iter().map(|v| (v, iteratee.call(v))), Ordering::Less)
+                .cloned()
+                .unwrap_or_else(value_undefined)
+        }
+        _ => value_undefined(),
+    }
+}
+
+/// Based on [min_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "n": 1 }, { "n": 2 }]);
+/// assert_eq!(
+///   min_by!(objects.clone(), |o: &Value| o["n"].clone()),
+///   json!({ "n": 1 })
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   min_by!(objects, "n"),
+///   json!({ "n": 1 })
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(min_by!(), json!(null));
+/// assert_eq!(min_by!(json!(null)), json!(null));
+/// assert_eq!(min_by!(json!([])), json!(null));
+/// assert_eq!(min_by!(json!({"n": 1}), "n"), json!(null));
+/// assert_eq!(min_by!(json!([3, 1, 2])), min!(json!([3, 1, 2])));
+/// assert_eq!(min_by!(json!([{}, {}]), "n"), json!(null));
+/// assert_eq!(min_by!(json!([{"n": 3}, {}, {"n": 1}, {"n": 1, "i": 1}]), "n"), json!({"n": 1}));
+/// assert_eq!(min_by!(json!([{"a": {"n": "b"}}, {"a": {"n": "c"}}]), "a.n"), json!({"a": {"n": "b"}}));
+/// assert_eq!(min_by!(json!(["aa", "b", "cc"]), |s: &Value| json!(s.as_str().unwrap().len())), json!("b"));
+/// ```
+#[macro_export]
+macro_rules! min_by {
+    () => {
+        $crate::internal::value_undefined()
+    };
+    ($a:expr $(,)*) => {
+        $crate::min($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::min_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::min_by($a, $b)
+    };
+}
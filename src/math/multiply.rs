@@ -0,0 +1,231 @@
+use crate::lib::{Value, Number};
+use crate::internal::{number_to_f64, number_to_option_i128, i128_to_option_number};
+use crate::internal::decimal::Decimal;
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+///
+pub fn x_checked_multiply_x(n: Number, n2: Number) -> Option<Number> {
+    if cfg!(feature = "arbitrary_precision") {
+        return Some((Decimal::from(&n) * Decimal::from(&n2)).into());
+    }
+    match (number_to_option_i128(&n), number_to_option_i128(&n2)) {
+        (Some(i), Some(i2)) => i.checked_mul(i2).and_then(i128_to_option_number),
+        _ => Number::from_f64(number_to_f64(&n) * number_to_f64(&n2)),
+    }
+}
+///
+#[deprecated(note = "use `multiply::<JsNumber>()`")]
+pub fn x_multiply_x(n: Number, n2: Number) -> JsNumber {
+    multiply(n, n2)
+}
+
+/// See lodash [multiply](https://lodash.com/docs/#multiply)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{multiply, JsNumber};
+/// assert_eq!(multiply::<f64>(6, 4), 24.0);
+/// assert_eq!(multiply::<Value>(json!(6), json!(4)), json!(24));
+/// assert_eq!(multiply::<JsNumber>("a", 2), JsNumber::NaN);
+/// ```
+pub fn multiply<R: FromValue>(multiplier: impl IntoValue, multiplicand: impl IntoValue) -> R {
+    R::from_js_number(
+        JsNumber::from(multiplier.into_value()) * JsNumber::from(multiplicand.into_value()),
+    )
+}
+///
+pub fn try_multiply(
+    policy: NonFinitePolicy,
+    multiplier: Value,
+    multiplicand: Value,
+) -> Result<Value, NonFiniteError> {
+    (JsNumber::from(multiplier) * JsNumber::from(multiplicand)).try_into_value(policy)
+}
+
+/// Based on [x_checked_multiply_x()]
+///
+/// Same as [multiply()], but returns `None` instead of promoting to f64 if the result doesn't fit in `i64`/`u64`,
+/// or if it isn't finite. With the `arbitrary_precision` feature, it is exact on decimals and never `None`.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_checked_multiply_x!(Number::from(6), Number::from(4)),
+///   Some(Number::from(24))
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// assert_eq!(x_checked_multiply_x!(), Some(Number::from(1)));
+/// assert_eq!(x_checked_multiply_x!(Number::from(-3), Number::from(4)), Some(Number::from(-12)));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_multiply_x!(Number::from(u64::MAX), Number::from(u64::MAX)), None);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_multiply_x!(Number::from(i64::MIN), Number::from(2)), None);
+/// assert_eq!(x_checked_multiply_x!(Number::from(i64::MIN), Number::from(-1)), Some(Number::from(9223372036854775808_u64)));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_multiply_x!(Number::from_f64(f64::MAX).unwrap(), Number::from(2)), None);
+/// ```
+#[macro_export]
+macro_rules! x_checked_multiply_x {
+    () => {
+        Some($crate::lib::Number::from(1))
+    };
+    ($a:expr $(,)*) => {
+        Some($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::x_checked_multiply_x($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::x_checked_multiply_x($a, $b)
+    };
+}
+/// Based on [x_multiply_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_multiply_x!(Number::from(6), Number::from(4)),
+///   Number::from(24)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// assert_eq!(x_multiply_x!(), Number::from(1));
+/// assert_eq!(x_multiply_x!(Number::from(-3), Number::from(-4)), Number::from(12));
+/// assert_eq!(x_multiply_x!(Number::from_f64(1.5).unwrap(), Number::from(3)), Number::from_f64(4.5).unwrap());
+/// // promote to f64 like js does if it overflows
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_multiply_x!(Number::from(u64::MAX), Number::from(2)), Number::from_f64(36893488147419103232.0).unwrap());
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_multiply_x!(Number::from(u64::MAX), Number::from(u64::MAX)), Number::from_f64(3.402823669209385e38).unwrap());
+/// ```
+#[deprecated(note = "use `multiply::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_multiply_x {
+    () => {
+        $crate::JsNumber::Finite($crate::lib::Number::from(1))
+    };
+    ($a:expr $(,)*) => {
+        $crate::JsNumber::Finite($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_multiply_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_multiply_x($a, $b);
+        result
+    }};
+}
+/// Based on [multiply()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   multiply!(json!(6), json!(4)),
+///   json!(24)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(multiply!(), json!(1));
+/// assert_eq!(multiply!(json!(null)), json!(null));
+/// assert_eq!(multiply!(json!(null), json!(5)), json!(0));
+/// assert_eq!(multiply!(json!(true), json!(-5)), json!(-5));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(multiply!(json!(1.5), json!("2")), json!(3.0));
+/// assert_eq!(multiply!(json!("1e2"), json!([3])), json!(300));
+/// assert_eq!(multiply!(json!("a"), json!(1)), json!(null)); // NaN
+/// assert_eq!(multiply!(json!({}), json!(1)), json!(null)); // NaN
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(multiply!(json!(u64::MAX), json!(2)), json!(36893488147419103230.0));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(multiply!(json!(1e300), json!(1e300)), json!(null)); // Infinity
+/// assert_eq!(multiply!(json!("Infinity"), json!(0)), json!(null)); // NaN
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(multiply!(json!(1e300), json!(-1e300)), json!(null)); // -Infinity
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(multiply!(n("12345678901234567890.5"), json!(2)), n("24691357802469135781"));
+/// assert_eq!(multiply!(json!(0.1), json!(3)), n("0.3"));
+/// assert_eq!(multiply!(json!(1e300), json!(1e300)), n("1e600"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! multiply {
+    () => {
+        json!(1)
+    };
+    ($a:expr $(,)*) => {
+        $crate::IntoValue::into_value($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::multiply::<$crate::lib::Value>($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::multiply::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_multiply()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_multiply!(NonFinitePolicy::Error, json!(6), json!(4)), Ok(json!(24)));
+/// assert!(try_multiply!(NonFinitePolicy::Error, json!(1), json!({})).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_multiply {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(1))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>($a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_multiply($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_multiply($p, $a, $b)
+    };
+}
@@ -0,0 +1,204 @@
+use crate::lib::{Value, Number};
+use crate::internal::{number_round_by, Rounding};
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+///
+#[deprecated(note = "use `round::<JsNumber>()`")]
+pub fn x_round_x(n: Number, precision: isize) -> JsNumber {
+    round(n, precision)
+}
+///
+#[deprecated(note = "use `round()`, it takes a `Number` too")]
+pub fn x_round(number: Number, precision: isize) -> Value {
+    round(number, precision)
+}
+fn base_round(number: Value, precision: isize) -> JsNumber {
+    match JsNumber::from(number) {
+        JsNumber::Finite(n) => number_round_by(n, precision, Rounding::Round),
+        // NaN, Infinity and -0 have nothing to round
+        n => n,
+    }
+}
+/// See lodash [round](https://lodash.com/docs/#round)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{round, JsNumber};
+/// assert_eq!(round::<f64>(4.006, 0), 4.0);
+/// assert_eq!(round::<Value>(json!(4.006), 2), json!(4.01));
+/// assert_eq!(round::<JsNumber>("a", 0), JsNumber::NaN);
+/// ```
+pub fn round<R: FromValue>(number: impl IntoValue, precision: isize) -> R {
+    R::from_js_number(base_round(number.into_value(), precision))
+}
+///
+pub fn try_round(
+    policy: NonFinitePolicy,
+    number: Value,
+    precision: isize,
+) -> Result<Value, NonFiniteError> {
+    base_round(number, precision).try_into_value(policy)
+}
+
+/// Based on [x_round_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_round_x!(Number::from_f64(4.006).unwrap()),
+///   Number::from(4)
+/// );
+/// assert_eq!(
+///   x_round_x!(Number::from_f64(4.006).unwrap(), 2),
+///   Number::from_f64(4.01).unwrap()
+/// );
+/// assert_eq!(
+///   x_round_x!(Number::from(4060), -2),
+///   Number::from(4100)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Number};
+/// # use serde_json_lodash::JsNumber;
+/// assert_eq!(
+///   x_round_x!(),
+///   JsNumber::NaN
+/// );
+/// assert_eq!(x_round_x!(Number::from_f64(1.005).unwrap(), 2), Number::from_f64(1.01).unwrap());
+/// assert_eq!(x_round_x!(Number::from_f64(1.255).unwrap(), 2), Number::from_f64(1.26).unwrap());
+/// assert_eq!(x_round_x!(Number::from_f64(2.5).unwrap()), Number::from(3));
+/// assert_eq!(x_round_x!(Number::from_f64(-2.5).unwrap()), Number::from(-2)); // like Math.round
+/// assert_eq!(x_round_x!(Number::from_f64(-2.51).unwrap()), Number::from(-3));
+/// assert_eq!(x_round_x!(Number::from_f64(0.49999999999999994).unwrap()), Number::from(0));
+/// assert_eq!(x_round_x!(Number::from(4050), -2), Number::from(4100));
+/// assert_eq!(x_round_x!(Number::from(-4050), -2), Number::from(-4000));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_round_x!(Number::from(u64::MAX), -1), Number::from_f64(18446744073709551620.0).unwrap());
+/// assert_eq!(x_round_x!(Number::from(u64::MAX - 10), -1), Number::from(u64::MAX - 5));
+/// assert_eq!(x_round_x!(Number::from_f64(1e300).unwrap(), 200), Number::from_f64(1e300).unwrap());
+/// ```
+#[deprecated(note = "use `round::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_round_x {
+    () => {
+        $crate::JsNumber::NaN
+    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_round_x($a, 0);
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_round_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_round_x($a, $b);
+        result
+    }};
+}
+/// Based on [round()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   round!(json!(4.006)),
+///   json!(4)
+/// );
+/// assert_eq!(
+///   round!(json!(4.006), 2),
+///   json!(4.01)
+/// );
+/// assert_eq!(
+///   round!(json!(4060), -2),
+///   json!(4100)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(round!(), json!(null)); // NaN
+/// assert_eq!(round!(json!(null)), json!(0));
+/// assert_eq!(round!(json!(false)), json!(0));
+/// assert_eq!(round!(json!(1.005), 2), json!(1.01));
+/// assert_eq!(round!(json!(1.5)), json!(2));
+/// assert_eq!(round!(json!("1.45"), 1), json!(1.5));
+/// assert_eq!(round!(json!("a")), json!(null)); // NaN
+/// assert_eq!(round!(json!(["2.5"])), json!(3));
+/// assert_eq!(round!(json!({})), json!(null)); // NaN
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(round!(n("12345678901234567890.125"), 2), n("12345678901234567890.13"));
+/// assert_eq!(round!(n("-12345678901234567890.125"), 2), n("-12345678901234567890.12"));
+/// assert_eq!(round!(n("12345678901234567890.5")), n("12345678901234567891"));
+/// assert_eq!(round!(n("12345678901234567890"), -3), n("12345678901234568000"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! round {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::round::<$crate::lib::Value>($a, 0)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::round::<$crate::lib::Value>($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::round::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_round()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_round!(NonFinitePolicy::Error, json!(4.5)), Ok(json!(5)));
+/// assert_eq!(try_round!(NonFinitePolicy::String, json!("Infinity")), Ok(json!("Infinity")));
+/// ```
+#[macro_export]
+macro_rules! try_round {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_round($p, $a, 0)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_round($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_round($p, $a, $b)
+    };
+}
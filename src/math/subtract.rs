@@ -0,0 +1,232 @@
+use crate::lib::{Value, Number};
+use crate::internal::{number_to_f64, number_to_option_i128, i128_to_option_number};
+use crate::internal::decimal::Decimal;
+use crate::{FromValue, IntoValue, JsNumber, NonFiniteError, NonFinitePolicy};
+
+///
+pub fn x_checked_subtract_x(n: Number, n2: Number) -> Option<Number> {
+    if cfg!(feature = "arbitrary_precision") {
+        return Some((Decimal::from(&n) - Decimal::from(&n2)).into());
+    }
+    match (number_to_option_i128(&n), number_to_option_i128(&n2)) {
+        (Some(i), Some(i2)) => i128_to_option_number(i - i2),
+        _ => Number::from_f64(number_to_f64(&n) - number_to_f64(&n2)),
+    }
+}
+///
+#[deprecated(note = "use `subtract::<JsNumber>()`")]
+pub fn x_subtract_x(n: Number, n2: Number) -> JsNumber {
+    subtract(n, n2)
+}
+
+/// See lodash [subtract](https://lodash.com/docs/#subtract)
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a [JsNumber], an `f64` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::{subtract, JsNumber};
+/// assert_eq!(subtract::<f64>(6, 4), 2.0);
+/// assert_eq!(subtract::<Value>(json!(6), json!(4)), json!(2));
+/// assert_eq!(subtract::<JsNumber>("a", 1), JsNumber::NaN);
+/// ```
+pub fn subtract<R: FromValue>(minuend: impl IntoValue, subtrahend: impl IntoValue) -> R {
+    R::from_js_number(
+        JsNumber::from(minuend.into_value()) - JsNumber::from(subtrahend.into_value()),
+    )
+}
+///
+pub fn try_subtract(
+    policy: NonFinitePolicy,
+    minuend: Value,
+    subtrahend: Value,
+) -> Result<Value, NonFiniteError> {
+    (JsNumber::from(minuend) - JsNumber::from(subtrahend)).try_into_value(policy)
+}
+
+/// Based on [x_checked_subtract_x()]
+///
+/// Same as [subtract()], but returns `None` instead of promoting to f64 if the result doesn't fit in `i64`/`u64`,
+/// or if it isn't finite. With the `arbitrary_precision` feature, it is exact on decimals, but when one operand is
+/// more than 1000 zeros below the last digit of the other, it's negligible and the result is the other one.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_checked_subtract_x!(Number::from(6), Number::from(4)),
+///   Some(Number::from(2))
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// assert_eq!(x_checked_subtract_x!(), Some(Number::from(0)));
+/// assert_eq!(x_checked_subtract_x!(Number::from(1), Number::from(2)), Some(Number::from(-1)));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_subtract_x!(Number::from(u64::MAX), Number::from(-1)), None);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_subtract_x!(Number::from(i64::MIN), Number::from(1)), None);
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_checked_subtract_x!(Number::from_f64(-f64::MAX).unwrap(), Number::from_f64(f64::MAX).unwrap()), None);
+/// ```
+#[macro_export]
+macro_rules! x_checked_subtract_x {
+    () => {
+        Some($crate::lib::Number::from(0))
+    };
+    ($a:expr $(,)*) => {
+        Some($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::x_checked_subtract_x($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::x_checked_subtract_x($a, $b)
+    };
+}
+/// Based on [x_subtract_x()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::Number;
+/// assert_eq!(
+///   x_subtract_x!(Number::from(6), Number::from(4)),
+///   Number::from(2)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::Number;
+/// assert_eq!(x_subtract_x!(), Number::from(0));
+/// assert_eq!(x_subtract_x!(Number::from(1), Number::from(2)), Number::from(-1));
+/// assert_eq!(x_subtract_x!(Number::from(-1), Number::from(-2)), Number::from(1));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_subtract_x!(Number::from(i64::MIN), Number::from(u64::MAX)), Number::from_f64(-27670116110564327424.0).unwrap());
+/// assert_eq!(x_subtract_x!(Number::from_f64(0.5).unwrap(), Number::from(1)), Number::from_f64(-0.5).unwrap());
+/// // promote to f64 like js does if it overflows
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(x_subtract_x!(Number::from(u64::MAX), Number::from(-1)), Number::from_f64(18446744073709551616.0).unwrap());
+/// ```
+#[deprecated(note = "use `subtract::<JsNumber>()`")]
+#[macro_export]
+macro_rules! x_subtract_x {
+    () => {
+        $crate::JsNumber::Finite($crate::lib::Number::from(0))
+    };
+    ($a:expr $(,)*) => {
+        $crate::JsNumber::Finite($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_subtract_x($a, $b);
+        result
+    }};
+    ($a:expr, $b:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_subtract_x($a, $b);
+        result
+    }};
+}
+/// Based on [subtract()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   subtract!(json!(6), json!(4)),
+///   json!(2)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(subtract!(), json!(0));
+/// assert_eq!(subtract!(json!(null)), json!(null));
+/// assert_eq!(subtract!(json!(null), json!(null)), json!(0));
+/// assert_eq!(subtract!(json!(true), json!(2)), json!(-1));
+/// assert_eq!(subtract!(json!(1.5), json!(false)), json!(1.5));
+/// assert_eq!(subtract!(json!("6"), json!("4")), json!(2)); // not like `add`, strings are numbers here
+/// assert_eq!(subtract!(json!(" 0x10 "), json!([1])), json!(15));
+/// assert_eq!(subtract!(json!("a"), json!(1)), json!(null)); // NaN
+/// assert_eq!(subtract!(json!([1, 2]), json!(1)), json!(null)); // NaN
+/// assert_eq!(subtract!(json!({}), json!(1)), json!(null)); // NaN
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(subtract!(json!(i64::MIN), json!(1)), json!(-9223372036854775809.0));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(subtract!(json!(-1.7976931348623157e308), json!(1.7976931348623157e308)), json!(null)); // -Infinity
+/// ```
+///
+/// With the `arbitrary_precision` feature, it's exact on decimals:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// # #[cfg(feature = "arbitrary_precision")]
+/// # {
+/// let n = |s: &str| serde_json::from_str::<serde_json::Value>(s).unwrap();
+/// assert_eq!(subtract!(n("12345678901234567890.1"), n("0.2")), n("12345678901234567889.9"));
+/// assert_eq!(subtract!(n("1e2000"), json!(1)), n("1e2000"));
+/// assert_eq!(subtract!(json!(1), n("1e2000")), n("-1e2000"));
+/// assert_eq!(subtract!(json!(0.3), json!(0.1)), n("0.2"));
+/// assert_eq!(subtract!(json!(i64::MIN), json!(1)), n("-9223372036854775809"));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! subtract {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::IntoValue::into_value($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::subtract::<$crate::lib::Value>($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::subtract::<$crate::lib::Value>($a, $b)
+    };
+}
+/// Based on [try_subtract()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_subtract!(NonFinitePolicy::Error, json!(6), json!(4)), Ok(json!(2)));
+/// assert!(try_subtract!(NonFinitePolicy::Error, json!(1), json!({})).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_subtract {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(0))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>($a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_subtract($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_subtract($p, $a, $b)
+    };
+}
@@ -0,0 +1,99 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_sum, value_undefined, JsSum};
+use crate::{NonFiniteError, NonFinitePolicy};
+
+/// See lodash [sum](https://lodash.com/docs/#sum)
+///
+/// Numbers are added like [add()](crate::add()), so the total stays an exact integer as long as it fits in `i64`/`u64`.
+/// `null` items are skipped like `undefined`, and if there is no item left, the result is `null` (undefined).
+pub fn sum(array: Value) -> Value {
+    sum_total(array).map_or_else(value_undefined, JsSum::into_value)
+}
+///
+pub fn try_sum(policy: NonFinitePolicy, array: Value) -> Result<Value, NonFiniteError> {
+    sum_total(array).map_or_else(
+        || Ok(value_undefined()),
+        |total| total.try_into_value(policy),
+    )
+}
+// `None` is undefined
+fn sum_total(array: Value) -> Option<JsSum> {
+    match array {
+        Value::Array(vec) if !vec.is_empty() => base_sum(vec.into_iter()),
+        _ => Some(JsSum::Value(json!(0))),
+    }
+}
+
+/// Based on [sum()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   sum!(json!([4, 2, 8, 6])),
+///   json!(20)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(sum!(), json!(0));
+/// assert_eq!(sum!(json!(null)), json!(0));
+/// assert_eq!(sum!(json!(true)), json!(0));
+/// assert_eq!(sum!(json!(1)), json!(0));
+/// assert_eq!(sum!(json!("abc")), json!(0));
+/// assert_eq!(sum!(json!({})), json!(0));
+/// assert_eq!(sum!(json!([])), json!(0));
+/// assert_eq!(sum!(json!([null])), json!(null)); // undefined
+/// assert_eq!(sum!(json!([null, 1, null, 2])), json!(3));
+/// assert_eq!(sum!(json!([1, -2, 0.5])), json!(-0.5));
+/// assert_eq!(sum!(json!([true, [2], " 3 "])), json!("3 3 ")); // like js, strings are concatenated
+/// assert_eq!(sum!(json!([1, {}, 2])), json!(null)); // NaN
+/// assert_eq!(sum!(json!([1, {}, "a"])), json!("NaNa")); // like js, NaN + "a"
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(sum!(json!([1e308, 1e308, "a"])), json!("Infinitya"));
+/// assert_eq!(sum!(json!([u64::MAX - 1, 1])), json!(u64::MAX));
+/// assert_eq!(sum!(json!([9007199254740993_u64, 1])), json!(9007199254740994_u64));
+/// # #[cfg(not(feature = "arbitrary_precision"))]
+/// assert_eq!(sum!(json!([u64::MAX, 1])), json!(18446744073709551616.0));
+/// ```
+#[macro_export]
+macro_rules! sum {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::sum($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::sum($a)
+    };
+}
+/// Based on [try_sum()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_sum!(NonFinitePolicy::Error, json!([4, 2, 8, 6])), Ok(json!(20)));
+/// assert!(try_sum!(NonFinitePolicy::Error, json!([1, {}])).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_sum {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(0))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_sum($p, $a)
+    };
+    ($p:expr, $a:expr, $($rest:tt)*) => {
+        $crate::try_sum($p, $a)
+    };
+}
@@ -0,0 +1,105 @@
+use crate::lib::{json, Value};
+use crate::internal::{base_sum, value_undefined, JsSum};
+use crate::{Iteratee, NonFiniteError, NonFinitePolicy};
+
+/// See lodash [sumBy](https://lodash.com/docs/#sumBy)
+///
+/// Same as [sum()](crate::sum()), `null` computed values are skipped.
+pub fn sum_by<I: Iteratee>(array: Value, iteratee: I) -> Value {
+    sum_by_total(array, iteratee).map_or_else(value_undefined, JsSum::into_value)
+}
+///
+pub fn try_sum_by<I: Iteratee>(
+    policy: NonFinitePolicy,
+    array: Value,
+    iteratee: I,
+) -> Result<Value, NonFiniteError> {
+    sum_by_total(array, iteratee).map_or_else(
+        || Ok(value_undefined()),
+        |total| total.try_into_value(policy),
+    )
+}
+// `None` is undefined
+fn sum_by_total<I: Iteratee>(array: Value, iteratee: I) -> Option<JsSum> {
+    match array {
+        Value::Array(vec) if !vec.is_empty() => base_sum(vec.iter().map(|v| iteratee.call(v))),
+        _ => Some(JsSum::Value(json!(0))),
+    }
+}
+
+/// Based on [sum_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let objects = json!([{ "n": 4 }, { "n": 2 }, { "n": 8 }, { "n": 6 }]);
+/// assert_eq!(
+///   sum_by!(objects.clone(), |o: &Value| o["n"].clone()),
+///   json!(20)
+/// );
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(
+///   sum_by!(objects, "n"),
+///   json!(20)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(sum_by!(), json!(0));
+/// assert_eq!(sum_by!(json!(null)), json!(0));
+/// assert_eq!(sum_by!(json!({})), json!(0));
+/// assert_eq!(sum_by!(json!([])), json!(0));
+/// assert_eq!(sum_by!(json!([1, 2])), json!(3));
+/// assert_eq!(sum_by!(json!([{}, {}]), "n"), json!(null)); // undefined
+/// let orders = json!([{ "total": 3 }, { "id": 2 }, { "total": 1.5 }, { "total": "2" }]);
+/// assert_eq!(sum_by!(orders.clone(), "total"), json!("4.52"));
+/// assert_eq!(sum_by!(orders, |o: &Value| to_number!(o["total"].clone())), json!(6.5));
+/// assert_eq!(sum_by!(json!([{"a": {"b": [1]}}, {"a": {"b": [2]}}]), "a.b[0]"), json!(3));
+/// ```
+#[macro_export]
+macro_rules! sum_by {
+    () => {
+        json!(0)
+    };
+    ($a:expr $(,)*) => {
+        $crate::sum($a)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::sum_by($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::sum_by($a, $b)
+    };
+}
+/// Based on [try_sum_by()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_sum_by!(NonFinitePolicy::Error, json!([{"n": 4}, {"n": 6}]), "n"), Ok(json!(10)));
+/// assert!(try_sum_by!(NonFinitePolicy::Error, json!([{"n": 4}, {"n": {}}]), "n").is_err());
+/// ```
+#[macro_export]
+macro_rules! try_sum_by {
+    ($p:expr $(,)*) => {
+        Ok::<$crate::lib::Value, $crate::NonFiniteError>(json!(0))
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_sum($p, $a)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_sum_by($p, $a, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::try_sum_by($p, $a, $b)
+    };
+}
@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+
+use crate::lib::{Value, Number};
+use crate::{JsNumber, NonFiniteError, NonFinitePolicy};
+
+/// See lodash [clamp](https://lodash.com/docs/#clamp)
+///
+/// `number`, `lower` and `upper` are converted like [to_number()](crate::to_number()), a `NaN` bound is taken as `0`.
+/// A `null` bound is `undefined`, which leaves that side unbounded.
+pub fn clamp(number: Value, lower: Value, upper: Value) -> Value {
+    base_clamp(number, lower, upper).into_value()
+}
+///
+pub fn try_clamp(
+    policy: NonFinitePolicy,
+    number: Value,
+    lower: Value,
+    upper: Value,
+) -> Result<Value, NonFiniteError> {
+    base_clamp(number, lower, upper).try_into_value(policy)
+}
+fn base_clamp(number: Value, lower: Value, upper: Value) -> JsNumber {
+    let to_bound = |v: Value| match v {
+        Value::Null => None,
+        v => match JsNumber::from(v) {
+            JsNumber::NaN => Some(JsNumber::Finite(Number::from(0))),
+            n => Some(n),
+        },
+    };
+    let (lower, upper) = (to_bound(lower), to_bound(upper));
+    let mut number = JsNumber::from(number);
+    if !number.is_nan() {
+        if let Some(upper) = upper {
+            if number.js_cmp(&upper) == Some(Ordering::Greater) {
+                number = upper;
+            }
+        }
+        if let Some(lower) = lower {
+            if number.js_cmp(&lower) == Some(Ordering::Less) {
+                number = lower;
+            }
+        }
+    }
+    number
+}
+
+/// Based on [clamp()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   clamp!(json!(-10), json!(-5), json!(5)),
+///   json!(-5)
+/// );
+/// assert_eq!(
+///   clamp!(json!(10), json!(-5), json!(5)),
+///   json!(5)
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(clamp!(), json!(null)); // NaN
+/// assert_eq!(clamp!(json!(3)), json!(3));
+/// assert_eq!(clamp!(json!("3")), json!(3));
+/// assert_eq!(clamp!(json!(10), json!(5)), json!(5));
+/// assert_eq!(clamp!(json!(-10), json!(5)), json!(-10));
+/// assert_eq!(clamp!(json!(-10), json!(null), json!(5)), json!(-10));
+/// assert_eq!(clamp!(json!(10), json!(-5), json!(null)), json!(10));
+/// assert_eq!(clamp!(json!(-10), json!(-5), json!(null)), json!(-5));
+/// assert_eq!(clamp!(json!(1.5), json!(-5), json!(5)), json!(1.5));
+/// assert_eq!(clamp!(json!("a"), json!(-5), json!(5)), json!(null)); // NaN
+/// assert_eq!(clamp!(json!(10), json!(-5), json!("a")), json!(0));
+/// assert_eq!(clamp!(json!(-10), json!("a"), json!(5)), json!(0));
+/// assert_eq!(clamp!(json!(10), json!(5), json!(-5)), json!(5)); // bounds are not swapped
+/// assert_eq!(clamp!(json!(-10), json!(5), json!(-5)), json!(5));
+/// assert_eq!(clamp!(json!("Infinity"), json!(-5), json!(5)), json!(5));
+/// assert_eq!(clamp!(json!(u64::MAX), json!(0), json!(u64::MAX - 1)), json!(u64::MAX - 1));
+/// ```
+#[macro_export]
+macro_rules! clamp {
+    () => {
+        $crate::internal::value_nan()
+    };
+    ($a:expr $(,)*) => {
+        $crate::clamp($a, $crate::lib::Value::Null, $crate::lib::Value::Null)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::clamp($a, $crate::lib::Value::Null, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::clamp($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::clamp($a, $b, $c)
+    };
+}
+/// Based on [try_clamp()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::NonFinitePolicy;
+/// assert_eq!(try_clamp!(NonFinitePolicy::Error, json!(10), json!(-5), json!(5)), Ok(json!(5)));
+/// assert_eq!(try_clamp!(NonFinitePolicy::String, json!("Infinity")), Ok(json!("Infinity")));
+/// ```
+#[macro_export]
+macro_rules! try_clamp {
+    ($p:expr $(,)*) => {
+        $crate::JsNumber::NaN.try_into_value($p)
+    };
+    ($p:expr, $a:expr $(,)*) => {
+        $crate::try_clamp($p, $a, $crate::lib::Value::Null, $crate::lib::Value::Null)
+    };
+    ($p:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::try_clamp($p, $a, $crate::lib::Value::Null, $b)
+    };
+    ($p:expr, $a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::try_clamp($p, $a, $b, $c)
+    };
+    ($p:expr, $a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::try_clamp($p, $a, $b, $c)
+    };
+}
@@ -0,0 +1,95 @@
+use std::cmp::Ordering;
+
+use crate::lib::Value;
+use crate::{to_finite, JsNumber};
+
+/// See lodash [inRange](https://lodash.com/docs/#inRange)
+///
+/// `start` and `end` are converted like [to_finite()](crate::to_finite()) and swapped if `start` is greater,
+/// `number` is converted like [to_number()](crate::to_number()).
+pub fn in_range(number: Value, start: Value, end: Value) -> bool {
+    let start = JsNumber::from(to_finite::<Value>(start));
+    let end = JsNumber::from(to_finite::<Value>(end));
+    let (start, end) = match start.js_cmp(&end) {
+        Some(Ordering::Greater) => (end, start),
+        _ => (start, end),
+    };
+    let number = JsNumber::from(number);
+    matches!(
+        number.js_cmp(&start),
+        Some(Ordering::Greater) | Some(Ordering::Equal)
+    ) && number.js_cmp(&end) == Some(Ordering::Less)
+}
+
+/// Based on [in_range()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   in_range!(json!(3), json!(2), json!(4)),
+///   true
+/// );
+/// assert_eq!(
+///   in_range!(json!(4), json!(8)),
+///   true
+/// );
+/// assert_eq!(
+///   in_range!(json!(4), json!(2)),
+///   false
+/// );
+/// assert_eq!(
+///   in_range!(json!(2), json!(2)),
+///   false
+/// );
+/// assert_eq!(
+///   in_range!(json!(1.2), json!(2)),
+///   true
+/// );
+/// assert_eq!(
+///   in_range!(json!(5.2), json!(4)),
+///   false
+/// );
+/// assert_eq!(
+///   in_range!(json!(-3), json!(-2), json!(-6)),
+///   true
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(in_range!(), false);
+/// assert_eq!(in_range!(json!(0)), false);
+/// assert_eq!(in_range!(json!(0), json!(1)), true);
+/// assert_eq!(in_range!(json!(-1), json!(-2)), true);
+/// assert_eq!(in_range!(json!("3"), json!("2"), json!("4")), true);
+/// assert_eq!(in_range!(json!(null), json!(1)), true);
+/// assert_eq!(in_range!(json!("a"), json!(1)), false); // NaN
+/// assert_eq!(in_range!(json!(1), json!("a"), json!(2)), true);
+/// assert_eq!(in_range!(json!(1e300), json!("Infinity")), true);
+/// assert_eq!(in_range!(json!(u64::MAX - 1), json!(u64::MAX)), true);
+/// assert_eq!(in_range!(json!(u64::MAX), json!(u64::MAX)), false);
+/// ```
+#[macro_export]
+macro_rules! in_range {
+    () => {
+        false
+    };
+    ($a:expr $(,)*) => {
+        $crate::in_range($a, $crate::lib::Value::Null, $crate::lib::Value::Null)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::in_range($a, $crate::lib::Value::Null, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::in_range($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::in_range($a, $b, $c)
+    };
+}
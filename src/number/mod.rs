@@ -1,14 +1,6 @@
-#[doc(hidden)]
-pub fn clamp() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn in_range() {
-    todo!()
-}
-#[doc(hidden)]
-pub use in_range as inRange;
-#[doc(hidden)]
-pub fn random() {
-    todo!()
-}
+mod clamp;
+pub use clamp::*;
+mod in_range;
+pub use in_range::*;
+mod random;
+pub use random::*;
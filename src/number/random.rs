@@ -0,0 +1,208 @@
+use std::cell::RefCell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use crate::lib::Value;
+use crate::internal::f64_to_value;
+use crate::to_finite;
+
+/// Where [random_with()] gets its randomness from
+///
+/// Any `FnMut() -> f64` closure is one, which makes the result easy to pin down in tests.
+pub trait RandomSource {
+    /// A number in `0.0..1.0`, like js `Math.random()`
+    fn next_f64(&mut self) -> f64;
+}
+impl<F: FnMut() -> f64> RandomSource for F {
+    fn next_f64(&mut self) -> f64 {
+        self()
+    }
+}
+
+/// A seedable [RandomSource] (SplitMix64), the same seed always gives the same numbers
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json_lodash::{RandomSource, SeededRandom};
+/// let (mut a, mut b) = (SeededRandom::new(42), SeededRandom::new(42));
+/// assert_eq!(a.next_f64(), b.next_f64());
+/// assert!((0.0..1.0).contains(&a.next_f64()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeededRandom {
+    state: u64,
+}
+impl SeededRandom {
+    ///
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom { state: seed }
+    }
+    /// Seeded from the random keys std gives to each `HashMap`
+    pub fn from_entropy() -> SeededRandom {
+        SeededRandom::new(RandomState::new().build_hasher().finish())
+    }
+}
+impl RandomSource for SeededRandom {
+    fn next_f64(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        // the top 53 bits, which is all the precision f64 has
+        (z >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+thread_local! {
+    static THREAD_RANDOM: RefCell<SeededRandom> = RefCell::new(SeededRandom::from_entropy());
+}
+
+/// Same as [random()], but gets its randomness from `source`
+pub fn random_with<R: RandomSource + ?Sized>(
+    source: &mut R,
+    lower: Value,
+    upper: Value,
+    floating: bool,
+) -> Value {
+    let (mut lower, mut upper, mut floating) = (lower, upper, floating);
+    if !floating {
+        if let Value::Bool(b) = upper {
+            floating = b;
+            upper = Value::Null;
+        } else if let Value::Bool(b) = lower {
+            floating = b;
+            lower = Value::Null;
+        }
+    }
+    let (lower, upper) = match (lower, upper) {
+        (Value::Null, Value::Null) => (0.0, 1.0),
+        (lower, Value::Null) => (0.0, to_finite(lower)),
+        (lower, upper) => (to_finite(lower), to_finite(upper)),
+    };
+    let (lower, upper) = if lower > upper {
+        (upper, lower)
+    } else {
+        (lower, upper)
+    };
+    let rand = source.next_f64();
+    if floating || lower.fract() != 0.0 || upper.fract() != 0.0 {
+        // like lodash, widen the range by the last digit of `rand`, so that `upper` could be reached
+        let widen = format!("1e-{}", rand.to_string().len() - 1)
+            .parse::<f64>()
+            .unwrap();
+        f64_to_value((lower + rand * (upper - lower + widen)).min(upper))
+    } else {
+        f64_to_value(lower + (rand * (upper - lower + 1.0)).floor())
+    }
+}
+/// See lodash [random](https://lodash.com/docs/#random)
+///
+/// `null` bounds are `undefined`, and like lodash, a boolean `upper` or `lower` is taken as `floating`.
+/// The result is a float if `floating` is `true` or if either bound isn't an integer.
+///
+/// The randomness comes from a [SeededRandom] per thread, use [random_with()] to choose it.
+pub fn random(lower: Value, upper: Value, floating: bool) -> Value {
+    THREAD_RANDOM.with(|source| random_with(&mut *source.borrow_mut(), lower, upper, floating))
+}
+
+/// Based on [random_with()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// use serde_json_lodash::SeededRandom;
+/// let mut source = SeededRandom::new(42);
+/// let n = random_with!(&mut source, json!(0), json!(5));
+/// assert_eq!(n, random_with!(&mut SeededRandom::new(42), json!(0), json!(5)));
+/// assert_eq!(random_with!(&mut || 0.5, json!(0), json!(5)), json!(3));
+/// assert_eq!(random_with!(&mut || 0.5, json!(5), json!(true)), json!(2.505));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(random_with!(&mut || 0.0), json!(0));
+/// assert_eq!(random_with!(&mut || 0.99), json!(1));
+/// assert_eq!(random_with!(&mut || 0.99, json!(5)), json!(5));
+/// assert_eq!(random_with!(&mut || 0.0, json!(-5)), json!(-5));
+/// assert_eq!(random_with!(&mut || 0.5, json!(5), json!(0)), json!(3));
+/// assert_eq!(random_with!(&mut || 0.5, json!("2"), json!("4")), json!(3));
+/// assert_eq!(random_with!(&mut || 0.5, json!(true)), json!(0.505));
+/// assert_eq!(random_with!(&mut || 0.5, json!(0), json!(5), true), json!(2.505));
+/// assert_eq!(random_with!(&mut || 0.5, json!(1.2), json!(5.2)), json!(3.205));
+/// ```
+#[macro_export]
+macro_rules! random_with {
+    ($s:expr $(,)*) => {
+        $crate::random_with(
+            $s,
+            $crate::lib::Value::Null,
+            $crate::lib::Value::Null,
+            false,
+        )
+    };
+    ($s:expr, $a:expr $(,)*) => {
+        $crate::random_with($s, $a, $crate::lib::Value::Null, false)
+    };
+    ($s:expr, $a:expr, $b:expr $(,)*) => {
+        $crate::random_with($s, $a, $b, false)
+    };
+    ($s:expr, $a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::random_with($s, $a, $b, $c)
+    };
+    ($s:expr, $a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::random_with($s, $a, $b, $c)
+    };
+}
+/// Based on [random()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// let n = random!(json!(0), json!(5)).as_u64().unwrap();
+/// assert!(n <= 5); // an integer between 0 and 5
+/// let n = random!(json!(5)).as_u64().unwrap();
+/// assert!(n <= 5); // also an integer between 0 and 5
+/// let n = random!(json!(5), json!(true)).as_f64().unwrap();
+/// assert!((0.0..=5.0).contains(&n)); // a floating-point number between 0 and 5
+/// let n = random!(json!(1.2), json!(5.2)).as_f64().unwrap();
+/// assert!((1.2..=5.2).contains(&n)); // a floating-point number between 1.2 and 5.2
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// for _ in 0..100 {
+///   assert!(random!().as_u64().unwrap() <= 1);
+///   assert!(random!(json!(-5), json!(-3)).as_i64().map_or(false, |n| (-5..=-3).contains(&n)));
+///   assert_eq!(random!(json!(3), json!(3)), json!(3));
+/// }
+/// ```
+#[macro_export]
+macro_rules! random {
+    () => {
+        $crate::random($crate::lib::Value::Null, $crate::lib::Value::Null, false)
+    };
+    ($a:expr $(,)*) => {
+        $crate::random($a, $crate::lib::Value::Null, false)
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::random($a, $b, false)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)*) => {
+        $crate::random($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
+        $crate::random($a, $b, $c)
+    };
+}
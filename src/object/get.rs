@@ -1,9 +1,9 @@
 use crate::lib::{json, Value};
-use crate::to_path_x;
+use crate::internal::value_to_path;
 
 /// See lodash [get](https://lodash.com/docs/#get)
 pub fn get(object: Value, path: Value, default: Value) -> Value {
-    let p_vec = to_path_x(path);
+    let p_vec = value_to_path(path);
     if p_vec.is_empty() {
         return default;
     }
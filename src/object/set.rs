@@ -1,15 +1,13 @@
 use crate::lib::{json, Value};
-use crate::internal::value_undefined;
-use crate::to_path_x;
+use crate::internal::{value_to_path, value_undefined};
 
 /// See lodash [set](https://lodash.com/docs/#set)
 pub fn set(mut object: Value, path: Value, value: Value) -> Value {
-    let mut p_vec = to_path_x(path);
+    let mut p_vec = value_to_path(path);
     if p_vec.is_empty() {
         return object;
     }
     let last = p_vec.pop();
-    let i_max = p_vec.len() - 1;
     let mut cur = &mut object;
     for (ii, k) in p_vec.iter().enumerate() {
         match cur {
@@ -28,7 +26,8 @@ pub fn set(mut object: Value, path: Value, value: Value) -> Value {
             },
             Value::Object(map) => {
                 if !map.contains_key(k) {
-                    let v = if ii < i_max && p_vec[ii + 1].parse::<usize>().is_ok() {
+                    let next = p_vec.get(ii + 1).or(last.as_ref());
+                    let v = if next.is_some_and(|k| k.parse::<usize>().is_ok()) {
                         json!([])
                     } else {
                         json!({})
@@ -130,6 +129,9 @@ pub fn set(mut object: Value, path: Value, value: Value) -> Value {
 ///   set!(json!({}), json!("1[1].a"), json!(5)),
 ///   json!({"1":[null,{"a":5}]})
 /// );
+/// assert_eq!(set!(json!({"a": 1}), json!("a"), json!(2)), json!({"a": 2}));
+/// assert_eq!(set!(json!([1]), json!("2"), json!(3)), json!([1, null, 3]));
+/// assert_eq!(set!(json!({}), json!("a[1]"), json!(1)), json!({"a": [null, 1]}));
 /// ```
 #[macro_export]
 macro_rules! set {
@@ -0,0 +1,476 @@
+use std::fmt;
+
+use crate::lib::Value;
+use crate::Iteratee;
+
+type Op<'a> = Box<dyn FnOnce(Value) -> Value + 'a>;
+
+/// The wrapper of [chain()], its methods are the functions of this crate, taking the wrapped value as first argument
+///
+/// Like lodash, the chain is lazy: nothing runs until [Chain::value()] or [Chain::commit()].
+/// Results which aren't a `Value`, like the `isize` of [index_of()](crate::index_of()), are wrapped as a `Value`.
+pub struct Chain<'a> {
+    value: Value,
+    ops: Vec<Op<'a>>,
+}
+impl<'a> Chain<'a> {
+    /// See [tap()], `interceptor` can change the value in the middle of the chain
+    pub fn tap<F: FnOnce(&mut Value) + 'a>(self, interceptor: F) -> Chain<'a> {
+        self.thru(move |value| tap(value, interceptor))
+    }
+    /// See [thru()], `interceptor` replaces the value in the middle of the chain
+    pub fn thru<F: FnOnce(Value) -> Value + 'a>(mut self, interceptor: F) -> Chain<'a> {
+        self.ops.push(Box::new(interceptor));
+        self
+    }
+    /// Runs the chain, and gives a chain of the result, like lodash `prototype.commit`
+    pub fn commit(self) -> Chain<'a> {
+        chain(self.value())
+    }
+    /// Runs the chain, and unwraps the result, like lodash `prototype.value`
+    pub fn value(self) -> Value {
+        self.ops.into_iter().fold(self.value, |value, op| op(value))
+    }
+    /// See [get()](crate::get()), with `undefined` as default, use [Chain::thru()] for another one
+    pub fn get(self, path: impl Into<Value> + 'a) -> Chain<'a> {
+        self.thru(move |value| crate::get(value, path.into(), Value::Null))
+    }
+    /// See [difference_by()](crate::difference_by())
+    pub fn difference_by<I: Iteratee + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::difference_by(value, values.into(), iteratee))
+    }
+    /// See [difference_with()](crate::difference_with())
+    pub fn difference_with<C: Fn(&Value, &Value) -> bool + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        comparator: C,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::difference_with(value, values.into(), comparator))
+    }
+    /// See [drop_right_while()](crate::drop_right_while())
+    pub fn drop_right_while<I: Iteratee + 'a>(self, predicate: I) -> Chain<'a> {
+        self.thru(move |value| crate::drop_right_while(value, predicate))
+    }
+    /// See [drop_while()](crate::drop_while())
+    pub fn drop_while<I: Iteratee + 'a>(self, predicate: I) -> Chain<'a> {
+        self.thru(move |value| crate::drop_while(value, predicate))
+    }
+    /// See [find_index()](crate::find_index())
+    pub fn find_index(self, predicate: fn(&Value) -> bool, from_index: usize) -> Chain<'a> {
+        self.thru(move |value| Value::from(crate::find_index(value, predicate, from_index)))
+    }
+    /// See [find_last_index()](crate::find_last_index())
+    pub fn find_last_index(self, predicate: fn(&Value) -> bool, from_index: usize) -> Chain<'a> {
+        self.thru(move |value| Value::from(crate::find_last_index(value, predicate, from_index)))
+    }
+    /// See [intersection_by()](crate::intersection_by())
+    pub fn intersection_by<I: Iteratee + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::intersection_by(value, values.into(), iteratee))
+    }
+    /// See [intersection_with()](crate::intersection_with())
+    pub fn intersection_with<C: Fn(&Value, &Value) -> bool + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        comparator: C,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::intersection_with(value, values.into(), comparator))
+    }
+    /// See [pull_all_by()](crate::pull_all_by())
+    pub fn pull_all_by<I: Iteratee + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::pull_all_by(value, values.into(), iteratee))
+    }
+    /// See [pull_all_with()](crate::pull_all_with())
+    pub fn pull_all_with<C: Fn(&Value, &Value) -> bool + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        comparator: C,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::pull_all_with(value, values.into(), comparator))
+    }
+    /// See [remove()](crate::remove()), the chain goes on with the removed items like lodash
+    pub fn remove<I: Iteratee + 'a>(self, predicate: I) -> Chain<'a> {
+        self.thru(move |mut value| crate::remove(&mut value, predicate))
+    }
+    /// See [reverse()](crate::reverse())
+    pub fn reverse(self) -> Chain<'a> {
+        self.thru(|mut value| {
+            crate::reverse(&mut value);
+            value
+        })
+    }
+    /// See [sorted_index_by()](crate::sorted_index_by())
+    pub fn sorted_index_by<I: Iteratee + 'a>(
+        self,
+        value: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |array| Value::from(crate::sorted_index_by(array, value.into(), iteratee)))
+    }
+    /// See [sorted_last_index_by()](crate::sorted_last_index_by())
+    pub fn sorted_last_index_by<I: Iteratee + 'a>(
+        self,
+        value: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |array| {
+            Value::from(crate::sorted_last_index_by(array, value.into(), iteratee))
+        })
+    }
+    /// See [sorted_uniq_by()](crate::sorted_uniq_by())
+    pub fn sorted_uniq_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::sorted_uniq_by(value, iteratee))
+    }
+    /// See [take_right_while()](crate::take_right_while())
+    pub fn take_right_while<I: Iteratee + 'a>(self, predicate: I) -> Chain<'a> {
+        self.thru(move |value| crate::take_right_while(value, predicate))
+    }
+    /// See [take_while()](crate::take_while())
+    pub fn take_while<I: Iteratee + 'a>(self, predicate: I) -> Chain<'a> {
+        self.thru(move |value| crate::take_while(value, predicate))
+    }
+    /// See [union_by()](crate::union_by())
+    pub fn union_by<I: Iteratee + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        iteratee: I,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::union_by(value, values.into(), iteratee))
+    }
+    /// See [union_with()](crate::union_with())
+    pub fn union_with<C: Fn(&Value, &Value) -> bool + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        comparator: C,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::union_with(value, values.into(), comparator))
+    }
+    /// See [uniq_by()](crate::uniq_by())
+    pub fn uniq_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::uniq_by(value, iteratee))
+    }
+    /// See [uniq_with()](crate::uniq_with())
+    pub fn uniq_with<C: Fn(&Value, &Value) -> bool + 'a>(self, comparator: C) -> Chain<'a> {
+        self.thru(move |value| crate::uniq_with(value, comparator))
+    }
+    /// See [unzip_with()](crate::unzip_with())
+    pub fn unzip_with<F: Fn(&[Value]) -> Value + 'a>(self, iteratee: F) -> Chain<'a> {
+        self.thru(move |value| crate::unzip_with(value, iteratee))
+    }
+    /// See [xor_by()](crate::xor_by())
+    pub fn xor_by<I: Iteratee + 'a>(self, values: impl Into<Value> + 'a, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::xor_by(value, values.into(), iteratee))
+    }
+    /// See [xor_with()](crate::xor_with())
+    pub fn xor_with<C: Fn(&Value, &Value) -> bool + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        comparator: C,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::xor_with(value, values.into(), comparator))
+    }
+    /// See [zip_with()](crate::zip_with())
+    pub fn zip_with<F: Fn(&[Value]) -> Value + 'a>(
+        self,
+        values: impl Into<Value> + 'a,
+        iteratee: F,
+    ) -> Chain<'a> {
+        self.thru(move |value| crate::zip_with(value, values.into(), iteratee))
+    }
+    /// See [max_by()](crate::max_by())
+    pub fn max_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::max_by(value, iteratee))
+    }
+    /// See [mean_by()](crate::mean_by())
+    pub fn mean_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::mean_by(value, iteratee))
+    }
+    /// See [min_by()](crate::min_by())
+    pub fn min_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::min_by(value, iteratee))
+    }
+    /// See [sum_by()](crate::sum_by())
+    pub fn sum_by<I: Iteratee + 'a>(self, iteratee: I) -> Chain<'a> {
+        self.thru(move |value| crate::sum_by(value, iteratee))
+    }
+}
+impl fmt::Debug for Chain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chain")
+            .field("value", &self.value)
+            .field("pending", &self.ops.len())
+            .finish()
+    }
+}
+
+// Arguments before `;` are `Value`s, taken as `impl Into<Value>` so that `.add(1)` works as well as `.add(json!(1))`,
+// the others keep their type. The names are those of the crate, even when they are those of std traits.
+// Generic functions name their result type, like `to_number::<Value>()`.
+macro_rules! chain_methods {
+    ($($name:ident $(::<$r:ty>)?($($value:ident),* $(; $($arg:ident: $ty:ty),*)?);)*) => {
+        #[allow(clippy::should_implement_trait)]
+        impl<'a> Chain<'a> {
+            $(
+                #[doc = concat!("See [", stringify!($name), "()](crate::", stringify!($name), "())")]
+                pub fn $name(
+                    self,
+                    $($value: impl Into<Value> + 'a,)*
+                    $($($arg: $ty),*)?
+                ) -> Chain<'a> {
+                    self.thru(move |wrapped| {
+                        Value::from(crate::$name $(::<$r>)?(wrapped, $($value.into(),)* $($($arg),*)?))
+                    })
+                }
+            )*
+        }
+    };
+}
+
+chain_methods! {
+    chunk(; size: usize);
+    compact();
+    concat(values);
+    difference(values);
+    drop(; n: isize);
+    drop_right(; n: isize);
+    fill(value; start: isize, end: isize);
+    flatten::<Value>();
+    flatten_deep::<Value>();
+    flatten_depth::<Value>(; depth: usize);
+    from_pairs();
+    head();
+    index_of(value; from_index: usize);
+    initial();
+    intersection::<Value>(values);
+    join::<Value>(; separator: &'a str);
+    last();
+    last_index_of(value; from_index: usize);
+    nth(; n: isize);
+    pull(value);
+    pull_all(values);
+    slice(; start: isize, end: isize);
+    sorted_index(value);
+    sorted_index_of(value);
+    sorted_last_index(value);
+    sorted_last_index_of(value);
+    sorted_uniq();
+    tail();
+    take(; n: isize);
+    take_right(; n: isize);
+    union(values);
+    uniq();
+    unzip::<Value>();
+    without(values);
+    xor(values);
+    zip(values);
+    zip_object(values);
+    zip_object_deep(values);
+
+    is_safe_integer();
+    to_finite::<Value>();
+    to_integer::<Value>();
+    to_length::<Value>();
+    to_number::<Value>();
+    to_safe_integer::<Value>();
+    to_string::<Value>();
+
+    add::<Value>(addend);
+    ceil::<Value>(; precision: isize);
+    divide::<Value>(divisor);
+    floor::<Value>(; precision: isize);
+    max();
+    mean();
+    min();
+    multiply::<Value>(multiplicand);
+    round::<Value>(; precision: isize);
+    subtract::<Value>(subtrahend);
+    sum();
+
+    clamp(lower, upper);
+    in_range(start, end);
+
+    merge(source);
+    set(path, value);
+
+    capitalize::<Value>();
+    to_lower::<Value>();
+
+    to_path::<Value>();
+}
+
+/// See lodash [chain](https://lodash.com/docs/#chain)
+pub fn chain<'a>(value: Value) -> Chain<'a> {
+    Chain { value, ops: vec![] }
+}
+/// See lodash [tap](https://lodash.com/docs/#tap)
+pub fn tap<F: FnOnce(&mut Value)>(mut value: Value, interceptor: F) -> Value {
+    interceptor(&mut value);
+    value
+}
+/// See lodash [thru](https://lodash.com/docs/#thru)
+pub fn thru<F: FnOnce(Value) -> Value>(value: Value, interceptor: F) -> Value {
+    interceptor(value)
+}
+
+/// Based on [chain()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let users = json!([
+///   { "user": "barney",  "age": 36 },
+///   { "user": "fred",    "age": 40 },
+///   { "user": "pebbles", "age": 1 }
+/// ]);
+/// // `sortBy` and `map` are not implemented
+/// let youngest = chain!(users)
+///   .min_by("age")
+///   .thru(|o| json!(format!("{} is {}", o["user"].as_str().unwrap(), o["age"])))
+///   .value();
+/// assert_eq!(youngest, json!("pebbles is 1"));
+///
+/// // `prototype.commit`
+/// let mut logs = vec![];
+/// let wrapped = chain!(json!([1, 2])).concat(json!([3])).tap(|array| logs.push(array.clone()));
+/// let wrapped = wrapped.commit();
+/// assert_eq!(wrapped.last().value(), json!(3));
+/// assert_eq!(logs, vec![json!([1, 2, 3])]);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(chain!().value(), json!(null));
+/// let v = json!({"items": [[0, 1], null, [2, false], [3]]});
+/// assert_eq!(chain!(v.clone()).get("items").compact().flatten().compact().chunk(2).value(), json!([[1, 2], [3]]));
+/// assert_eq!(chain!(v.clone()).get("items[3][0]").add(1).value(), json!(4));
+/// assert_eq!(chain!(v).get("items").index_of(json!(null), 0).value(), json!(1));
+/// assert_eq!(chain!(json!([1.25, 2.5])).sum().round(1).clamp(0, 3).in_range(0, 3).value(), json!(false));
+/// assert_eq!(chain!(json!(["a", "b"])).join("-").capitalize().value(), json!("A-b"));
+/// assert_eq!(chain!(json!([{"n": 1}, {"n": 3}])).sum_by("n").value(), json!(4));
+/// assert_eq!(chain!(json!([2.1, 1.2, 2.3])).uniq_by(|v: &Value| json!(v.as_f64().unwrap().floor())).uniq().value(), json!([2.1, 1.2]));
+/// assert_eq!(chain!(json!([1, 1, 2])).sorted_uniq().value(), json!([1, 2]));
+/// assert_eq!(chain!(json!([2])).union(json!([1, 2])).xor(json!([1, 3])).value(), json!([2, 3]));
+/// assert_eq!(chain!(json!([{"x": 1}])).union_by(json!([{"x": 2}, {"x": 1}]), "x").xor_by(json!([{"x": 1}]), "x").value(), json!([{"x": 2}]));
+/// assert_eq!(chain!(json!([{"x": 1}, {"x": 2}, {"x": 3}])).difference_by(json!([{"x": 1}]), "x").intersection_with(json!([{"x": 3}]), |a: &Value, b: &Value| a == b).value(), json!([{"x": 3}]));
+/// assert_eq!(chain!(json!(["a", "b"])).zip(json!([1, 2])).unzip().value(), json!([["a", "b"], [1, 2]]));
+/// assert_eq!(chain!(json!(["a.b", "c"])).zip_object_deep(json!([1, 2])).value(), json!({"a": {"b": 1}, "c": 2}));
+/// assert_eq!(chain!(json!([1, 2])).zip_with(json!([10, 20]), |group: &[Value]| json!(group.len())).value(), json!([2, 2]));
+/// assert_eq!(chain!(json!([4, 5, 5, 5, 6])).sorted_last_index(5).value(), json!(4));
+/// assert_eq!(chain!(json!([{"x": 4}, {"x": 5}])).sorted_index_by(json!({"x": 4}), "x").value(), json!(0));
+/// assert_eq!(chain!(json!([1, 2, 3, 4])).drop_while(|v: &Value| json!(v.as_i64() < Some(2))).take_right_while(|v: &Value| json!(v.as_i64() > Some(2))).value(), json!([3, 4]));
+/// assert_eq!(chain!(json!([1, 2, 3, 4])).remove(|v: &Value| json!(v.as_i64().unwrap() % 2 == 0)).reverse().value(), json!([4, 2]));
+/// let mut calls = 0;
+/// let lazy = chain!(json!(1)).tap(|_| calls += 1).multiply(3);
+/// assert!(format!("{:?}", lazy).contains("pending: 2"));
+/// assert_eq!(lazy.value(), json!(3));
+/// assert_eq!(calls, 1);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    () => {
+        $crate::chain($crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::chain($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::chain($a)
+    };
+}
+/// Based on [tap()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// assert_eq!(
+///   chain!(json!([1, 2, 3]))
+///     .tap(|array| { array.as_array_mut().unwrap().pop(); })
+///     .reverse()
+///     .value(),
+///   json!([2, 1])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(tap!(), json!(null));
+/// assert_eq!(tap!(json!(1)), json!(1));
+/// let mut seen = json!(null);
+/// assert_eq!(tap!(json!([1]), |v| seen = v.clone()), json!([1]));
+/// assert_eq!(seen, json!([1]));
+/// assert_eq!(tap!(json!({"a": 1}), |v| v["a"] = json!(2)), json!({"a": 2}));
+/// ```
+#[macro_export]
+macro_rules! tap {
+    () => {
+        $crate::lib::Value::Null
+    };
+    ($a:expr $(,)*) => {
+        $a
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::tap($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::tap($a, $b)
+    };
+}
+/// Based on [thru()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::json;
+/// // `trim` is not implemented
+/// assert_eq!(
+///   chain!(json!("ABC")).to_lower().thru(|value| json!([value])).value(),
+///   json!(["abc"])
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// assert_eq!(thru!(), json!(null));
+/// assert_eq!(thru!(json!(1)), json!(1));
+/// assert_eq!(thru!(json!(1), |v| json!([v])), json!([1]));
+/// ```
+#[macro_export]
+macro_rules! thru {
+    () => {
+        $crate::lib::Value::Null
+    };
+    ($a:expr $(,)*) => {
+        $a
+    };
+    ($a:expr, $b:expr $(,)*) => {
+        $crate::thru($a, $b)
+    };
+    ($a:expr, $b:expr, $($rest:tt)*) => {
+        $crate::thru($a, $b)
+    };
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use crate::lib::Value;
+use crate::internal::value_is_truthy;
+use crate::{chain, Chain, Iteratee};
+
+/// The lazy sequence of [lazy()], like lodash `LazyWrapper`
+///
+/// Its steps are fused into a single pass over the items, without any intermediate array,
+/// and [Lazy::take()], [Lazy::find()] and [Lazy::head()] stop pulling items once they have enough.
+pub struct Lazy<'a> {
+    iter: Box<dyn Iterator<Item = Value> + 'a>,
+}
+impl<'a> Lazy<'a> {
+    fn then<I: Iterator<Item = Value> + 'a>(iter: I) -> Lazy<'a> {
+        Lazy {
+            iter: Box::new(iter),
+        }
+    }
+    /// See lodash [map](https://lodash.com/docs/#map)
+    pub fn map<I: Iteratee + 'a>(self, iteratee: I) -> Lazy<'a> {
+        Lazy::then(self.iter.map(move |v| iteratee.call(&v)))
+    }
+    /// See lodash [filter](https://lodash.com/docs/#filter), an item matches when the computed value is truthy
+    pub fn filter<I: Iteratee + 'a>(self, predicate: I) -> Lazy<'a> {
+        Lazy::then(
+            self.iter
+                .filter(move |v| value_is_truthy(&predicate.call(v))),
+        )
+    }
+    /// See [compact()](crate::compact())
+    pub fn compact(self) -> Lazy<'a> {
+        Lazy::then(self.iter.filter(value_is_truthy))
+    }
+    /// See [take()](crate::take())
+    pub fn take(self, n: isize) -> Lazy<'a> {
+        Lazy::then(self.iter.take(n.max(0) as usize))
+    }
+    /// See [drop()](crate::drop())
+    pub fn drop(self, n: isize) -> Lazy<'a> {
+        Lazy::then(self.iter.skip(n.max(0) as usize))
+    }
+    /// See lodash [find](https://lodash.com/docs/#find), `null` when no item matches
+    pub fn find<I: Iteratee>(mut self, predicate: I) -> Value {
+        self.iter
+            .find(|v| value_is_truthy(&predicate.call(v)))
+            .unwrap_or(Value::Null)
+    }
+    /// See [head()](crate::head())
+    pub fn head(mut self) -> Value {
+        self.iter.next().unwrap_or(Value::Null)
+    }
+    /// Runs the sequence, and collects its items into an array
+    pub fn value(self) -> Value {
+        Value::Array(self.iter.collect())
+    }
+    /// Runs the sequence, and gives a [Chain] of its array
+    pub fn chain(self) -> Chain<'a> {
+        chain(self.value())
+    }
+}
+impl<'a> IntoIterator for Lazy<'a> {
+    type Item = Value;
+    type IntoIter = Box<dyn Iterator<Item = Value> + 'a>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+}
+impl fmt::Debug for Lazy<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy").finish_non_exhaustive()
+    }
+}
+impl<'a> Chain<'a> {
+    /// Runs the chain, and gives a [Lazy] sequence of the items of the result
+    pub fn lazy(self) -> Lazy<'a> {
+        lazy(self.value())
+    }
+}
+
+/// A lazy sequence of the items of an array, or of the characters of a string, other values have no item
+pub fn lazy<'a>(value: Value) -> Lazy<'a> {
+    match value {
+        Value::Array(vec) => Lazy::then(vec.into_iter()),
+        Value::String(s) => Lazy::then(
+            s.chars()
+                .map(|c| Value::String(c.to_string()))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+        _ => Lazy::then(std::iter::empty()),
+    }
+}
+
+/// Based on [lazy()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let array = Value::Array((0..1_000_000).map(|i| json!(i)).collect());
+/// let seen = std::cell::Cell::new(0);
+/// let result = lazy!(array)
+///   .filter(|v: &Value| { seen.set(seen.get() + 1); json!(v.as_u64().unwrap() % 3 == 0) })
+///   .map(|v: &Value| multiply!(v.clone(), json!(2)))
+///   .drop(1)
+///   .take(3)
+///   .value();
+/// assert_eq!(result, json!([6, 12, 18]));
+/// // only the items needed for the result are pulled
+/// assert_eq!(seen.get(), 10);
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(lazy!().value(), json!([]));
+/// assert_eq!(lazy!(json!({"a": 1})).value(), json!([]));
+/// assert_eq!(lazy!(json!("ab")).value(), json!(["a", "b"]));
+/// let users = json!([
+///   { "user": "barney",  "age": 36, "active": true },
+///   { "user": "fred",    "age": 40, "active": false },
+///   { "user": "pebbles", "age": 1,  "active": true }
+/// ]);
+/// assert_eq!(lazy!(users.clone()).map("user").value(), json!(["barney", "fred", "pebbles"]));
+/// assert_eq!(lazy!(users.clone()).map("active").compact().value(), json!([true, true]));
+/// assert_eq!(lazy!(users.clone()).find(|u: &Value| json!(u["age"].as_u64() < Some(40)))["user"], json!("barney"));
+/// assert_eq!(lazy!(users.clone()).find(|u: &Value| json!(u["age"].as_u64() > Some(40))), json!(null));
+/// // The `_.property` iteratee shorthand.
+/// assert_eq!(lazy!(users.clone()).filter("active").map("user").value(), json!(["barney", "pebbles"]));
+/// assert_eq!(lazy!(users.clone()).find("active")["user"], json!("barney"));
+/// assert_eq!(lazy!(users.clone()).find("missing"), json!(null));
+/// assert_eq!(lazy!(users.clone()).take(-1).value(), json!([]));
+/// assert_eq!(lazy!(users.clone()).drop(-1).value(), users);
+/// assert_eq!(lazy!(users.clone()).drop(2).head()["user"], json!("pebbles"));
+/// assert_eq!(lazy!(json!([])).head(), json!(null));
+/// assert_eq!(lazy!(json!([0, 1, "", 2])).compact().chain().sum().value(), json!(3));
+/// assert_eq!(chain!(json!([[1], [2, 3]])).flatten().lazy().take(2).value(), json!([1, 2]));
+/// assert_eq!(lazy!(json!([1, 2])).into_iter().collect::<Vec<Value>>(), vec![json!(1), json!(2)]);
+/// ```
+#[macro_export]
+macro_rules! lazy {
+    () => {
+        $crate::lazy($crate::lib::Value::Null)
+    };
+    ($a:expr $(,)*) => {
+        $crate::lazy($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::lazy($a)
+    };
+}
@@ -1,20 +1,16 @@
+mod chain;
+pub use chain::*;
+mod lazy;
+pub use lazy::*;
 // #[doc(hidden)]
 // pub fn _() { todo!() }
 // #[doc(hidden)]
-// pub fn chain() { todo!() }
-// #[doc(hidden)]
-// pub fn tap() { todo!() }
-// #[doc(hidden)]
-// pub fn thru() { todo!() }
-// #[doc(hidden)]
 // pub fn prototype[Symbol.iterator]() { todo!() }
 // #[doc(hidden)]
 // pub fn prototype.at() { todo!() }
 // #[doc(hidden)]
 // pub fn prototype.chain() { todo!() }
 // #[doc(hidden)]
-// pub fn prototype.commit() { todo!() }
-// #[doc(hidden)]
 // pub fn prototype.next() { todo!() }
 // #[doc(hidden)]
 // pub fn prototype.plant() { todo!() }
@@ -23,6 +19,4 @@
 // #[doc(hidden)]
 // pub fn prototype.toJSON -> value() { todo!() }
 // #[doc(hidden)]
-// pub fn prototype.value() { todo!() }
-// #[doc(hidden)]
 // pub fn prototype.valueOf -> value() { todo!() }
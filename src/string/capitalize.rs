@@ -1,24 +1,18 @@
-use crate::lib::{json, Value};
+use crate::lib::Value;
 use crate::internal::{type_name};
+use crate::{to_lower, FromValue, IntoValue};
 
-///
-pub fn x_capitalize_x(s: &str) -> String {
-    if s.is_empty() {
-        s.into()
-    } else if s.len() == 1 {
-        s.to_uppercase()
-    } else {
-        let mut ss = s.chars().next().unwrap().to_uppercase().to_string();
-        ss.push_str(&s[1..].to_lowercase());
-        ss
+fn capitalize_str(s: &str) -> String {
+    match s.chars().next().map(char::len_utf8) {
+        Some(len) => {
+            let mut ss = s[..len].to_uppercase();
+            ss.push_str(&s[len..].to_lowercase());
+            ss
+        }
+        None => s.into(),
     }
 }
-///
-pub fn x_capitalize(s: &str) -> Value {
-    json!(x_capitalize_x(s))
-}
-///
-pub fn capitalize_x(v: Value) -> String {
+fn base_capitalize(v: Value) -> String {
     match v {
         Value::Null => "".into(),
         Value::Bool(b) => {
@@ -28,7 +22,7 @@ pub fn capitalize_x(v: Value) -> String {
                 "False".into()
             }
         }
-        Value::String(s) => x_capitalize_x(&s),
+        Value::String(s) => capitalize_str(&s),
         Value::Number(n) => n.to_string(),
         Value::Array(vec) => {
             let mut iter = vec.into_iter();
@@ -38,24 +32,52 @@ pub fn capitalize_x(v: Value) -> String {
                         if v.is_null() {
                             "Null".into()
                         } else {
-                            capitalize_x(v)
+                            base_capitalize(v)
                         }
                     };
                     for v in iter {
                         s.push(',');
-                        s.push_str(&crate::to_lower_x(v));
+                        s.push_str(&to_lower::<String>(v));
                     }
                     s
                 }
                 None => "".into(),
             }
         }
-        Value::Object(o) => x_capitalize_x(type_name(&o)),
+        Value::Object(o) => capitalize_str(type_name(&o)),
     }
 }
+///
+#[deprecated(note = "use `capitalize::<String>()`")]
+pub fn x_capitalize_x(s: &str) -> String {
+    capitalize(s)
+}
+///
+#[deprecated(note = "use `capitalize()`, it takes a `&str` too")]
+pub fn x_capitalize(s: &str) -> Value {
+    capitalize(s)
+}
+///
+#[deprecated(note = "use `capitalize::<String>()`")]
+pub fn capitalize_x(v: Value) -> String {
+    capitalize(v)
+}
 /// See lodash [capitalize](https://lodash.com/docs/#capitalize)
-pub fn capitalize(v: Value) -> Value {
-    Value::String(capitalize_x(v))
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `String` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::capitalize;
+/// assert_eq!(capitalize::<String>("FRED"), "Fred".to_owned());
+/// assert_eq!(capitalize::<Value>(json!("FRED")), json!("Fred"));
+/// assert_eq!(capitalize::<String>(String::from("fred")), "Fred".to_owned());
+/// assert_eq!(capitalize::<String>("éCOLE"), "École".to_owned());
+/// ```
+pub fn capitalize<R: FromValue>(string: impl IntoValue) -> R {
+    R::from_value(Value::String(base_capitalize(string.into_value())))
 }
 
 /// Based on [x_capitalize_x()]
@@ -78,43 +100,58 @@ pub fn capitalize(v: Value) -> Value {
 /// # use serde_json::json;
 /// assert_eq!(x_capitalize_x!(), "".to_owned());
 /// ```
+#[deprecated(note = "use `capitalize::<String>()`")]
 #[macro_export]
 macro_rules! x_capitalize_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::x_capitalize_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_capitalize_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_capitalize_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_capitalize_x($a);
+        result
+    }};
 }
 /// Based on [x_capitalize()]
+#[deprecated(note = "use `capitalize!()`, it takes a `&str` too")]
 #[macro_export]
 macro_rules! x_capitalize {
     () => {
         json!("")
     };
-    ($a:expr $(,)*) => {
-        $crate::x_capitalize($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_capitalize($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_capitalize($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_capitalize($a);
+        result
+    }};
 }
 /// Based on [capitalize_x()]
+#[deprecated(note = "use `capitalize::<String>()`")]
 #[macro_export]
 macro_rules! capitalize_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::capitalize_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::capitalize_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::capitalize_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::capitalize_x($a);
+        result
+    }};
 }
 /// Based on [capitalize()]
 ///
@@ -142,6 +179,9 @@ macro_rules! capitalize_x {
 /// assert_eq!(capitalize!(json!([])), json!(""));
 /// assert_eq!(capitalize!(json!([null,'A',{}])), json!("Null,a,serde_json::map::map<alloc::string::string, serde_json::value::value>"));
 /// assert_eq!(capitalize!(json!({})), json!("Serde_json::map::map<alloc::string::string, serde_json::value::value>"));
+/// assert_eq!(capitalize!(json!("ß")), json!("SS"));
+/// assert_eq!(capitalize!(json!("ñ")), json!("Ñ"));
+/// assert_eq!(capitalize!("fRED"), json!("Fred"));
 /// ```
 #[macro_export]
 macro_rules! capitalize {
@@ -149,9 +189,9 @@ macro_rules! capitalize {
         json!("")
     };
     ($a:expr $(,)*) => {
-        $crate::capitalize($a)
+        $crate::capitalize::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::capitalize($a)
+        $crate::capitalize::<$crate::lib::Value>($a)
     };
 }
@@ -1,16 +1,8 @@
-use crate::lib::{json, Value};
+use crate::lib::Value;
 use crate::internal::{type_name};
+use crate::{FromValue, IntoValue};
 
-///
-pub fn x_to_lower_x(s: &str) -> String {
-    s.to_lowercase()
-}
-///
-pub fn x_to_lower(s: &str) -> Value {
-    json!(x_to_lower_x(s))
-}
-///
-pub fn to_lower_x(v: Value) -> String {
+fn base_to_lower(v: Value) -> String {
     match v {
         Value::Null => "".into(),
         Value::Bool(b) => {
@@ -20,7 +12,7 @@ pub fn to_lower_x(v: Value) -> String {
                 "false".into()
             }
         }
-        Value::String(s) => x_to_lower_x(&s),
+        Value::String(s) => s.to_lowercase(),
         Value::Number(n) => n.to_string(),
         Value::Array(vec) => {
             let mut result = vec![];
@@ -28,17 +20,43 @@ pub fn to_lower_x(v: Value) -> String {
                 if item.is_null() {
                     result.push("null".into())
                 } else {
-                    result.push(to_lower_x(item));
+                    result.push(base_to_lower(item));
                 }
             }
             result.join(",")
         }
-        Value::Object(o) => x_to_lower_x(type_name(&o)),
+        Value::Object(o) => type_name(&o).to_lowercase(),
     }
 }
+///
+#[deprecated(note = "use `to_lower::<String>()`")]
+pub fn x_to_lower_x(s: &str) -> String {
+    to_lower(s)
+}
+///
+#[deprecated(note = "use `to_lower()`, it takes a `&str` too")]
+pub fn x_to_lower(s: &str) -> Value {
+    to_lower(s)
+}
+///
+#[deprecated(note = "use `to_lower::<String>()`")]
+pub fn to_lower_x(v: Value) -> String {
+    to_lower(v)
+}
 /// See lodash [toLower](https://lodash.com/docs/#toLower)
-pub fn to_lower(v: Value) -> Value {
-    Value::String(to_lower_x(v))
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `String` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_lower;
+/// assert_eq!(to_lower::<String>("--Foo-Bar--"), "--foo-bar--".to_owned());
+/// assert_eq!(to_lower::<Value>(json!("fooBar")), json!("foobar"));
+/// ```
+pub fn to_lower<R: FromValue>(string: impl IntoValue) -> R {
+    R::from_value(Value::String(base_to_lower(string.into_value())))
 }
 
 /// Based on [x_to_lower_x()]
@@ -69,43 +87,58 @@ pub fn to_lower(v: Value) -> Value {
 /// # use serde_json::json;
 /// assert_eq!(x_to_lower_x!(), "".to_owned());
 /// ```
+#[deprecated(note = "use `to_lower::<String>()`")]
 #[macro_export]
 macro_rules! x_to_lower_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::x_to_lower_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_to_lower_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_lower_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_lower_x($a);
+        result
+    }};
 }
 /// Based on [x_to_lower()]
+#[deprecated(note = "use `to_lower!()`, it takes a `&str` too")]
 #[macro_export]
 macro_rules! x_to_lower {
     () => {
         json!("")
     };
-    ($a:expr $(,)*) => {
-        $crate::x_to_lower($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_to_lower($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_lower($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_lower($a);
+        result
+    }};
 }
 /// Based on [to_lower_x()]
+#[deprecated(note = "use `to_lower::<String>()`")]
 #[macro_export]
 macro_rules! to_lower_x {
     () => {
         "".to_owned()
     };
-    ($a:expr $(,)*) => {
-        $crate::to_lower_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::to_lower_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_lower_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_lower_x($a);
+        result
+    }};
 }
 /// Based on [to_lower()]
 ///
@@ -141,6 +174,7 @@ macro_rules! to_lower_x {
 /// assert_eq!(to_lower!(json!([])), json!(""));
 /// assert_eq!(to_lower!(json!([null,'A',{}])), json!("null,a,serde_json::map::map<alloc::string::string, serde_json::value::value>"));
 /// assert_eq!(to_lower!(json!({})), json!("serde_json::map::map<alloc::string::string, serde_json::value::value>"));
+/// assert_eq!(to_lower!("fooBar"), json!("foobar"));
 /// ```
 #[macro_export]
 macro_rules! to_lower {
@@ -148,9 +182,9 @@ macro_rules! to_lower {
         json!("")
     };
     ($a:expr $(,)*) => {
-        $crate::to_lower($a)
+        $crate::to_lower::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::to_lower($a)
+        $crate::to_lower::<$crate::lib::Value>($a)
     };
 }
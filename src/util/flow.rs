@@ -0,0 +1,236 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::lib::Value;
+
+/// A named step of a [Flow]
+pub struct FlowStep {
+    name: String,
+    func: Box<dyn Fn(Value) -> Value>,
+}
+impl FlowStep {
+    ///
+    pub fn new<F: Fn(Value) -> Value + 'static>(name: &str, func: F) -> FlowStep {
+        FlowStep {
+            name: name.to_owned(),
+            func: Box::new(func),
+        }
+    }
+    ///
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+impl fmt::Debug for FlowStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FlowStep").field(&self.name).finish()
+    }
+}
+
+/// The value a step of a [Flow] returned, from [Flow::trace()]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowTrace {
+    ///
+    pub step: String,
+    ///
+    pub output: Value,
+}
+
+/// The step of a [Flow] which panicked, from [Flow::try_call()]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowError {
+    /// The name of the step
+    pub step: String,
+    /// Its position in the flow
+    pub index: usize,
+    /// The value it was called with
+    pub input: Value,
+    /// The panic message
+    pub message: String,
+}
+impl fmt::Display for FlowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "step {} `{}` failed on {}: {}",
+            self.index, self.step, self.input, self.message
+        )
+    }
+}
+impl std::error::Error for FlowError {}
+
+/// A pipeline of `Value -> Value` functions, each one called with the result of the previous one
+#[derive(Debug, Default)]
+pub struct Flow {
+    steps: Vec<FlowStep>,
+}
+impl Flow {
+    /// Adds a step at the end
+    pub fn then<F: Fn(Value) -> Value + 'static>(mut self, name: &str, func: F) -> Flow {
+        self.steps.push(FlowStep::new(name, func));
+        self
+    }
+    /// The names of the steps, in the order they are called
+    pub fn steps(&self) -> impl Iterator<Item = &str> {
+        self.steps.iter().map(FlowStep::name)
+    }
+    ///
+    pub fn call(&self, value: Value) -> Value {
+        self.steps
+            .iter()
+            .fold(value, |value, step| (step.func)(value))
+    }
+    /// Same as [Flow::call()], but also gives what each step returned
+    pub fn trace(&self, value: Value) -> (Value, Vec<FlowTrace>) {
+        let mut traces = Vec::with_capacity(self.steps.len());
+        let value = self.steps.iter().fold(value, |value, step| {
+            let output = (step.func)(value);
+            traces.push(FlowTrace {
+                step: step.name.clone(),
+                output: output.clone(),
+            });
+            output
+        });
+        (value, traces)
+    }
+    /// Same as [Flow::call()], but a panicking step gives an error naming it
+    ///
+    /// The panic is still reported by the panic hook, as for any other panic.
+    pub fn try_call(&self, value: Value) -> Result<Value, FlowError> {
+        let mut value = value;
+        for (index, step) in self.steps.iter().enumerate() {
+            let input = value.clone();
+            value = panic::catch_unwind(AssertUnwindSafe(|| (step.func)(value))).map_err(
+                |payload| {
+                    let message = if let Some(s) = payload.downcast_ref::<&str>() {
+                        s.to_string()
+                    } else if let Some(s) = payload.downcast_ref::<String>() {
+                        s.clone()
+                    } else {
+                        "".to_owned()
+                    };
+                    FlowError {
+                        step: step.name.clone(),
+                        index,
+                        input,
+                        message,
+                    }
+                },
+            )?;
+        }
+        Ok(value)
+    }
+    /// The whole pipeline as one boxed function
+    pub fn into_fn(self) -> Box<dyn Fn(Value) -> Value> {
+        Box::new(move |value| self.call(value))
+    }
+}
+
+/// See lodash [flow](https://lodash.com/docs/#flow)
+pub fn flow(steps: Vec<FlowStep>) -> Flow {
+    Flow { steps }
+}
+/// See lodash [flowRight](https://lodash.com/docs/#flowRight)
+///
+/// The steps are called from the last one to the first one.
+pub fn flow_right(mut steps: Vec<FlowStep>) -> Flow {
+    steps.reverse();
+    Flow { steps }
+}
+
+/// Based on [flow()]
+///
+/// Steps are either `"name" => func`, or only `func`, which is then named after its code.
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let square = |n: Value| multiply!(n.clone(), n);
+/// let add_square = flow!(
+///   "add" => |args: Value| add!(args[0].clone(), args[1].clone()),
+///   "square" => square,
+/// );
+/// assert_eq!(add_square.call(json!([1, 2])), json!(9));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// use serde_json_lodash::{compact, flatten, FlowTrace};
+/// assert_eq!(flow!().call(json!(1)), json!(1));
+/// let pipeline = flow!(flatten, compact)
+///   .then("pairs", |v| chunk!(v, 2))
+///   .then("first", |v| get!(v, json!("[0]")));
+/// assert_eq!(pipeline.steps().collect::<Vec<_>>(), vec!["flatten", "compact", "pairs", "first"]);
+/// assert_eq!(pipeline.call(json!([[0, 1], [false, 2], 3])), json!([1, 2]));
+/// let (result, traces) = pipeline.trace(json!([[1], null]));
+/// assert_eq!(result, json!([1]));
+/// assert_eq!(traces[1], FlowTrace { step: "compact".into(), output: json!([1]) });
+/// assert_eq!(traces.len(), 4);
+///
+/// let checked = flow!(
+///   "double" => |v: Value| multiply!(v, json!(2)),
+///   "expect a small number" => |v: Value| if v.as_f64().unwrap() < 10.0 { v } else { panic!("{} is too big", v) },
+/// );
+/// assert_eq!(checked.try_call(json!(3)), Ok(json!(6)));
+/// let error = checked.try_call(json!(7)).unwrap_err();
+/// assert_eq!((error.index, error.step.as_str(), error.input), (1, "expect a small number", json!(14)));
+/// assert_eq!(error.message, "14 is too big");
+///
+/// let boxed: Box<dyn Fn(Value) -> Value> = checked.into_fn();
+/// assert_eq!(vec![json!(1), json!(2)].into_iter().map(boxed).collect::<Vec<_>>(), vec![json!(2), json!(4)]);
+/// ```
+#[macro_export]
+macro_rules! flow {
+    () => {
+        $crate::flow(vec![])
+    };
+    ($($name:literal => $f:expr),+ $(,)*) => {
+        $crate::flow(vec![$($crate::FlowStep::new($name, $f)),+])
+    };
+    ($($f:expr),+ $(,)*) => {
+        $crate::flow(vec![$($crate::FlowStep::new(stringify!($f), $f)),+])
+    };
+}
+/// Based on [flow_right()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let square = |n: Value| multiply!(n.clone(), n);
+/// let add_square = flow_right!(
+///   "square" => square,
+///   "add" => |args: Value| add!(args[0].clone(), args[1].clone()),
+/// );
+/// assert_eq!(add_square.call(json!([1, 2])), json!(9));
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::json;
+/// use serde_json_lodash::{compact, flatten};
+/// assert_eq!(flow_right!().call(json!(1)), json!(1));
+/// let pipeline = flow_right!(compact, flatten);
+/// assert_eq!(pipeline.steps().collect::<Vec<_>>(), vec!["flatten", "compact"]);
+/// assert_eq!(pipeline.call(json!([[0, 1], 2])), json!([1, 2]));
+/// ```
+#[macro_export]
+macro_rules! flow_right {
+    () => {
+        $crate::flow_right(vec![])
+    };
+    ($($name:literal => $f:expr),+ $(,)*) => {
+        $crate::flow_right(vec![$($crate::FlowStep::new($name, $f)),+])
+    };
+    ($($f:expr),+ $(,)*) => {
+        $crate::flow_right(vec![$($crate::FlowStep::new(stringify!($f), $f)),+])
+    };
+}
@@ -0,0 +1,80 @@
+use crate::lib::{Value};
+use crate::internal::{string_to_path, value_get_path};
+
+/// The iteratee of `_by` functions, it could be a closure, or a property path as the shorthand
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::Iteratee;
+/// let o = json!({"a": [{"b": 1}]});
+/// assert_eq!((|v: &Value| v["a"].clone()).call(&o), json!([{"b": 1}]));
+/// assert_eq!("a[0].b".call(&o), json!(1));
+/// assert_eq!("a[1].b".call(&o), json!(null));
+/// ```
+pub trait Iteratee {
+    /// Computes the value of an item
+    fn call(&self, value: &Value) -> Value;
+}
+impl<F: Fn(&Value) -> Value> Iteratee for F {
+    fn call(&self, value: &Value) -> Value {
+        self(value)
+    }
+}
+impl Iteratee for &str {
+    fn call(&self, value: &Value) -> Value {
+        value_get_path(value, &string_to_path(self))
+    }
+}
+impl Iteratee for String {
+    fn call(&self, value: &Value) -> Value {
+        value_get_path(value, &string_to_path(self))
+    }
+}
+
+/// See lodash [iteratee](https://lodash.com/docs/#iteratee)
+pub fn iteratee<I: Iteratee>(func: I) -> impl Fn(&Value) -> Value {
+    move |value| func.call(value)
+}
+
+/// Based on [iteratee()]
+///
+/// Examples:
+///
+/// ```rust
+/// #[macro_use] extern crate serde_json_lodash;
+/// use serde_json::{json, Value};
+/// let users = json!([
+///   { "user": "barney", "age": 36, "active": true },
+///   { "user": "fred",   "age": 40, "active": false }
+/// ]);
+/// // The `_.matches`, `_.matchesProperty` shorthands are not implemented
+/// let user = iteratee!("user");
+/// assert_eq!(
+///   users.as_array().unwrap().iter().map(user).collect::<Vec<Value>>(),
+///   vec![json!("barney"), json!("fred")]
+/// );
+/// ```
+///
+/// More examples:
+///
+/// ```rust
+/// # #[macro_use] extern crate serde_json_lodash;
+/// # use serde_json::{json, Value};
+/// assert_eq!(iteratee!()(&json!(1)), json!(1));
+/// assert_eq!(iteratee!(|v: &Value| v["a"].clone())(&json!({"a": 2})), json!(2));
+/// assert_eq!(iteratee!("a.b")(&json!({"a": {"b": 3}})), json!(3));
+/// assert_eq!(iteratee!("a[1]")(&json!({"a": "xyz"})), json!("y"));
+/// assert_eq!(iteratee!("a".to_owned())(&json!({"b": 3})), json!(null));
+/// ```
+#[macro_export]
+macro_rules! iteratee {
+    () => {
+        $crate::iteratee(|v: &$crate::lib::Value| v.clone())
+    };
+    ($a:expr $(,)*) => {
+        $crate::iteratee($a)
+    };
+    ($a:expr, $($rest:tt)*) => {
+        $crate::iteratee($a)
+    };
+}
@@ -26,24 +26,14 @@ pub fn default_to() {
 }
 #[doc(hidden)]
 pub use default_to as defaultTo;
-#[doc(hidden)]
-pub fn flow() {
-    todo!()
-}
-#[doc(hidden)]
-pub fn flow_right() {
-    todo!()
-}
-#[doc(hidden)]
-pub use flow_right as flowRight;
+mod flow;
+pub use flow::*;
 #[doc(hidden)]
 pub fn identity() {
     todo!()
 }
-#[doc(hidden)]
-pub fn iteratee() {
-    todo!()
-}
+mod iteratee;
+pub use iteratee::*;
 #[doc(hidden)]
 pub fn matches() {
     todo!()
@@ -1,7 +1,8 @@
-use crate::lib::{json, Value};
-use crate::{to_safe_integer_x};
-///
-pub fn x_range_x(start: isize, end: isize, step: isize) -> Vec<isize> {
+use crate::lib::Value;
+use crate::internal::{value_to_safe_isize};
+use crate::{FromValue, IntoValue};
+
+fn base_range(start: isize, end: isize, step: isize) -> Vec<isize> {
     let mut result = vec![];
     // handle infinity cases
     match step {
@@ -44,21 +45,42 @@ pub fn x_range_x(start: isize, end: isize, step: isize) -> Vec<isize> {
     result
 }
 ///
+#[deprecated(note = "use `range::<Vec<isize>>()`")]
+pub fn x_range_x(start: isize, end: isize, step: isize) -> Vec<isize> {
+    range(start, end, step)
+}
+///
+#[deprecated(note = "use `range()`, it takes an `isize` too")]
 pub fn x_range(start: isize, end: isize, step: isize) -> Value {
-    Value::Array(
-        x_range_x(start, end, step)
-            .iter()
-            .map(|n| json!(n))
-            .collect::<Vec<Value>>(),
-    )
+    range(start, end, step)
 }
 ///
+#[deprecated(note = "use `range::<Vec<isize>>()`")]
 pub fn range_x(start: Value, end: Value, step: isize) -> Vec<isize> {
-    x_range_x(to_safe_integer_x(start), to_safe_integer_x(end), step)
+    range(start, end, step)
 }
 /// See lodash [range](https://lodash.com/docs/#range)
-pub fn range(start: Value, end: Value, step: isize) -> Value {
-    x_range(to_safe_integer_x(start), to_safe_integer_x(end), step)
+///
+/// It takes anything which is [IntoValue] as `start` and `end`, and gives anything which is [FromValue], e.g. a `Vec<isize>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::range;
+/// assert_eq!(range::<Vec<isize>>(0, 20, 5), vec![0, 5, 10, 15]);
+/// assert_eq!(range::<Value>(json!(0), json!(-4), -1), json!([0, -1, -2, -3]));
+/// assert_eq!(range::<Vec<isize>>(json!("1"), 4, 0), vec![1, 1, 1]);
+/// ```
+pub fn range<R: FromValue>(start: impl IntoValue, end: impl IntoValue, step: isize) -> R {
+    let start = value_to_safe_isize(start.into_value());
+    let end = value_to_safe_isize(end.into_value());
+    R::from_value(
+        base_range(start, end, step)
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+    )
 }
 
 /// Based on [x_range_x()]
@@ -107,6 +129,7 @@ pub fn range(start: Value, end: Value, step: isize) -> Value {
 /// let expect: Vec<isize> = vec![];
 /// assert_eq!(x_range_x!(), expect);
 /// ```
+#[deprecated(note = "use `range::<Vec<isize>>()`")]
 #[macro_export]
 macro_rules! x_range_x {
     () => {{
@@ -114,46 +137,58 @@ macro_rules! x_range_x {
         a
     }};
     ($a:expr $(,)*) => {{
-        if $a >= 0 {
-            $crate::x_range_x(0, $a, 1)
-        } else {
-            $crate::x_range_x(0, $a, -1)
-        }
+        let end = $a;
+        #[allow(deprecated)]
+        let result = $crate::x_range_x(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_x($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_x($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_x($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::x_range_x($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::x_range_x($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::x_range_x($a, $b, $c)
-    };
 }
 /// Based on [x_range()]
+#[deprecated(note = "use `range!()`, it takes an `isize` too")]
 #[macro_export]
 macro_rules! x_range {
-    () => {
+    () => {{
         json!([])
-    };
+    }};
     ($a:expr $(,)*) => {{
-        if $a >= 0 {
-            $crate::x_range(0, $a, 1)
-        } else {
-            $crate::x_range(0, $a, -1)
-        }
+        let end = $a;
+        #[allow(deprecated)]
+        let result = $crate::x_range(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::x_range($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::x_range($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::x_range($a, $b, $c)
-    };
 }
 /// Based on [range_x()]
+#[deprecated(note = "use `range::<Vec<isize>>()`")]
 #[macro_export]
 macro_rules! range_x {
     () => {{
@@ -161,22 +196,26 @@ macro_rules! range_x {
         a
     }};
     ($a:expr $(,)*) => {{
-        let end = $crate::to_safe_integer_x($a);
-        if end >= 0 {
-            $crate::x_range_x(0, end, 1)
-        } else {
-            $crate::x_range_x(0, end, -1)
-        }
+        let end = $crate::internal::value_to_safe_isize($a);
+        #[allow(deprecated)]
+        let result = $crate::x_range_x(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_x($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_x($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_x($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::range_x($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::range_x($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::range_x($a, $b, $c)
-    };
 }
 /// Based on [range()]
 ///
@@ -221,6 +260,8 @@ macro_rules! range_x {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// assert_eq!(range!(), json!([]));
+/// assert_eq!(range!(-2), json!([0, -1]));
+/// assert_eq!(range!(1, "3"), json!([1, 2]));
 /// ```
 #[macro_export]
 macro_rules! range {
@@ -228,20 +269,16 @@ macro_rules! range {
         json!([])
     };
     ($a:expr $(,)*) => {{
-        let end = $crate::to_safe_integer_x($a);
-        if end >= 0 {
-            $crate::x_range(0, end, 1)
-        } else {
-            $crate::x_range(0, end, -1)
-        }
+        let end = $crate::internal::value_to_safe_isize($crate::IntoValue::into_value($a));
+        $crate::range::<$crate::lib::Value>(0, end, if end >= 0 { 1 } else { -1 })
     }};
     ($a:expr, $b:expr $(,)*) => {
-        $crate::range($a, $b, 1)
+        $crate::range::<$crate::lib::Value>($a, $b, 1)
     };
     ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::range($a, $b, $c)
+        $crate::range::<$crate::lib::Value>($a, $b, $c)
     };
     ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::range($a, $b, $c)
+        $crate::range::<$crate::lib::Value>($a, $b, $c)
     };
 }
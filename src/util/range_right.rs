@@ -1,37 +1,37 @@
-use crate::lib::{json, Value};
-
-use crate::{to_safe_integer_x, x_range_x};
+use crate::lib::Value;
+use crate::{range, FromValue, IntoValue};
 ///
+#[deprecated(note = "use `range_right::<Vec<isize>>()`")]
 pub fn x_range_right_x(start: isize, end: isize, step: isize) -> Vec<isize> {
-    let mut vec = x_range_x(start, end, step);
-    vec.reverse();
-    vec
+    range_right(start, end, step)
 }
 ///
+#[deprecated(note = "use `range_right()`, it takes an `isize` too")]
 pub fn x_range_right(start: isize, end: isize, step: isize) -> Value {
-    Value::Array(
-        x_range_right_x(start, end, step)
-            .iter()
-            .map(|n| json!(n))
-            .collect::<Vec<Value>>(),
-    )
+    range_right(start, end, step)
 }
 ///
+#[deprecated(note = "use `range_right::<Vec<isize>>()`")]
 pub fn range_right_x(start: Value, end: Value, step: isize) -> Vec<isize> {
-    x_range_right_x(to_safe_integer_x(start), to_safe_integer_x(end), step)
+    range_right(start, end, step)
 }
 /// See lodash [rangeRight](https://lodash.com/docs/#rangeRight)
 ///
+/// Same as [range()](crate::range()), but the items are in descending order.
+///
 /// Examples:
 ///
 /// ```rust
 /// use serde_json::json;
 /// use serde_json_lodash::range_right;
-/// assert_eq!(range_right(json!(null), json!(null), 2), json!([]));
-/// assert_eq!(range_right(json!(0), json!(1), 2), json!([0]));
+/// assert_eq!(range_right::<serde_json::Value>(json!(null), json!(null), 2), json!([]));
+/// assert_eq!(range_right::<serde_json::Value>(json!(0), json!(1), 2), json!([0]));
+/// assert_eq!(range_right::<Vec<isize>>(0, 20, 5), vec![15, 10, 5, 0]);
 /// ```
-pub fn range_right(start: Value, end: Value, step: isize) -> Value {
-    x_range_right(to_safe_integer_x(start), to_safe_integer_x(end), step)
+pub fn range_right<R: FromValue>(start: impl IntoValue, end: impl IntoValue, step: isize) -> R {
+    let mut vec: Vec<Value> = range(start, end, step);
+    vec.reverse();
+    R::from_value(Value::Array(vec))
 }
 
 /// Based on [x_range_right_x()]
@@ -80,6 +80,7 @@ pub fn range_right(start: Value, end: Value, step: isize) -> Value {
 /// let expect: Vec<isize> = vec![];
 /// assert_eq!(x_range_right_x!(), expect);
 /// ```
+#[deprecated(note = "use `range_right::<Vec<isize>>()`")]
 #[macro_export]
 macro_rules! x_range_right_x {
     () => {{
@@ -87,46 +88,58 @@ macro_rules! x_range_right_x {
         a
     }};
     ($a:expr $(,)*) => {{
-        if $a >= 0 {
-            $crate::x_range_right_x(0, $a, 1)
-        } else {
-            $crate::x_range_right_x(0, $a, -1)
-        }
+        let end = $a;
+        #[allow(deprecated)]
+        let result = $crate::x_range_right_x(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right_x($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right_x($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right_x($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::x_range_right_x($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::x_range_right_x($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::x_range_right_x($a, $b, $c)
-    };
 }
 /// Based on [x_range_right()]
+#[deprecated(note = "use `range_right!()`, it takes an `isize` too")]
 #[macro_export]
 macro_rules! x_range_right {
-    () => {
+    () => {{
         json!([])
-    };
+    }};
     ($a:expr $(,)*) => {{
-        if $a >= 0 {
-            $crate::x_range_right(0, $a, 1)
-        } else {
-            $crate::x_range_right(0, $a, -1)
-        }
+        let end = $a;
+        #[allow(deprecated)]
+        let result = $crate::x_range_right(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_range_right($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::x_range_right($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::x_range_right($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::x_range_right($a, $b, $c)
-    };
 }
 /// Based on [range_right_x()]
+#[deprecated(note = "use `range_right::<Vec<isize>>()`")]
 #[macro_export]
 macro_rules! range_right_x {
     () => {{
@@ -134,22 +147,26 @@ macro_rules! range_right_x {
         a
     }};
     ($a:expr $(,)*) => {{
-        let end = $crate::to_safe_integer_x($a);
-        if end >= 0 {
-            $crate::x_range_right_x(0, end, 1)
-        } else {
-            $crate::x_range_right_x(0, end, -1)
-        }
+        let end = $crate::internal::value_to_safe_isize($a);
+        #[allow(deprecated)]
+        let result = $crate::x_range_right_x(0, end, if end >= 0 { 1 } else { -1 });
+        result
+    }};
+    ($a:expr, $b:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_right_x($a, $b, 1);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_right_x($a, $b, $c);
+        result
+    }};
+    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::range_right_x($a, $b, $c);
+        result
     }};
-    ($a:expr, $b:expr $(,)*) => {
-        $crate::range_right_x($a, $b, 1)
-    };
-    ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::range_right_x($a, $b, $c)
-    };
-    ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::range_right_x($a, $b, $c)
-    };
 }
 /// Based on [range_right()]
 ///
@@ -194,6 +211,8 @@ macro_rules! range_right_x {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// assert_eq!(range_right!(), json!([]));
+/// assert_eq!(range_right!(-2), json!([-1, 0]));
+/// assert_eq!(range_right!(1, "3"), json!([2, 1]));
 /// ```
 #[macro_export]
 macro_rules! range_right {
@@ -201,20 +220,16 @@ macro_rules! range_right {
         json!([])
     };
     ($a:expr $(,)*) => {{
-        let end = $crate::to_safe_integer_x($a);
-        if end >= 0 {
-            $crate::x_range_right(0, end, 1)
-        } else {
-            $crate::x_range_right(0, end, -1)
-        }
+        let end = $crate::internal::value_to_safe_isize($crate::IntoValue::into_value($a));
+        $crate::range_right::<$crate::lib::Value>(0, end, if end >= 0 { 1 } else { -1 })
     }};
     ($a:expr, $b:expr $(,)*) => {
-        $crate::range_right($a, $b, 1)
+        $crate::range_right::<$crate::lib::Value>($a, $b, 1)
     };
     ($a:expr, $b:expr, $c:expr $(,)*) => {
-        $crate::range_right($a, $b, $c)
+        $crate::range_right::<$crate::lib::Value>($a, $b, $c)
     };
     ($a:expr, $b:expr, $c:expr, $($rest:tt)*) => {
-        $crate::range_right($a, $b, $c)
+        $crate::range_right::<$crate::lib::Value>($a, $b, $c)
     };
 }
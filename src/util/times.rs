@@ -1,5 +1,5 @@
 use crate::lib::{Value};
-use crate::to_safe_integer_x;
+use crate::to_safe_integer;
 
 ///
 pub fn x_times(n: usize, iteratee: fn(usize) -> Value) -> Value {
@@ -11,7 +11,7 @@ pub fn x_times(n: usize, iteratee: fn(usize) -> Value) -> Value {
 }
 /// See lodash [times](https://lodash.com/docs/#times)
 pub fn times(n: Value, iteratee: fn(usize) -> Value) -> Value {
-    x_times(to_safe_integer_x(n) as usize, iteratee)
+    x_times(to_safe_integer::<i64>(n).max(0) as usize, iteratee)
 }
 
 /// Based on [x_times()]
@@ -81,6 +81,7 @@ macro_rules! x_times {
 /// assert_eq!(times!(json!("")), json!([]));
 /// assert_eq!(times!(json!("2")), json!([0,1]));
 /// assert_eq!(times!(json!("a")), json!([]));
+/// assert_eq!(times!(json!(-1)), json!([]));
 /// assert_eq!(times!(json!([])), json!([]));
 /// assert_eq!(times!(json!([2])), json!([0,1]));
 /// assert_eq!(times!(json!([1,2])), json!([]));
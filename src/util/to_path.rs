@@ -1,127 +1,36 @@
-use crate::lib::{json, Value};
-use crate::{to_string, to_string_x};
+use crate::lib::Value;
+use crate::internal::{value_to_path};
+use crate::{FromValue, IntoValue};
 
 ///
+#[deprecated(note = "use `to_path::<Vec<String>>()`")]
 pub fn x_to_path_x(s: &str) -> Vec<String> {
-    let mut vec = vec![];
-    let mut prev = "".to_owned();
-    let mut current = "".to_owned();
-    let mut in_square = false;
-    for c in s.chars() {
-        match c {
-            '.' => {
-                if in_square {
-                    current.push_str(&c.to_string());
-                } else if prev != "]" {
-                    vec.push(current);
-                    current = "".to_owned();
-                }
-            }
-            '[' => {
-                in_square = true;
-                if !current.is_empty() {
-                    vec.push(current);
-                    current = "".to_owned();
-                }
-            }
-            ']' => {
-                in_square = false;
-                if prev == "]" {
-                    break;
-                }
-                let len = current.len();
-                if len >= 2 {
-                    let mut cs = current.chars();
-                    let first = cs.next().unwrap();
-                    let last = cs.last().unwrap();
-                    if (first == '"' && last == '"') || (first == '\'' && last == '\'') {
-                        current = current[1..(len - 1)].to_owned();
-                    }
-                }
-                vec.push(current);
-                current = "".to_owned();
-            }
-            _ => {
-                current.push_str(&c.to_string());
-            }
-        }
-        prev = c.to_string();
-    }
-    if prev == "." || !current.is_empty() {
-        vec.push(current);
-    }
-    vec
+    to_path(s)
 }
 ///
+#[deprecated(note = "use `to_path()`, it takes a `&str` too")]
 pub fn x_to_path(s: &str) -> Value {
-    let mut vec = vec![];
-    let mut prev = "".to_owned();
-    let mut current = "".to_owned();
-    let mut in_square = false;
-    for c in s.chars() {
-        match c {
-            '.' => {
-                if in_square {
-                    current.push_str(&c.to_string());
-                } else if prev != "]" {
-                    vec.push(json!(current));
-                    current = "".to_owned();
-                }
-            }
-            '[' => {
-                in_square = true;
-                if !current.is_empty() {
-                    vec.push(json!(current));
-                    current = "".to_owned();
-                }
-            }
-            ']' => {
-                in_square = false;
-                if prev == "]" {
-                    break;
-                }
-                let len = current.len();
-                if len >= 2 {
-                    let mut cs = current.chars();
-                    let first = cs.next().unwrap();
-                    let last = cs.last().unwrap();
-                    if (first == '"' && last == '"') || (first == '\'' && last == '\'') {
-                        current = current[1..(len - 1)].to_owned();
-                    }
-                }
-                vec.push(json!(current));
-                current = "".to_owned();
-            }
-            _ => {
-                current.push_str(&c.to_string());
-            }
-        }
-        prev = c.to_string();
-    }
-    if prev == "." || !current.is_empty() {
-        vec.push(json!(current));
-    }
-    Value::Array(vec)
+    to_path(s)
 }
 ///
+#[deprecated(note = "use `to_path::<Vec<String>>()`")]
 pub fn to_path_x(value: Value) -> Vec<String> {
-    match value {
-        Value::Null => vec![],
-        Value::Bool(_) | Value::Number(_) | Value::Object(_) => vec![to_string_x(value)],
-        Value::String(s) => x_to_path_x(&s),
-        Value::Array(vec) => vec.into_iter().map(to_string_x).collect::<Vec<String>>(),
-    }
+    to_path(value)
 }
 /// See lodash [toPath](https://lodash.com/docs/#toPath)
-pub fn to_path(value: Value) -> Value {
-    match value {
-        Value::Null => json!([]),
-        Value::Bool(_) | Value::Number(_) | Value::Object(_) => json!([to_string_x(value)]),
-        Value::String(s) => x_to_path(&s),
-        Value::Array(vec) => {
-            json!(vec.into_iter().map(to_string).collect::<Vec<Value>>())
-        }
-    }
+///
+/// It takes anything which is [IntoValue], and gives anything which is [FromValue], e.g. a `Vec<String>` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::to_path;
+/// assert_eq!(to_path::<Vec<String>>("a[0].b.c"), vec!["a", "0", "b", "c"]);
+/// assert_eq!(to_path::<Value>(json!(["a", 0])), json!(["a", "0"]));
+/// ```
+pub fn to_path<R: FromValue>(value: impl IntoValue) -> R {
+    R::from_value(Value::from(value_to_path(value.into_value())))
 }
 
 /// Based on [x_to_path_x()]
@@ -147,43 +56,58 @@ pub fn to_path(value: Value) -> Value {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// ```
+#[deprecated(note = "use `to_path::<Vec<String>>()`")]
 #[macro_export]
 macro_rules! x_to_path_x {
     () => {
         vec![]
     };
-    ($a:expr $(,)*) => {
-        $crate::x_to_path_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_to_path_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_path_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_path_x($a);
+        result
+    }};
 }
 /// Based on [x_to_path()]
+#[deprecated(note = "use `to_path!()`, it takes a `&str` too")]
 #[macro_export]
 macro_rules! x_to_path {
     () => {
         json!([])
     };
-    ($a:expr $(,)*) => {
-        $crate::x_to_path($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_to_path($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_path($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_to_path($a);
+        result
+    }};
 }
 /// Based on [to_path_x()]
+#[deprecated(note = "use `to_path::<Vec<String>>()`")]
 #[macro_export]
 macro_rules! to_path_x {
     () => {
         vec![]
     };
-    ($a:expr $(,)*) => {
-        $crate::to_path_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::to_path_x($a)
-    };
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_path_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::to_path_x($a);
+        result
+    }};
 }
 /// Based on [to_path()]
 ///
@@ -229,6 +153,7 @@ macro_rules! to_path_x {
 /// assert_eq!(to_path!(json!(["x", 0, "y", "z"])), json!(["x", "0", "y", "z"]));
 /// assert_eq!(to_path!(json!({})), json!(["serde_json::map::Map<alloc::string::String, serde_json::value::Value>"]));
 /// assert_eq!(to_path!(json!({"a":1})), json!(["serde_json::map::Map<alloc::string::String, serde_json::value::Value>"]));
+/// assert_eq!(to_path!("a[0]"), json!(["a", "0"]));
 /// ```
 #[macro_export]
 macro_rules! to_path {
@@ -236,9 +161,9 @@ macro_rules! to_path {
         json!([])
     };
     ($a:expr $(,)*) => {
-        $crate::to_path($a)
+        $crate::to_path::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::to_path($a)
+        $crate::to_path::<$crate::lib::Value>($a)
     };
 }
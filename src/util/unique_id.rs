@@ -1,25 +1,42 @@
 use std::sync::Mutex;
-use crate::lib::{json, Value};
+use crate::lib::Value;
+use crate::{FromValue, IntoValue};
 lazy_static::lazy_static! {
     static ref COUNT: Mutex<usize> = Mutex::new(0);
 }
 ///
+#[deprecated(note = "use `unique_id::<String>()`")]
 pub fn x_unique_id_x(prefix: &str) -> String {
-    let mut c = COUNT.lock().unwrap();
-    *c += 1;
-    format!("{}{}", prefix, c)
+    unique_id(prefix)
 }
 ///
+#[deprecated(note = "use `unique_id()`, it takes a `&str` too")]
 pub fn x_unique_id(prefix: &str) -> Value {
-    json!(x_unique_id_x(prefix))
+    unique_id(prefix)
 }
 ///
+#[deprecated(note = "use `unique_id::<String>()`")]
 pub fn unique_id_x(prefix: &str) -> String {
-    x_unique_id_x(prefix)
+    unique_id(prefix)
 }
 /// See lodash [uniqueId](https://lodash.com/docs/#uniqueId)
-pub fn unique_id(prefix: &str) -> Value {
-    x_unique_id(prefix)
+///
+/// It takes anything which is [IntoValue] as `prefix`, and gives anything which is [FromValue], e.g. a `String` or a `Value`.
+///
+/// Examples:
+///
+/// ```rust
+/// use serde_json::{json, Value};
+/// use serde_json_lodash::unique_id;
+/// let id = unique_id::<String>("contact_");
+/// assert!(id.starts_with("contact_"));
+/// assert_ne!(unique_id::<Value>(json!("contact_")), json!(id));
+/// ```
+pub fn unique_id<R: FromValue>(prefix: impl IntoValue) -> R {
+    let mut c = COUNT.lock().unwrap();
+    *c += 1;
+    let prefix = String::from_value(prefix.into_value());
+    R::from_value(Value::String(format!("{}{}", prefix, c)))
 }
 
 /// Based on [x_unique_id_x()]
@@ -45,43 +62,64 @@ pub fn unique_id(prefix: &str) -> Value {
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
 /// ```
+#[deprecated(note = "use `unique_id::<String>()`")]
 #[macro_export]
 macro_rules! x_unique_id_x {
-    () => {
-        $crate::x_unique_id_x("")
-    };
-    ($a:expr $(,)*) => {
-        $crate::x_unique_id_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_unique_id_x($a)
-    };
+    () => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id_x("");
+        result
+    }};
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id_x($a);
+        result
+    }};
 }
 /// Based on [x_unique_id()]
+#[deprecated(note = "use `unique_id!()`, it takes a `&str` too")]
 #[macro_export]
 macro_rules! x_unique_id {
-    () => {
-        $crate::x_unique_id("")
-    };
-    ($a:expr $(,)*) => {
-        $crate::x_unique_id($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::x_unique_id($a)
-    };
+    () => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id("");
+        result
+    }};
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::x_unique_id($a);
+        result
+    }};
 }
 /// Based on [unique_id_x()]
+#[deprecated(note = "use `unique_id::<String>()`")]
 #[macro_export]
 macro_rules! unique_id_x {
-    () => {
-        $crate::unique_id_x("")
-    };
-    ($a:expr $(,)*) => {
-        $crate::unique_id_x($a)
-    };
-    ($a:expr, $($rest:tt)*) => {
-        $crate::unique_id_x($a)
-    };
+    () => {{
+        #[allow(deprecated)]
+        let result = $crate::unique_id_x("");
+        result
+    }};
+    ($a:expr $(,)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::unique_id_x($a);
+        result
+    }};
+    ($a:expr, $($rest:tt)*) => {{
+        #[allow(deprecated)]
+        let result = $crate::unique_id_x($a);
+        result
+    }};
 }
 /// Based on [unique_id()]
 ///
@@ -105,16 +143,19 @@ macro_rules! unique_id_x {
 /// ```rust
 /// # #[macro_use] extern crate serde_json_lodash;
 /// # use serde_json::json;
+/// assert_eq!(unique_id!(json!(null)), json!("1"));
+/// assert_eq!(unique_id!(7), json!("72"));
+/// assert_eq!(unique_id!(String::from("a")), json!("a3"));
 /// ```
 #[macro_export]
 macro_rules! unique_id {
     () => {
-        $crate::unique_id("")
+        $crate::unique_id::<$crate::lib::Value>("")
     };
     ($a:expr $(,)*) => {
-        $crate::unique_id($a)
+        $crate::unique_id::<$crate::lib::Value>($a)
     };
     ($a:expr, $($rest:tt)*) => {
-        $crate::unique_id($a)
+        $crate::unique_id::<$crate::lib::Value>($a)
     };
 }
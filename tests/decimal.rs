@@ -0,0 +1,76 @@
+#![cfg(feature = "arbitrary_precision")]
+
+use proptest::prelude::*;
+use serde_json::Number;
+use serde_json_lodash::*;
+
+fn finite(n: JsNumber) -> Number {
+    match n {
+        JsNumber::Finite(n) => n,
+        JsNumber::NegZero => Number::from(0),
+        n => panic!("{:?} isn't finite", n),
+    }
+}
+
+fn int() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        any::<u64>().prop_map(i128::from),
+        any::<i64>().prop_map(i128::from),
+        (-1000_i128..1000),
+    ]
+}
+
+// Decimal strings with up to 20 digits on both sides of the point
+fn decimal() -> impl Strategy<Value = Number> {
+    "-?(0|[1-9][0-9]{0,19})(\\.[0-9]{0,19}[1-9])?"
+        .prop_filter("-0 is written as 0", |s| s != "-0")
+        .prop_map(|s| s.parse().unwrap())
+}
+
+proptest! {
+    #[test]
+    fn add_integers(a in int(), b in int()) {
+        let result = add::<JsNumber>(a.to_string().parse::<Number>().unwrap(), b.to_string().parse::<Number>().unwrap());
+        prop_assert_eq!(finite(result).to_string(), (a + b).to_string());
+    }
+
+    #[test]
+    fn subtract_integers(a in int(), b in int()) {
+        let result = subtract::<JsNumber>(a.to_string().parse::<Number>().unwrap(), b.to_string().parse::<Number>().unwrap());
+        prop_assert_eq!(finite(result).to_string(), (a - b).to_string());
+    }
+
+    #[test]
+    fn multiply_integers(a in int(), b in int()) {
+        let result = multiply::<JsNumber>(a.to_string().parse::<Number>().unwrap(), b.to_string().parse::<Number>().unwrap());
+        if let Some(exact) = a.checked_mul(b) {
+            prop_assert_eq!(finite(result).to_string(), exact.to_string());
+        }
+    }
+
+    #[test]
+    fn add_then_subtract_decimals(a in decimal(), b in decimal()) {
+        let sum = finite(add::<JsNumber>(a.clone(), b.clone()));
+        prop_assert_eq!(finite(subtract::<JsNumber>(sum, b)), a);
+    }
+
+    #[test]
+    fn multiply_decimals_by_powers_of_ten(a in decimal(), exp in 0_i32..30) {
+        let power: Number = format!("1e{}", exp).parse().unwrap();
+        let inverse: Number = format!("1e-{}", exp).parse().unwrap();
+        let product = finite(multiply::<JsNumber>(a.clone(), power));
+        prop_assert_eq!(finite(multiply::<JsNumber>(product, inverse)), a);
+    }
+
+    #[test]
+    fn round_decimals_between_floor_and_ceil(a in decimal(), precision in -25_isize..25) {
+        let floor = finite(floor::<JsNumber>(a.clone(), precision));
+        let round = finite(round::<JsNumber>(a.clone(), precision));
+        let ceil = finite(ceil::<JsNumber>(a.clone(), precision));
+        let is_negative = |n: JsNumber| finite(n).to_string().starts_with('-');
+        prop_assert!(!is_negative(subtract::<JsNumber>(a.clone(), floor.clone())));
+        prop_assert!(!is_negative(subtract::<JsNumber>(ceil.clone(), a)));
+        prop_assert!(!is_negative(subtract::<JsNumber>(round.clone(), floor)));
+        prop_assert!(!is_negative(subtract::<JsNumber>(ceil, round)));
+    }
+}
@@ -0,0 +1,33 @@
+use proptest::prelude::*;
+use serde_json::{json, Value};
+use serde_json_lodash::*;
+
+fn item() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        Just(json!(null)),
+        any::<bool>().prop_map(Value::from),
+        (-3_i64..3).prop_map(Value::from),
+        (-1.5_f64..1.5).prop_map(Value::from),
+        "[ab]?".prop_map(Value::from),
+        Just(json!([])),
+        Just(json!({})),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn fused_steps_match_eager_functions(
+        items in prop::collection::vec(item(), 0..50),
+        skip in -5_isize..60,
+        n in -5_isize..60,
+    ) {
+        let array = Value::Array(items.clone());
+        prop_assert_eq!(lazy(array.clone()).compact().value(), compact(array.clone()));
+        prop_assert_eq!(lazy(array.clone()).drop(skip).value(), drop(array.clone(), skip));
+        prop_assert_eq!(
+            lazy(array.clone()).compact().drop(skip).take(n).value(),
+            take(drop(compact(array.clone()), skip), n)
+        );
+        prop_assert_eq!(lazy(array.clone()).drop(skip).head(), head(drop(array, skip)));
+    }
+}
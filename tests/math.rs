@@ -0,0 +1,139 @@
+#![cfg(not(feature = "arbitrary_precision"))]
+
+use proptest::prelude::*;
+use serde_json::{json, Number};
+use serde_json_lodash::*;
+
+// Every integer result must be exact, and if it had to be promoted it must be the f64 reference
+fn assert_integer_op(result: JsNumber, checked: Option<Number>, exact: i128, reference: f64) {
+    let result = match result {
+        JsNumber::Finite(n) => n,
+        JsNumber::NegZero => Number::from(0),
+        n => panic!("{:?} isn't finite", n),
+    };
+    if let Some(r) = result.as_u64() {
+        assert_eq!(r as i128, exact);
+        assert_eq!(checked, Some(result));
+    } else if let Some(r) = result.as_i64() {
+        assert_eq!(r as i128, exact);
+        assert_eq!(checked, Some(result));
+    } else {
+        assert!(exact > u64::MAX as i128 || exact < i64::MIN as i128);
+        assert_eq!(result.as_f64().unwrap(), reference);
+        assert_eq!(checked, None);
+    }
+}
+
+fn assert_float_op(result: JsNumber, checked: Option<Number>, reference: f64) {
+    if reference.is_nan() {
+        assert_eq!(result, JsNumber::NaN);
+    } else {
+        assert_eq!(result.as_f64(), reference);
+    }
+    if reference.is_finite() {
+        assert_eq!(checked.unwrap().as_f64().unwrap(), reference);
+    } else {
+        assert_eq!(checked, None);
+    }
+}
+
+fn int() -> impl Strategy<Value = Number> {
+    prop_oneof![
+        any::<u64>().prop_map(Number::from),
+        any::<i64>().prop_map(Number::from),
+        (-1000_i64..1000).prop_map(Number::from),
+    ]
+}
+
+fn float() -> impl Strategy<Value = f64> {
+    prop_oneof![
+        any::<f64>().prop_filter("finite", |f| f.is_finite()),
+        -1e6_f64..1e6,
+    ]
+}
+
+fn as_i128(n: &Number) -> i128 {
+    n.as_u64()
+        .map(|u| u as i128)
+        .unwrap_or_else(|| n.as_i64().unwrap() as i128)
+}
+
+proptest! {
+    #[test]
+    fn add_integers(a in int(), b in int()) {
+        let reference = a.as_f64().unwrap() + b.as_f64().unwrap();
+        let exact = as_i128(&a) + as_i128(&b);
+        assert_integer_op(add::<JsNumber>(a.clone(), b.clone()), x_checked_add_x(a, b), exact, reference);
+    }
+
+    #[test]
+    fn subtract_integers(a in int(), b in int()) {
+        let reference = a.as_f64().unwrap() - b.as_f64().unwrap();
+        let exact = as_i128(&a) - as_i128(&b);
+        assert_integer_op(subtract::<JsNumber>(a.clone(), b.clone()), x_checked_subtract_x(a, b), exact, reference);
+    }
+
+    #[test]
+    fn multiply_integers(a in int(), b in int()) {
+        let reference = a.as_f64().unwrap() * b.as_f64().unwrap();
+        let result = multiply::<JsNumber>(a.clone(), b.clone());
+        let checked = x_checked_multiply_x(a.clone(), b.clone());
+        match as_i128(&a).checked_mul(as_i128(&b)) {
+            Some(exact) => assert_integer_op(result, checked, exact, reference),
+            None => {
+                assert_eq!(result.as_f64(), reference);
+                assert_eq!(checked, None);
+            }
+        }
+    }
+
+    #[test]
+    fn divide_integers(a in int(), b in int()) {
+        let reference = a.as_f64().unwrap() / b.as_f64().unwrap();
+        let result = divide::<JsNumber>(a.clone(), b.clone());
+        let checked = x_checked_divide_x(a.clone(), b.clone());
+        let (i, i2) = (as_i128(&a), as_i128(&b));
+        if i2 != 0 && i % i2 == 0 {
+            assert_integer_op(result, checked, i / i2, reference);
+        } else {
+            assert_float_op(result, checked, reference);
+        }
+    }
+
+    #[test]
+    fn add_floats(a in float(), b in float()) {
+        let (n, n2) = (Number::from_f64(a).unwrap(), Number::from_f64(b).unwrap());
+        assert_float_op(add::<JsNumber>(n.clone(), n2.clone()), x_checked_add_x(n, n2), a + b);
+    }
+
+    #[test]
+    fn subtract_floats(a in float(), b in float()) {
+        let (n, n2) = (Number::from_f64(a).unwrap(), Number::from_f64(b).unwrap());
+        assert_float_op(subtract::<JsNumber>(n.clone(), n2.clone()), x_checked_subtract_x(n, n2), a - b);
+    }
+
+    #[test]
+    fn multiply_floats(a in float(), b in float()) {
+        let (n, n2) = (Number::from_f64(a).unwrap(), Number::from_f64(b).unwrap());
+        assert_float_op(multiply::<JsNumber>(n.clone(), n2.clone()), x_checked_multiply_x(n, n2), a * b);
+    }
+
+    #[test]
+    fn divide_floats(a in float(), b in float()) {
+        let (n, n2) = (Number::from_f64(a).unwrap(), Number::from_f64(b).unwrap());
+        assert_float_op(divide::<JsNumber>(n.clone(), n2.clone()), x_checked_divide_x(n, n2), a / b);
+    }
+
+    #[test]
+    fn add_mixed(a in int(), b in float()) {
+        let n2 = Number::from_f64(b).unwrap();
+        let reference = a.as_f64().unwrap() + b;
+        assert_float_op(add::<JsNumber>(a.clone(), n2.clone()), x_checked_add_x(a, n2), reference);
+    }
+
+    #[test]
+    fn add_values_never_panic(a in int(), b in int()) {
+        let result = add::<serde_json::Value>(json!(a), json!(b));
+        prop_assert!(result.is_number());
+    }
+}
@@ -0,0 +1,106 @@
+use proptest::prelude::*;
+use serde_json::{json, Value};
+use serde_json_lodash::*;
+
+fn leaf() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        Just(json!(null)),
+        any::<bool>().prop_map(Value::from),
+        (-3_i64..3).prop_map(Value::from),
+        (-6_i64..6).prop_map(|n| Value::from(n as f64 / 2.0)),
+        "[ab]?".prop_map(Value::from),
+    ]
+}
+
+fn item() -> impl Strategy<Value = Value> {
+    leaf().prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3).prop_map(Value::Array),
+            prop::collection::btree_map("[ab]", inner, 0..3).prop_map(|map| json!(map)),
+        ]
+    })
+}
+
+// What the hashed key must agree with, as a plain pairwise comparison
+fn naive_eq(a: &Value, b: &Value, mode: EqualityMode) -> bool {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            mode == EqualityMode::Structural
+                && a.len() == b.len()
+                && a.iter().zip(b).all(|(a, b)| naive_eq(a, b, mode))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            mode == EqualityMode::Structural
+                && a.len() == b.len()
+                && a.iter()
+                    .all(|(k, a)| b.get(k).is_some_and(|b| naive_eq(a, b, mode)))
+        }
+        (Value::Array(_), _) | (Value::Object(_), _) => false,
+        _ => a == b,
+    }
+}
+
+proptest! {
+    #[test]
+    fn keys_match_pairwise_equality(a in item(), b in item()) {
+        for &mode in &[EqualityMode::SameValueZero, EqualityMode::Structural] {
+            let (ka, kb) = (ValueKey::new(&a, mode), ValueKey::new(&b, mode));
+            prop_assert_eq!(ka.is_some() && ka == kb, naive_eq(&a, &b, mode));
+        }
+    }
+
+    #[test]
+    fn hashed_set_operations_match_pairwise_ones(
+        a in prop::collection::vec(item(), 0..20),
+        b in prop::collection::vec(item(), 0..20),
+        structural in any::<bool>(),
+    ) {
+        let mode = if structural { EqualityMode::Structural } else { EqualityMode::SameValueZero };
+        let contains = |vec: &[Value], v: &Value| vec.iter().any(|w| naive_eq(v, w, mode));
+        let mut expected_intersection: Vec<Value> = vec![];
+        for v in a.iter().filter(|v| contains(&b, v)) {
+            if !contains(&expected_intersection, v) {
+                expected_intersection.push(v.clone());
+            }
+        }
+        let expected_difference: Vec<Value> = a.iter().filter(|v| !contains(&b, v)).cloned().collect();
+        let mut expected_uniq: Vec<Value> = vec![];
+        for v in a.iter() {
+            if !contains(&expected_uniq, v) {
+                expected_uniq.push(v.clone());
+            }
+        }
+        let (a, b) = (Value::Array(a), Value::Array(b));
+        prop_assert_eq!(uniq_in(mode, a.clone()), Value::Array(expected_uniq));
+        prop_assert_eq!(intersection_in(mode, a.clone(), b.clone()), Value::Array(expected_intersection));
+        prop_assert_eq!(difference_in(mode, a.clone(), b.clone()), Value::Array(expected_difference.clone()));
+        prop_assert_eq!(pull_all_in(mode, a, b), Value::Array(expected_difference));
+    }
+
+    #[test]
+    fn hashed_union_and_xor_match_pairwise_ones(
+        arrays in prop::collection::vec(prop::collection::vec(item(), 0..10), 1..4),
+        structural in any::<bool>(),
+    ) {
+        let mode = if structural { EqualityMode::Structural } else { EqualityMode::SameValueZero };
+        let contains = |vec: &[Value], v: &Value| vec.iter().any(|w| naive_eq(v, w, mode));
+        let mut expected_union: Vec<Value> = vec![];
+        let mut expected_xor: Vec<Value> = vec![];
+        for (i, vec) in arrays.iter().enumerate() {
+            for v in vec {
+                if !contains(&expected_union, v) {
+                    expected_union.push(v.clone());
+                }
+                let in_others = arrays.iter().enumerate().any(|(j, other)| j != i && contains(other, v));
+                if !in_others && !contains(&expected_xor, v) {
+                    expected_xor.push(v.clone());
+                }
+            }
+        }
+        let arrays: Vec<Value> = arrays.into_iter().map(Value::Array).collect();
+        prop_assert_eq!(_union_in_all(mode, arrays.clone()), Value::Array(expected_union));
+        prop_assert_eq!(_xor_in_all(mode, arrays.clone()), Value::Array(expected_xor.clone()));
+        prop_assert_eq!(_xor_with_all(arrays, |a, b| naive_eq(a, b, mode)), Value::Array(expected_xor));
+    }
+}
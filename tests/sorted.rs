@@ -0,0 +1,43 @@
+use proptest::prelude::*;
+use serde_json::{json, Value};
+use serde_json_lodash::internal::value_sort_cmp;
+use serde_json_lodash::*;
+use std::cmp::Ordering;
+
+fn item() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(json!(null)),
+        any::<bool>().prop_map(Value::from),
+        (-3_i64..3).prop_map(Value::from),
+        (-6_i64..6).prop_map(|n| Value::from(n as f64 / 2.0)),
+        "[ab]{0,2}".prop_map(Value::from),
+    ];
+    leaf.prop_recursive(2, 8, 3, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..3).prop_map(Value::Array),
+            prop::collection::btree_map("[ab]", inner, 0..3).prop_map(|map| json!(map)),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn sort_order_is_total(a in item(), b in item(), c in item()) {
+        prop_assert_eq!(value_sort_cmp(&a, &b), value_sort_cmp(&b, &a).reverse());
+        if value_sort_cmp(&a, &b) != Ordering::Greater && value_sort_cmp(&b, &c) != Ordering::Greater {
+            prop_assert_ne!(value_sort_cmp(&a, &c), Ordering::Greater);
+        }
+    }
+
+    #[test]
+    fn binary_searches_match_linear_ones(mut items in prop::collection::vec(item(), 0..30), value in item()) {
+        items.sort_by(value_sort_cmp);
+        let before = items.iter().filter(|v| value_sort_cmp(v, &value) == Ordering::Less).count();
+        let not_after = items.iter().filter(|v| value_sort_cmp(v, &value) != Ordering::Greater).count();
+        prop_assert_eq!(sorted_index(items.clone(), value.clone()), before);
+        prop_assert_eq!(sorted_last_index(items.clone(), value.clone()), not_after);
+        let array = Value::Array(items);
+        prop_assert_eq!(sorted_index(array.clone(), value.clone()), before);
+        prop_assert_eq!(sorted_last_index(array, value), not_after);
+    }
+}